@@ -9,8 +9,11 @@ if [[ "$crate" == "utoipa" ]]; then
   cargo test --test path_response_derive_test_no_serde_json --no-default-features
   cargo test --test component_derive_no_serde_json --no-default-features
   cargo test --test path_derive_actix --test path_parameter_derive_actix --features actix_extras
+  cargo test --test path_derive_actix --test component_derive_validator --features actix_extras,validator
+  cargo test --lib --test component_derive_schemars --features schemars
   cargo test --test component_derive_test --features chrono,decimal,uuid
   cargo test --test component_derive_test --features chrono_with_format
+  cargo test --test component_derive_test --features yaml
   cargo test --test path_derive_rocket --features rocket_extras,json
   elif [[ "$crate" == "utoipa-gen" ]]; then
   cargo test -p utoipa-gen --features utoipa/actix_extras
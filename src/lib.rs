@@ -64,6 +64,10 @@
 //!   See the `value_type` in [component derive docs][component_derive].
 //! * **uuid** Add support for [uuid](https://github.com/uuid-rs/uuid). `Uuid` type will be presented as `String` with
 //!   format `uuid` in OpenAPI spec.
+//! * **schemars** Add conversion from [schemars](https://crates.io/crates/schemars) JSON schemas to OpenAPI
+//!   components and `#[component(schemars)]` marker for types deriving `JsonSchema`. See [schemars module][schemars].
+//! * **validator** Reflect [validator](https://crates.io/crates/validator) crate's `#[validate(...)]` attributes
+//!   to OpenAPI schema constraints such as `minLength`, `maximum` and `pattern`. See [component derive docs][component_derive].
 //!
 //! Utoipa implicitly has partial support for `serde` attributes. See [component derive][serde] for more details.
 //!
@@ -199,6 +203,7 @@
 //!
 //! [security]: openapi/security/index.html
 //! [component_derive]: derive.Component.html
+//! [schemars]: openapi/schemars/index.html
 
 pub mod openapi;
 
@@ -316,6 +321,17 @@ pub trait Component {
     fn aliases() -> Vec<(&'static str, openapi::schema::Component)> {
         Vec::new()
     }
+
+    /// Other components this component references which need to be registered to
+    /// [`Components`][components] along with this component. By default there are none.
+    ///
+    /// This is used by components generated with `#[component(schemars)]` to register
+    /// types the schemars generated schema references.
+    ///
+    /// [components]: openapi/schema/struct.Components.html
+    fn definitions() -> Vec<(String, openapi::schema::Component)> {
+        Vec::new()
+    }
 }
 
 /// Trait for implementing OpenAPI PathItem object with path.
@@ -467,7 +483,40 @@ pub trait Path {
 /// }
 /// ```
 ///
+/// Modify every [`Operation`][operation] of the [`OpenApi`][`openapi::OpenApi`] with
+/// [`VisitMut`][visit_mut] instead of walking the paths by hand. Here every operation except
+/// _`GET`_ operations gets _`api_key`_ security requirement.
+/// ```rust
+/// # use utoipa::{OpenApi, Modify};
+/// # use utoipa::openapi::{path::{Operation, PathItemType}, security::SecurityRequirement, visit_mut::{self, VisitMut}};
+/// #[derive(OpenApi)]
+/// #[openapi(modifiers(&ApiKeyAddon))]
+/// struct ApiDoc;
+///
+/// struct ApiKeyAddon;
+///
+/// impl VisitMut for ApiKeyAddon {
+///     fn visit_operation_mut(&mut self, path_item_type: &PathItemType, operation: &mut Operation) {
+///         if *path_item_type != PathItemType::Get {
+///             operation
+///                 .security
+///                 .get_or_insert_with(Vec::new)
+///                 .push(SecurityRequirement::new("api_key", ["edit:items"]));
+///         }
+///         visit_mut::visit_operation_mut(self, path_item_type, operation);
+///     }
+/// }
+///
+/// impl Modify for ApiKeyAddon {
+///     fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+///         ApiKeyAddon.visit_openapi_mut(openapi)
+///     }
+/// }
+/// ```
+///
 /// [server]: https://spec.openapis.org/oas/latest.html#server-object
+/// [operation]: openapi/path/struct.Operation.html
+/// [visit_mut]: openapi/visit_mut/trait.VisitMut.html
 pub trait Modify {
     fn modify(&self, openapi: &mut openapi::OpenApi);
 }
@@ -539,3 +588,105 @@ pub trait IntoParams {
         parameter_in_provider: impl Fn() -> Option<openapi::path::ParameterIn>,
     ) -> Vec<openapi::path::Parameter>;
 }
+
+/// Trait used to convert implementing type to OpenAPI [`Response`][response] which can be registered
+/// as reusable response to [`Components`][components].
+///
+/// This trait is implemented via [`#[derive(ToResponse)]`][derive] macro and typically there is
+/// no need to implement it manually. Registered responses can be referenced from
+/// [`#[utoipa::path(...)]`][path] with `(status = ..., response = Type)`.
+///
+/// # Examples
+///
+/// Use `#[derive(ToResponse)]` to implement the trait.
+/// ```rust
+/// /// Requested resource was not found
+/// #[derive(utoipa::ToResponse)]
+/// struct NotFound;
+/// ```
+///
+/// Example of what would manual implementation roughly look like of above `#[derive(ToResponse)]`.
+/// ```rust
+/// # struct NotFound;
+/// impl utoipa::ToResponse for NotFound {
+///     fn response() -> (
+///         &'static str,
+///         utoipa::openapi::RefOr<utoipa::openapi::response::Response>,
+///     ) {
+///         (
+///             "NotFound",
+///             utoipa::openapi::ResponseBuilder::new()
+///                 .description("Requested resource was not found")
+///                 .into(),
+///         )
+///     }
+/// }
+/// ```
+///
+/// [response]: openapi/response/struct.Response.html
+/// [components]: openapi/schema/struct.Components.html
+/// [derive]: derive.ToResponse.html
+/// [path]: attr.path.html#responses-attributes
+pub trait ToResponse {
+    /// Provide name and the [`openapi::response::Response`] of the implementing type. The name is
+    /// used as a key of the response in [`Components`][components] and in references to it.
+    ///
+    /// [components]: openapi/schema/struct.Components.html
+    fn response() -> (&'static str, openapi::RefOr<openapi::response::Response>);
+}
+
+/// Trait used to convert implementing type to map of OpenAPI [`Response`][response]s
+/// identified by their status codes.
+///
+/// This trait is implemented via [`#[derive(IntoResponses)]`][derive] macro and typically there is
+/// no need to implement it manually. Implementing types can be given to
+/// [`#[utoipa::path(...)]`][path] as is e.g. `responses(MyErrors)` to add all of the responses
+/// to the path operation.
+///
+/// # Examples
+///
+/// Use `#[derive(IntoResponses)]` to implement the trait.
+/// ```rust
+/// #[derive(utoipa::IntoResponses)]
+/// enum MyErrors {
+///     /// Missing or invalid credentials
+///     #[response(status = 401)]
+///     Unauthorized,
+///
+///     #[response(status = 404, description = "Resource not found")]
+///     NotFound,
+/// }
+/// ```
+///
+/// Example of what would manual implementation roughly look like of above `#[derive(IntoResponses)]`.
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # enum MyErrors {
+/// #     Unauthorized,
+/// #     NotFound,
+/// # }
+/// impl utoipa::IntoResponses for MyErrors {
+///     fn responses() -> BTreeMap<String, utoipa::openapi::RefOr<utoipa::openapi::response::Response>> {
+///         utoipa::openapi::ResponsesBuilder::new()
+///             .response(
+///                 "401",
+///                 utoipa::openapi::ResponseBuilder::new()
+///                     .description("Missing or invalid credentials"),
+///             )
+///             .response(
+///                 "404",
+///                 utoipa::openapi::ResponseBuilder::new().description("Resource not found"),
+///             )
+///             .build()
+///             .responses
+///     }
+/// }
+/// ```
+///
+/// [response]: openapi/response/struct.Response.html
+/// [derive]: derive.IntoResponses.html
+/// [path]: attr.path.html#responses-attributes
+pub trait IntoResponses {
+    /// Provide map of status codes and [`openapi::response::Response`]s of the implementing type.
+    fn responses() -> std::collections::BTreeMap<String, openapi::RefOr<openapi::response::Response>>;
+}
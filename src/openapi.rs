@@ -1,35 +1,68 @@
 //! Rust implementation of Openapi Spec V3
 
-use serde::{de::Visitor, Deserialize, Serialize, Serializer};
+use std::{
+    collections::{btree_map, hash_map, BTreeMap, BTreeSet, HashMap},
+    fmt,
+};
+
+use serde::{
+    de::{
+        self, value::MapAccessDeserializer, DeserializeSeed, IntoDeserializer, MapAccess, Visitor,
+    },
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 pub use self::{
+    callback::{Callback, CallbackBuilder},
     content::{Content, ContentBuilder},
+    dereference::DereferenceError,
+    diff::{Change, Compatibility},
+    example::{Example, ExampleBuilder},
+    extensions::{Extensions, ExtensionsBuilder},
     external_docs::ExternalDocs,
     header::{Header, HeaderBuilder},
     info::{Contact, ContactBuilder, Info, InfoBuilder, License, LicenseBuilder},
+    link::{Link, LinkBuilder},
     path::{PathItem, PathItemType, Paths, PathsBuilder},
     response::{Response, ResponseBuilder, Responses, ResponsesBuilder},
     schema::{
-        Array, ArrayBuilder, Component, ComponentFormat, ComponentType, Components,
-        ComponentsBuilder, Object, ObjectBuilder, OneOf, OneOfBuilder, Property, PropertyBuilder,
-        Ref, ToArray,
+        AllOf, AllOfBuilder, AnyOf, AnyOfBuilder, Array, ArrayBuilder, Component, ComponentFormat,
+        ComponentType, Components, ComponentsBuilder, Object, ObjectBuilder, OneOf, OneOfBuilder,
+        Property, PropertyBuilder, Ref, ToArray,
     },
     security::SecurityRequirement,
     server::{Server, ServerBuilder, ServerVariable, ServerVariableBuilder},
     tag::Tag,
+    validation::{Severity, ValidationError},
 };
 
+mod bundle;
+pub mod callback;
 pub mod content;
+pub mod dereference;
+pub mod diff;
+pub mod example;
+pub mod extensions;
 pub mod external_docs;
+mod filter;
 pub mod header;
 pub mod info;
+#[cfg(feature = "serde_json")]
+pub mod json_schema;
+pub mod link;
 pub mod path;
 pub mod request_body;
 pub mod response;
 pub mod schema;
+#[cfg(feature = "schemars")]
+pub mod schemars;
 pub mod security;
 pub mod server;
 pub mod tag;
+pub mod validation;
+mod version;
+pub mod visit;
+pub mod visit_mut;
 pub mod xml;
 
 builder! {
@@ -57,13 +90,21 @@ builder! {
     ///
     /// See more details at <https://spec.openapis.org/oas/latest.html#openapi-object>.
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    #[serde(rename_all = "camelCase")]
+    #[serde(remote = "Self", rename_all = "camelCase")]
     pub struct OpenApi {
-        /// OpenAPI document version.
+        /// OpenAPI document version. Serialization of the document depends on the version, see
+        /// [`OpenApiVersion`] for more details.
         pub openapi: OpenApiVersion,
 
+        /// Default value of _`$schema`_ keyword of the schemas in this document. This is only
+        /// serialized with [`OpenApiVersion::Version31`].
+        #[serde(
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub json_schema_dialect: Option<String>,
+
         /// Provides metadata about the API.
         ///
         /// See more details at <https://spec.openapis.org/oas/latest.html#info-object>.
@@ -80,9 +121,19 @@ builder! {
         /// Available paths and operations for the API.
         ///
         /// See more details at <https://spec.openapis.org/oas/latest.html#paths-object>.
-        #[serde(flatten)]
         pub paths: Paths,
 
+        /// Map of webhooks identified by unique name. Webhooks describe requests initiated by the
+        /// API provider to the consumers of the API. This is only serialized with
+        /// [`OpenApiVersion::Version31`].
+        ///
+        /// See more details at <https://spec.openapis.org/oas/latest.html#oasWebhooks>.
+        #[serde(
+            skip_serializing_if = "BTreeMap::is_empty",
+            default
+        )]
+        pub webhooks: BTreeMap<String, PathItem>,
+
         /// Holds various reusable schemas for the OpenAPI document.
         ///
         /// Few of these elements are security schemas and object schemas.
@@ -110,6 +161,14 @@ builder! {
         /// See more details at <https://spec.openapis.org/oas/latest.html#external-documentation-object>.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
+
+        /// Optional extensions `x-something` of the [`OpenApi`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -134,6 +193,473 @@ impl OpenApi {
         }
     }
 
+    /// Merge `other` [`OpenApi`] document to this document.
+    ///
+    /// Paths, webhooks, components, tags and global security requirements of `other` are added
+    /// to this document. Other fields such as [`Info`] and servers of this document are kept as is.
+    ///
+    /// Definitions found in both documents are conflicting unless they are equal. Conflicts are
+    /// resolved by keeping the definition of this document and a [`MergeConflict`] is returned for
+    /// each of them. Operations of the same path and http method, components of the same name,
+    /// tags of the same name and path level fields of the same path may conflict.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, PathItem, PathItemType, Paths, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut openapi = OpenApi::new(
+    ///     Info::new("pet api", "1.0.0"),
+    ///     PathsBuilder::new().path(
+    ///         "/pets",
+    ///         PathItem::new(PathItemType::Get, OperationBuilder::new().operation_id(Some("listPets"))),
+    ///     ),
+    /// );
+    /// let store = OpenApi::new(
+    ///     Info::new("store api", "1.0.0"),
+    ///     PathsBuilder::new()
+    ///         .path(
+    ///             "/pets",
+    ///             PathItem::new(PathItemType::Get, OperationBuilder::new().operation_id(Some("findPets"))),
+    ///         )
+    ///         .path("/store", PathItem::new(PathItemType::Get, OperationBuilder::new())),
+    /// );
+    ///
+    /// let conflicts = openapi.merge(store);
+    ///
+    /// assert_eq!(conflicts.len(), 1);
+    /// assert_eq!(conflicts[0].path(), "paths./pets.get");
+    /// assert!(openapi.paths.paths.contains_key("/store"));
+    /// ```
+    #[must_use = "conflicting definitions of `other` are only reported by the returned conflicts"]
+    pub fn merge(&mut self, other: OpenApi) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+
+        merge_path_items(
+            &mut self.paths.paths,
+            other.paths.paths,
+            "paths",
+            &mut conflicts,
+        );
+        merge_path_items(
+            &mut self.webhooks,
+            other.webhooks,
+            "webhooks",
+            &mut conflicts,
+        );
+
+        if let Some(other_components) = other.components {
+            match self.components.as_mut() {
+                Some(components) => merge_components(components, other_components, &mut conflicts),
+                None => self.components = Some(other_components),
+            }
+        }
+
+        if let Some(other_tags) = other.tags {
+            let tags = self.tags.get_or_insert_with(Vec::new);
+            for tag in other_tags {
+                match tags.iter().find(|existing| existing.name == tag.name) {
+                    Some(existing) if *existing != tag => {
+                        conflicts.push(MergeConflict::new(format!("tags.{}", tag.name)))
+                    }
+                    Some(_) => (),
+                    None => tags.push(tag),
+                }
+            }
+        }
+
+        if let Some(other_security) = other.security {
+            let security = self.security.get_or_insert_with(Vec::new);
+            for requirement in other_security {
+                if !security.contains(&requirement) {
+                    security.push(requirement);
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    /// Nest `other` [`OpenApi`] document to this document under given path prefix.
+    ///
+    /// Every path of `other` is prefixed with the `prefix` before the document is merged to this
+    /// document with [`OpenApi::merge`]. Webhooks of `other` are merged as is since they do not
+    /// have paths within this api.
+    ///
+    /// Global security requirements of `other` only apply to the nested operations. They are set
+    /// to every operation of `other` without security requirements of its own instead of adding
+    /// them to the global security requirements of this document.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Info, OpenApi, PathItem, PathItemType, Paths, PathsBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut openapi = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+    /// let pets = OpenApi::new(
+    ///     Info::new("pet api", "1.0.0"),
+    ///     PathsBuilder::new()
+    ///         .path("/", PathItem::new(PathItemType::Get, OperationBuilder::new()))
+    ///         .path("/{id}", PathItem::new(PathItemType::Get, OperationBuilder::new())),
+    /// );
+    ///
+    /// let conflicts = openapi.nest("/api/v1/pets", pets);
+    ///
+    /// assert!(conflicts.is_empty());
+    /// assert_eq!(
+    ///     openapi.paths.paths.keys().collect::<Vec<_>>(),
+    ///     ["/api/v1/pets", "/api/v1/pets/{id}"]
+    /// );
+    /// ```
+    #[must_use = "conflicting definitions of `other` are only reported by the returned conflicts"]
+    pub fn nest<P: AsRef<str>>(&mut self, prefix: P, other: OpenApi) -> Vec<MergeConflict> {
+        self.merge(with_operation_security(with_path_prefix(
+            prefix.as_ref(),
+            other,
+        )))
+    }
+
+    /// Nest `other` [`OpenApi`] document to this document under given path prefix and tag every
+    /// operation of the nested paths with given `tag`.
+    ///
+    /// This is same as [`OpenApi::nest`] but the `tag` is also added to the [`Operation::tags`][tags]
+    /// of `other` unless the operation already has the tag. [`Tag`] of the given name is added to
+    /// the tags of this document unless either of the documents already defines it.
+    ///
+    /// [tags]: path/struct.Operation.html#structfield.tags
+    #[must_use = "conflicting definitions of `other` are only reported by the returned conflicts"]
+    pub fn nest_with_tag<P: AsRef<str>, T: AsRef<str>>(
+        &mut self,
+        prefix: P,
+        tag: T,
+        mut other: OpenApi,
+    ) -> Vec<MergeConflict> {
+        let tag = tag.as_ref();
+        other
+            .paths
+            .paths
+            .values_mut()
+            .flat_map(|path_item| path_item.operations.values_mut())
+            .map(|operation| operation.tags.get_or_insert_with(Vec::new))
+            .filter(|tags| !tags.iter().any(|existing| existing == tag))
+            .for_each(|tags| tags.push(tag.to_string()));
+
+        let conflicts = self.nest(prefix, other);
+
+        let tags = self.tags.get_or_insert_with(Vec::new);
+        if !tags.iter().any(|existing| existing.name == tag) {
+            tags.push(Tag::new(tag));
+        }
+
+        conflicts
+    }
+
+    /// Validate the [`OpenApi`] document for consistency.
+    ///
+    /// Returns all found problems as [`ValidationError`]s with [JSON pointer][json_pointer]
+    /// location and [`Severity`]. Validation fails if any problem is found, use
+    /// [`ValidationError::severity`] to ignore warnings if needed. Following is validated:
+    ///
+    /// * Local _`#/components/...`_ references resolve to components of the expected type.
+    /// * Operation ids are unique and operation ids of [`Link`]s exist.
+    /// * Path template expressions e.g. _`{id}`_ of _`/pets/{id}`_ are defined as path parameters
+    ///   of every operation of the path and path parameters are part of the path template.
+    /// * Parameters are not defined twice with the same name and location.
+    /// * Security requirements only name security schemes defined in components.
+    /// * Tag names are unique.
+    /// * Operations define at least one response, this is reported as [`Severity::Warning`].
+    ///
+    /// # Examples
+    ///
+    /// Validate derived [`OpenApi`] in unit test.
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[utoipa::path(
+    ///     get,
+    ///     path = "/pets/{id}",
+    ///     responses((status = 200, description = "Pet found")),
+    ///     params(("id" = u64, path, description = "Id of the pet"))
+    /// )]
+    /// fn get_pet(id: u64) {}
+    ///
+    /// #[derive(OpenApi)]
+    /// #[openapi(handlers(get_pet))]
+    /// struct ApiDoc;
+    ///
+    /// assert!(ApiDoc::openapi().validate().is_ok());
+    /// ```
+    ///
+    /// [json_pointer]: https://www.rfc-editor.org/rfc/rfc6901
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let errors = validation::validate(self);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Compare this [`OpenApi`] document to a `new` version of it e.g. committed document of
+    /// the API to freshly generated one.
+    ///
+    /// Returns found [`Change`]s with [JSON pointer][json_pointer] location and
+    /// [`Compatibility`] of the change for existing clients. Following changes are found:
+    ///
+    /// * Removed paths, webhooks, operations, parameters, request bodies, responses, media types
+    ///   and properties. These are always breaking.
+    /// * Added paths, webhooks, operations and other definitions. Added required parameters,
+    ///   request bodies and properties of request schemas are breaking.
+    /// * Changes of parameters, request bodies and properties between required and optional.
+    /// * Changes of schema type, format, enum values and nullability.
+    ///
+    /// Compatibility of schema changes depends on the usage of the schema. Change narrowing
+    /// the allowed values e.g. removing an enum value breaks clients sending requests but not
+    /// clients receiving responses and vice versa. Referenced schemas are therefore compared at
+    /// every location they are used at.
+    ///
+    /// # Examples
+    ///
+    /// Fail if the API has breaking changes.
+    /// ```rust
+    /// # use utoipa::openapi::{
+    /// #     path::{OperationBuilder, PathItemBuilder},
+    /// #     OpenApi, OpenApiBuilder, PathItemType, PathsBuilder,
+    /// # };
+    /// fn openapi(paths: &[&str]) -> OpenApi {
+    ///     let paths = paths.iter().fold(PathsBuilder::new(), |builder, path| {
+    ///         builder.path(
+    ///             *path,
+    ///             PathItemBuilder::new()
+    ///                 .operation(PathItemType::Get, OperationBuilder::new().build())
+    ///                 .build(),
+    ///         )
+    ///     });
+    ///     OpenApiBuilder::new().paths(paths).build()
+    /// }
+    ///
+    /// let committed = openapi(&["/pets", "/pets/{id}"]);
+    /// let generated = openapi(&["/pets"]);
+    ///
+    /// let breaking = committed
+    ///     .diff(&generated)
+    ///     .into_iter()
+    ///     .filter(|change| change.is_breaking())
+    ///     .map(|change| change.to_string())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     breaking,
+    ///     ["breaking change at /paths/~1pets~1{id}: path `/pets/{id}` was removed"]
+    /// );
+    /// ```
+    ///
+    /// [json_pointer]: https://www.rfc-editor.org/rfc/rfc6901
+    pub fn diff(&self, new: &OpenApi) -> Vec<Change> {
+        diff::diff(self, new)
+    }
+
+    /// Inline every [`Ref`] of the document with the referenced component.
+    ///
+    /// References of paths, webhooks and components are replaced with copies of the referenced
+    /// components recursively. This is useful for tools which cannot handle references. Components
+    /// are kept in the document but their references are inlined as well.
+    ///
+    /// Only local references to components e.g. _`#/components/schemas/Pet`_ can be inlined.
+    /// Returns [`DereferenceError`] if a reference does not resolve or references form a cycle
+    /// e.g. with recursive schema. The document is left unchanged if error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Component, ComponentsBuilder, ComponentType, ContentBuilder, Info,
+    /// #     OpenApiBuilder, PathItem, PathItemType, PathsBuilder, Property, Ref, ResponseBuilder};
+    /// # use utoipa::openapi::path::OperationBuilder;
+    /// let mut openapi = OpenApiBuilder::new()
+    ///     .info(Info::new("pet api", "1.0.0"))
+    ///     .paths(PathsBuilder::new().path(
+    ///         "/pets/name",
+    ///         PathItem::new(
+    ///             PathItemType::Get,
+    ///             OperationBuilder::new().response(
+    ///                 "200",
+    ///                 ResponseBuilder::new().description("Pet name").content(
+    ///                     "text/plain",
+    ///                     ContentBuilder::new().schema(Ref::from_component_name("Name")).build(),
+    ///                 ),
+    ///             ),
+    ///         ),
+    ///     ))
+    ///     .components(Some(
+    ///         ComponentsBuilder::new()
+    ///             .component("Name", Property::new(ComponentType::String))
+    ///             .build(),
+    ///     ))
+    ///     .build();
+    ///
+    /// openapi.dereference().unwrap();
+    ///
+    /// let response = openapi.paths.paths["/pets/name"].operations[&PathItemType::Get]
+    ///     .responses
+    ///     .responses["200"]
+    ///     .clone();
+    /// # use utoipa::openapi::RefOr;
+    /// let response = match response {
+    ///     RefOr::T(response) => response,
+    ///     RefOr::Ref(_) => panic!("response is not inline"),
+    /// };
+    /// assert!(matches!(response.content["text/plain"].schema, Component::Property(_)));
+    /// ```
+    pub fn dereference(&mut self) -> Result<(), DereferenceError> {
+        let mut openapi = self.clone();
+        dereference::dereference(&mut openapi)?;
+
+        *self = openapi;
+        Ok(())
+    }
+
+    /// Extract repeated inline object schemas of the document to schemas of [`Components`].
+    ///
+    /// This is the inverse of [`OpenApi::dereference`]. Inline [`Object`]s with properties which
+    /// are equal to an object schema of components are replaced with a [`Ref`] to that schema.
+    /// Inline [`Object`]s which occur more than once in the document are added to schemas of
+    /// components and replaced with a [`Ref`]. Names of the added schemas are derived from
+    /// property names where the objects are found e.g. _`Owner`_ for _`owner`_ property or
+    /// _`Schema`_ if there is no property name. Names are made unique with a numeric suffix.
+    pub fn bundle(&mut self) {
+        bundle::bundle(self)
+    }
+
+    /// Filter the document to operations matching the `predicate`.
+    ///
+    /// The `predicate` is called with path, [`PathItemType`] and [`Operation`][operation] of
+    /// every operation of [`OpenApi::paths`] and operations for which it returns `false` are
+    /// removed. Paths without operations are removed as well. Webhooks are retained as is.
+    ///
+    /// Components, security schemes and tags which are no longer used by the document are
+    /// removed from the filtered document. See [`OpenApi::remove_unused_components`].
+    ///
+    /// # Examples
+    ///
+    /// Publish public and internal API documentation from the same derive.
+    /// ```rust
+    /// # use utoipa::OpenApi;
+    /// #[utoipa::path(get, path = "/pets", tag = "public", responses((status = 200, description = "Pets")))]
+    /// fn list_pets() {}
+    ///
+    /// #[utoipa::path(get, path = "/metrics", tag = "internal", responses((status = 200, description = "Metrics")))]
+    /// fn metrics() {}
+    ///
+    /// #[derive(OpenApi)]
+    /// #[openapi(handlers(list_pets, metrics))]
+    /// struct ApiDoc;
+    ///
+    /// let public = ApiDoc::openapi().filter_by_tag("public");
+    /// let internal = ApiDoc::openapi();
+    ///
+    /// assert!(public.paths.get_path_item("/metrics").is_none());
+    /// assert!(internal.paths.get_path_item("/metrics").is_some());
+    /// ```
+    ///
+    /// [operation]: path/struct.Operation.html
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: FnMut(&str, &PathItemType, &path::Operation) -> bool,
+    {
+        filter::filter(&mut self, predicate);
+        self
+    }
+
+    /// Filter the document to operations having the given `tag`.
+    ///
+    /// See [`OpenApi::filter`] for more details.
+    pub fn filter_by_tag<T: AsRef<str>>(self, tag: T) -> Self {
+        let tag = tag.as_ref();
+        self.filter(|_, _, operation| {
+            operation
+                .tags
+                .iter()
+                .flatten()
+                .any(|operation_tag| operation_tag == tag)
+        })
+    }
+
+    /// Filter the document to operations of paths starting with the given `prefix`.
+    ///
+    /// The prefix matches whole path segments e.g. _`/api`_ matches _`/api`_ and _`/api/pets`_
+    /// but not _`/apis`_. See [`OpenApi::filter`] for more details.
+    pub fn filter_by_path_prefix<P: AsRef<str>>(self, prefix: P) -> Self {
+        let prefix = prefix.as_ref().trim_end_matches('/');
+        self.filter(|path, _, _| {
+            path.strip_prefix(prefix)
+                .map_or(false, |rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+
+    /// Remove components which are not used by the document.
+    ///
+    /// Components are used if they are referenced by [`OpenApi::paths`] or [`OpenApi::webhooks`]
+    /// or by other used components. Security schemes are used if they are named by a
+    /// [`SecurityRequirement`] of the document.
+    pub fn remove_unused_components(&mut self) {
+        filter::remove_unused(self)
+    }
+
+    /// Get operation ids referenced by [`Link::operation_id`]s which do not match any
+    /// [`Operation::operation_id`][operation] in [`OpenApi::paths`].
+    ///
+    /// Links of path operation responses and links and responses of [`Components`] are checked.
+    /// Returned operation ids are sorted and deduplicated.
+    ///
+    /// [operation]: path/struct.Operation.html#structfield.operation_id
+    pub fn unresolved_link_operation_ids(&self) -> Vec<&str> {
+        let operations = self
+            .paths
+            .paths
+            .values()
+            .flat_map(|path_item| path_item.operations.values());
+
+        let operation_ids = operations
+            .clone()
+            .filter_map(|operation| operation.operation_id.as_deref())
+            .collect::<BTreeSet<_>>();
+
+        fn response_links(
+            response: &RefOr<Response>,
+        ) -> Option<impl Iterator<Item = &RefOr<Link>>> {
+            match response {
+                RefOr::T(response) => Some(response.links.values()),
+                RefOr::Ref(_) => None,
+            }
+        }
+
+        let operation_links = operations
+            .flat_map(|operation| operation.responses.responses.values())
+            .filter_map(response_links)
+            .flatten();
+
+        let component_links = self.components.iter().flat_map(|components| {
+            components.links.values().chain(
+                components
+                    .responses
+                    .values()
+                    .filter_map(response_links)
+                    .flatten(),
+            )
+        });
+
+        operation_links
+            .chain(component_links)
+            .filter_map(|link| match link {
+                RefOr::T(link) => link.operation_id.as_deref(),
+                RefOr::Ref(_) => None,
+            })
+            .filter(|operation_id| !operation_ids.contains(operation_id))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     /// Converts this [`OpenApi`] to JSON String. This method essentially calls [`serde_json::to_string`] method. [^json]
     ///
     /// [^json]: **json** feature is needed.
@@ -157,9 +683,345 @@ impl OpenApi {
     pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
         serde_yaml::to_string(self)
     }
+
+    /// Parse [`OpenApi`] from JSON String. [^json]
+    ///
+    /// Returned [`ParseError`] contains the path of the invalid value within the document.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::OpenApi;
+    /// let openapi = OpenApi::from_json(
+    ///     r#"{"openapi": "3.0.3", "info": {"title": "pet api", "version": "0.1.0"}, "paths": {}}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(openapi.info.title, "pet api");
+    ///
+    /// let result = OpenApi::from_json(
+    ///     r#"{"openapi": "3.0.3", "info": {"title": "pet api", "version": 1}, "paths": {}}"#,
+    /// );
+    /// assert!(matches!(result, Err(error) if error.path() == "info.version"));
+    /// ```
+    ///
+    /// [^json]: **json** feature is needed.
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> Result<Self, ParseError> {
+        Self::parse(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    /// Parse [`OpenApi`] from YAML String. [^yaml]
+    ///
+    /// Returned [`ParseError`] contains the path of the invalid value within the document.
+    ///
+    /// [^yaml]: **yaml** feature is needed.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> Result<Self, ParseError> {
+        Self::parse(serde_yaml::Deserializer::from_str(yaml))
+    }
+
+    #[cfg(any(feature = "json", feature = "yaml"))]
+    fn parse<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, ParseError>
+    where
+        D::Error: fmt::Display,
+    {
+        serde_path_to_error::deserialize(deserializer).map_err(ParseError::new)
+    }
+}
+
+/// Error of parsing [`OpenApi`] with _`OpenApi::from_json`_ or _`OpenApi::from_yaml`_.
+///
+/// Error contains the path of the invalid value within the document e.g.
+/// _`paths./pets.get.responses.200.description`_ and the error message.
+#[derive(Clone, Debug)]
+pub struct ParseError {
+    path: String,
+    message: String,
+}
+
+impl ParseError {
+    #[cfg(any(feature = "json", feature = "yaml"))]
+    fn new<E: fmt::Display>(error: serde_path_to_error::Error<E>) -> Self {
+        Self {
+            path: error.path().to_string(),
+            message: error.into_inner().to_string(),
+        }
+    }
+
+    /// Path of the invalid value within the document. Path is _`.`_ if the error is in the root
+    /// of the document.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Message describing the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Conflicting definition found while merging documents with [`OpenApi::merge`] or
+/// [`OpenApi::nest`].
+///
+/// Conflict contains the path of the conflicting definition within the document e.g.
+/// _`components.schemas.Pet`_.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeConflict {
+    path: String,
+}
+
+impl MergeConflict {
+    fn new(path: String) -> Self {
+        Self { path }
+    }
+
+    /// Path of the conflicting definition within the document.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "conflicting definitions of `{}`", self.path)
+    }
+}
+
+fn merge_value<T: PartialEq>(
+    target: &mut Option<T>,
+    value: Option<T>,
+    path: &str,
+    field: &str,
+    conflicts: &mut Vec<MergeConflict>,
+) {
+    match (target.as_ref(), value) {
+        (None, value) => *target = value,
+        (Some(existing), Some(value)) if *existing != value => {
+            conflicts.push(MergeConflict::new(format!("{}.{}", path, field)))
+        }
+        _ => (),
+    }
+}
+
+fn merge_path_items(
+    target: &mut BTreeMap<String, PathItem>,
+    path_items: BTreeMap<String, PathItem>,
+    path: &str,
+    conflicts: &mut Vec<MergeConflict>,
+) {
+    for (name, path_item) in path_items {
+        let item_path = format!("{}.{}", path, name);
+        let existing = match target.entry(name) {
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(path_item);
+                continue;
+            }
+            btree_map::Entry::Occupied(entry) => entry.into_mut(),
+        };
+
+        for (path_item_type, operation) in path_item.operations {
+            match existing.operations.entry(path_item_type) {
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(operation);
+                }
+                btree_map::Entry::Occupied(entry) if *entry.get() != operation => conflicts.push(
+                    MergeConflict::new(format!("{}.{}", item_path, entry.key().as_str())),
+                ),
+                btree_map::Entry::Occupied(_) => (),
+            }
+        }
+
+        merge_value(
+            &mut existing.summary,
+            path_item.summary,
+            &item_path,
+            "summary",
+            conflicts,
+        );
+        merge_value(
+            &mut existing.description,
+            path_item.description,
+            &item_path,
+            "description",
+            conflicts,
+        );
+        merge_value(
+            &mut existing.servers,
+            path_item.servers,
+            &item_path,
+            "servers",
+            conflicts,
+        );
+        merge_value(
+            &mut existing.parameters,
+            path_item.parameters,
+            &item_path,
+            "parameters",
+            conflicts,
+        );
+        merge_value(
+            &mut existing.extensions,
+            path_item.extensions,
+            &item_path,
+            "extensions",
+            conflicts,
+        );
+    }
+}
+
+fn merge_components(
+    target: &mut Components,
+    components: Components,
+    conflicts: &mut Vec<MergeConflict>,
+) {
+    fn merge_map<V: PartialEq>(
+        target: &mut HashMap<String, V>,
+        values: HashMap<String, V>,
+        path: &str,
+        conflicts: &mut Vec<MergeConflict>,
+    ) {
+        for (name, value) in values {
+            match target.entry(name) {
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                hash_map::Entry::Occupied(entry) if *entry.get() != value => conflicts.push(
+                    MergeConflict::new(format!("components.{}.{}", path, entry.key())),
+                ),
+                hash_map::Entry::Occupied(_) => (),
+            }
+        }
+    }
+
+    let Components {
+        schemas,
+        security_schemes,
+        examples,
+        responses,
+        parameters,
+        request_bodies,
+        headers,
+        links,
+        callbacks,
+    } = components;
+
+    merge_map(&mut target.schemas, schemas, "schemas", conflicts);
+    merge_map(
+        &mut target.security_schemes,
+        security_schemes,
+        "securitySchemes",
+        conflicts,
+    );
+    merge_map(&mut target.examples, examples, "examples", conflicts);
+    merge_map(&mut target.responses, responses, "responses", conflicts);
+    merge_map(&mut target.parameters, parameters, "parameters", conflicts);
+    merge_map(
+        &mut target.request_bodies,
+        request_bodies,
+        "requestBodies",
+        conflicts,
+    );
+    merge_map(&mut target.headers, headers, "headers", conflicts);
+    merge_map(&mut target.links, links, "links", conflicts);
+    merge_map(&mut target.callbacks, callbacks, "callbacks", conflicts);
+}
+
+/// Prefix every path of the [`OpenApi`] document with given `prefix`. Root path _`/`_ of the
+/// document becomes the `prefix` itself.
+fn with_path_prefix(prefix: &str, mut openapi: OpenApi) -> OpenApi {
+    let prefix = prefix.trim_end_matches('/');
+
+    openapi.paths.paths = std::mem::take(&mut openapi.paths.paths)
+        .into_iter()
+        .map(|(path, path_item)| {
+            let path = path.trim_start_matches('/');
+            let path = match (prefix, path) {
+                ("", path) => format!("/{}", path),
+                (prefix, "") => prefix.to_string(),
+                (prefix, path) => format!("{}/{}", prefix, path),
+            };
+
+            (path, path_item)
+        })
+        .collect();
+
+    openapi
+}
+
+/// Move global security requirements of the [`OpenApi`] document to every operation of the
+/// document which does not have security requirements of its own.
+fn with_operation_security(mut openapi: OpenApi) -> OpenApi {
+    if let Some(security) = openapi.security.take() {
+        openapi
+            .paths
+            .paths
+            .values_mut()
+            .chain(openapi.webhooks.values_mut())
+            .flat_map(|path_item| path_item.operations.values_mut())
+            .filter(|operation| operation.security.is_none())
+            .for_each(|operation| operation.security = Some(security.clone()));
+    }
+
+    openapi
+}
+
+/// Get JSON pointer of the child `token` of the `pointer` escaping _`~`_ and _`/`_ of the token
+/// as defined by [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901).
+fn pointer_child<T: fmt::Display>(pointer: &str, token: T) -> String {
+    let token = token.to_string().replace('~', "~0").replace('/', "~1");
+    format!("{}/{}", pointer, token)
+}
+
+impl Serialize for OpenApi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.openapi {
+            OpenApiVersion::Version3 => {
+                OpenApi::serialize(&version::to_openapi_30(self), serializer)
+            }
+            #[cfg(feature = "serde_json")]
+            OpenApiVersion::Version31 => {
+                let mut document = OpenApi::serialize(self, serde_json::value::Serializer)
+                    .map_err(serde::ser::Error::custom)?;
+                version::translate_to_openapi_31(&mut document);
+
+                document.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenApi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        OpenApi::deserialize(deserializer)
+    }
 }
 
 impl OpenApiBuilder {
+    /// Add [`OpenApiVersion`] of the document.
+    pub fn openapi(mut self, openapi: OpenApiVersion) -> Self {
+        set_value!(self openapi openapi)
+    }
+
+    /// Add default value of _`$schema`_ keyword of the schemas in the document. This is only
+    /// serialized with [`OpenApiVersion::Version31`].
+    pub fn json_schema_dialect<S: Into<String>>(mut self, json_schema_dialect: Option<S>) -> Self {
+        set_value!(self json_schema_dialect json_schema_dialect.map(|dialect| dialect.into()))
+    }
+
     /// Add [`Info`] metadata of the API.
     pub fn info(mut self, info: Info) -> Self {
         set_value!(self info info)
@@ -175,6 +1037,22 @@ impl OpenApiBuilder {
         set_value!(self paths paths.into())
     }
 
+    /// Add webhook [`PathItem`] identified by unique name. If webhook with the name already exists
+    /// the [`Operation`][operation]s of the [`PathItem`] will be merged with the operations of
+    /// the existing webhook. Webhooks are only serialized with [`OpenApiVersion::Version31`].
+    ///
+    /// [operation]: path/struct.Operation.html
+    pub fn webhook<S: Into<String>>(mut self, name: S, mut path_item: PathItem) -> Self {
+        let name = name.into();
+        if let Some(existing_item) = self.webhooks.get_mut(&name) {
+            existing_item.operations.append(&mut path_item.operations);
+        } else {
+            self.webhooks.insert(name, path_item);
+        }
+
+        self
+    }
+
     /// Add [`Components`] to configure reusable schemas.
     pub fn components(mut self, components: Option<Components>) -> Self {
         set_value!(self components components)
@@ -197,17 +1075,69 @@ impl OpenApiBuilder {
     pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
         set_value!(self external_docs external_docs)
     }
+
+    /// Add or change optional extensions `x-something` of the [`OpenApi`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 /// Represents available [OpenAPI versions][version].
 ///
+/// Version of the [`OpenApi`] document defines how the document is serialized. Objects and fields
+/// only available in OpenAPI 3.1 such as [`Info::summary`], [`License::identifier`],
+/// [`OpenApi::json_schema_dialect`], [`OpenApi::webhooks`], [`Property::const_value`] and
+/// [`Object::defs`] are only serialized with [`OpenApiVersion::Version31`]. Objects serialized
+/// outside of [`OpenApi`] document are serialized as is.
+///
 /// [version]: <https://spec.openapis.org/oas/latest.html#versions>
-#[derive(Serialize, Deserialize, Clone)]
+#[non_exhaustive]
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum OpenApiVersion {
     /// Will serialize to `3.0.3` the latest from 3.0 serde.
     #[serde(rename = "3.0.3")]
     Version3,
+    /// Will serialize to `3.1.0` the latest from 3.1 serde. [^json]
+    ///
+    /// Schemas are serialized as [JSON Schema 2020-12][json_schema] compatible schemas:
+    /// * _`nullable`_ is serialized as _`null`_ type e.g. _`"type": ["string", "null"]`_.
+    /// * _`example`_ is serialized as _`examples`_ array.
+    /// * Boolean _`exclusiveMinimum`_ and _`exclusiveMaximum`_ are serialized as numeric limits.
+    ///
+    /// [json_schema]: https://json-schema.org/draft/2020-12/release-notes.html
+    /// [^json]: **json** feature is needed.
+    #[cfg(feature = "serde_json")]
+    #[serde(rename = "3.1.0")]
+    Version31,
+}
+
+impl<'de> Deserialize<'de> for OpenApiVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let version = String::deserialize(deserializer)?;
+        let is_version = |minor: &str| {
+            version == minor
+                || version
+                    .strip_prefix(minor)
+                    .map_or(false, |patch| patch.starts_with('.'))
+        };
+
+        if is_version("3.0") {
+            return Ok(Self::Version3);
+        }
+        #[cfg(feature = "serde_json")]
+        if is_version("3.1") {
+            return Ok(Self::Version31);
+        }
+
+        Err(serde::de::Error::custom(format!(
+            "unsupported OpenAPI version `{}`",
+            version
+        )))
+    }
 }
 
 impl Default for OpenApiVersion {
@@ -235,39 +1165,154 @@ impl Serialize for Deprecated {
     }
 }
 
-impl<'de> Deserialize<'de> for Deprecated {
+impl<'de> Deserialize<'de> for Deprecated {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct BoolVisitor;
+        impl<'de> Visitor<'de> for BoolVisitor {
+            type Value = Deprecated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a bool true or false")
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match v {
+                    true => Ok(Deprecated::True),
+                    false => Ok(Deprecated::False),
+                }
+            }
+        }
+        deserializer.deserialize_bool(BoolVisitor)
+    }
+}
+
+impl Default for Deprecated {
+    fn default() -> Self {
+        Deprecated::False
+    }
+}
+
+/// Either a [`Ref`] to a reusable object defined in [`Components`] or the object `T` itself.
+///
+/// This is used where OpenAPI specification allows defining the object either inline or as
+/// a [Reference Object][reference].
+///
+/// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
+#[derive(Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(untagged)]
+pub enum RefOr<T> {
+    Ref(Ref),
+    T(T),
+}
+
+/// Objects starting with _`$ref`_ are deserialized as [`Ref`] and other objects as `T` so that
+/// errors of `T` are reported as is instead of untagged enum error. The object is deserialized
+/// without buffering so that the deserializer knows the path of the possible error.
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for RefOr<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        D: serde::Deserializer<'de>,
+        D: Deserializer<'de>,
     {
-        struct BoolVisitor;
-        impl<'de> Visitor<'de> for BoolVisitor {
-            type Value = Deprecated;
+        struct RefOrVisitor<T>(std::marker::PhantomData<T>);
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("a bool true or false")
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for RefOrVisitor<T> {
+            type Value = RefOr<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("reference or object")
             }
 
-            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match v {
-                    true => Ok(Deprecated::True),
-                    false => Ok(Deprecated::False),
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let first_key = map.next_key::<String>()?;
+                let is_ref = first_key.as_deref() == Some("$ref");
+                let map = MapAccessDeserializer::new(KeyCheckingMap { first_key, map });
+
+                if is_ref {
+                    Ref::deserialize(map).map(RefOr::Ref)
+                } else {
+                    T::deserialize(map).map(RefOr::T)
                 }
             }
         }
-        deserializer.deserialize_bool(BoolVisitor)
+
+        deserializer.deserialize_map(RefOrVisitor(std::marker::PhantomData))
     }
 }
 
-impl Default for Deprecated {
-    fn default() -> Self {
-        Deprecated::False
+/// [`MapAccess`] which gives the already read first key of the map before the rest of the keys.
+/// Objects which are not references cannot have _`$ref`_ after the first key.
+struct KeyCheckingMap<A> {
+    first_key: Option<String>,
+    map: A,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for KeyCheckingMap<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let key = match self.first_key.take() {
+            Some(key) => key,
+            None => match self.map.next_key::<String>()? {
+                Some(key) if key == "$ref" => {
+                    return Err(de::Error::custom(
+                        "`$ref` must be the first keyword of the reference",
+                    ))
+                }
+                Some(key) => key,
+                None => return Ok(None),
+            },
+        };
+
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.map.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map
+            .size_hint()
+            .map(|size| size + usize::from(self.first_key.is_some()))
+    }
+}
+
+impl<T> From<Ref> for RefOr<T> {
+    fn from(r: Ref) -> Self {
+        Self::Ref(r)
     }
 }
 
+macro_rules! ref_or_from {
+    ( $name:ident $builder:ident ) => {
+        impl From<$name> for $crate::openapi::RefOr<$name> {
+            fn from(value: $name) -> Self {
+                Self::T(value)
+            }
+        }
+
+        impl From<$builder> for $crate::openapi::RefOr<$name> {
+            fn from(builder: $builder) -> Self {
+                Self::T(builder.build())
+            }
+        }
+    };
+}
+pub(crate) use ref_or_from;
+
 /// Value used to indicate whether parameter or property is required.
 ///
 /// The value will serialize to boolean.
@@ -417,14 +1462,20 @@ pub(crate) use builder;
 mod tests {
     use crate::openapi::{
         info::InfoBuilder,
-        path::{OperationBuilder, PathsBuilder},
+        path::{OperationBuilder, PathItemBuilder, PathsBuilder},
+        tag::TagBuilder,
     };
 
     use super::{response::Response, *};
 
     #[test]
     fn serialize_deserialize_openapi_version_success() -> Result<(), serde_json::Error> {
-        assert_eq!(serde_json::to_value(&OpenApiVersion::Version3)?, "3.0.3");
+        assert_eq!(serde_json::to_value(OpenApiVersion::Version3)?, "3.0.3");
+        assert_eq!(serde_json::to_value(OpenApiVersion::Version31)?, "3.1.0");
+        assert!(
+            serde_json::from_value::<OpenApiVersion>(serde_json::json!("3.1.0"))?
+                == OpenApiVersion::Version31
+        );
         Ok(())
     }
 
@@ -493,4 +1544,752 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn openapi_unresolved_link_operation_ids() {
+        let openapi = OpenApi::new(
+            Info::new("My api", "1.0.0"),
+            PathsBuilder::new()
+                .path(
+                    "/pets",
+                    PathItem::new(
+                        PathItemType::Post,
+                        OperationBuilder::new()
+                            .operation_id(Some("create_pet"))
+                            .response(
+                                "201",
+                                response::ResponseBuilder::new()
+                                    .description("Pet created")
+                                    .link(
+                                        "GetPet",
+                                        LinkBuilder::new().operation_id(Some("get_pet")),
+                                    )
+                                    .link(
+                                        "DeletePet",
+                                        LinkBuilder::new().operation_id(Some("delete_pet")),
+                                    ),
+                            ),
+                    ),
+                )
+                .path(
+                    "/pets/{id}",
+                    PathItem::new(
+                        PathItemType::Get,
+                        OperationBuilder::new().operation_id(Some("get_pet")),
+                    ),
+                ),
+        );
+
+        assert_eq!(openapi.unresolved_link_operation_ids(), ["delete_pet"]);
+    }
+
+    fn openapi_with_version_specific_fields(version: OpenApiVersion) -> OpenApi {
+        OpenApiBuilder::new()
+            .openapi(version)
+            .json_schema_dialect(Some("https://spec.openapis.org/oas/3.1/dialect/base"))
+            .webhook(
+                "petCreated",
+                PathItem::new(
+                    PathItemType::Post,
+                    OperationBuilder::new().operation_id(Some("pet_created")),
+                ),
+            )
+            .info(
+                InfoBuilder::new()
+                    .title("Pet api")
+                    .version("1.0.0")
+                    .summary(Some("Api for pets"))
+                    .license(Some(
+                        LicenseBuilder::new()
+                            .name("MIT")
+                            .identifier(Some("MIT"))
+                            .build(),
+                    ))
+                    .build(),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .component(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property(
+                                "name",
+                                PropertyBuilder::new()
+                                    .component_type(ComponentType::String)
+                                    .nullable(Some(true))
+                                    .example(Some(serde_json::json!("bob"))),
+                            )
+                            .property(
+                                "kind",
+                                PropertyBuilder::new()
+                                    .component_type(ComponentType::String)
+                                    .const_value(Some(serde_json::json!("pet"))),
+                            )
+                            .property(
+                                "age",
+                                PropertyBuilder::new()
+                                    .component_type(ComponentType::Integer)
+                                    .minimum(Some(0.0))
+                                    .exclusive_minimum(Some(true)),
+                            )
+                            .def(
+                                "Owner",
+                                ObjectBuilder::new().property(
+                                    "name",
+                                    PropertyBuilder::new().component_type(ComponentType::String),
+                                ),
+                            ),
+                    )
+                    .build(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn serialize_openapi_31_version_specific_fields() {
+        let openapi = openapi_with_version_specific_fields(OpenApiVersion::Version31);
+        let value = serde_json::to_value(&openapi).unwrap();
+
+        assert_eq!(value["openapi"], "3.1.0");
+        assert_eq!(
+            value["jsonSchemaDialect"],
+            "https://spec.openapis.org/oas/3.1/dialect/base"
+        );
+        assert_eq!(
+            value["webhooks"]["petCreated"]["post"]["operationId"],
+            "pet_created"
+        );
+        assert_eq!(value["info"]["summary"], "Api for pets");
+        assert_eq!(value["info"]["license"]["identifier"], "MIT");
+
+        let pet = &value["components"]["schemas"]["Pet"];
+        assert_eq!(
+            pet["properties"]["name"],
+            serde_json::json!({ "type": ["string", "null"], "examples": ["bob"] })
+        );
+        assert_eq!(pet["properties"]["kind"]["const"], "pet");
+        assert_eq!(
+            pet["properties"]["age"],
+            serde_json::json!({ "type": "integer", "exclusiveMinimum": 0.0 })
+        );
+        assert_eq!(
+            pet["$defs"]["Owner"]["properties"]["name"]["type"],
+            "string"
+        );
+    }
+
+    #[test]
+    fn serialize_openapi_30_skips_openapi_31_fields() {
+        let openapi = openapi_with_version_specific_fields(OpenApiVersion::Version3);
+        let value = serde_json::to_value(&openapi).unwrap();
+
+        assert_eq!(value["openapi"], "3.0.3");
+        assert!(value.get("jsonSchemaDialect").is_none());
+        assert!(value.get("webhooks").is_none());
+        assert!(value["info"].get("summary").is_none());
+        assert!(value["info"]["license"].get("identifier").is_none());
+
+        let pet = &value["components"]["schemas"]["Pet"];
+        assert_eq!(
+            pet["properties"]["name"],
+            serde_json::json!({ "type": "string", "nullable": true, "example": "bob" })
+        );
+        assert!(pet["properties"]["kind"].get("const").is_none());
+        assert_eq!(pet["properties"]["age"]["exclusiveMinimum"], true);
+        assert!(pet.get("$defs").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn serialize_openapi_31_yaml_same_as_json() {
+        let openapi = openapi_with_version_specific_fields(OpenApiVersion::Version31);
+        let yaml = openapi.to_yaml().unwrap();
+
+        assert_eq!(
+            serde_yaml::from_str::<serde_json::Value>(&yaml).unwrap(),
+            serde_json::to_value(&openapi).unwrap()
+        );
+    }
+
+    #[test]
+    fn serialize_deserialize_openapi_with_extensions() -> Result<(), serde_json::Error> {
+        let extensions =
+            |value: &str| Some(ExtensionsBuilder::new().add("x-internal-id", value).build());
+        let openapi = OpenApiBuilder::new()
+            .info(
+                InfoBuilder::new()
+                    .title("My api")
+                    .version("1.0.0")
+                    .extensions(extensions("info"))
+                    .build(),
+            )
+            .paths(
+                PathsBuilder::new().path(
+                    "/api/v1/users",
+                    PathItemBuilder::new()
+                        .operation(
+                            PathItemType::Get,
+                            OperationBuilder::new()
+                                .response(
+                                    "200",
+                                    ResponseBuilder::new()
+                                        .description("Get users list")
+                                        .extensions(extensions("response")),
+                                )
+                                .extensions(extensions("operation")),
+                        )
+                        .extensions(extensions("path_item"))
+                        .build(),
+                ),
+            )
+            .tags(Some([TagBuilder::new()
+                .name("users")
+                .extensions(extensions("tag"))
+                .build()]))
+            .extensions(extensions("openapi"))
+            .build();
+
+        let value = serde_json::to_value(&openapi)?;
+        assert_eq!(value["x-internal-id"], "openapi");
+        assert_eq!(value["info"]["x-internal-id"], "info");
+        assert_eq!(value["tags"][0]["x-internal-id"], "tag");
+
+        let path_item = &value["paths"]["/api/v1/users"];
+        assert_eq!(path_item["x-internal-id"], "path_item");
+        assert_eq!(path_item["get"]["x-internal-id"], "operation");
+        assert_eq!(
+            path_item["get"]["responses"]["200"]["x-internal-id"],
+            "response"
+        );
+
+        let deserialized = serde_json::from_value::<OpenApi>(value.clone())?;
+        assert_eq!(serde_json::to_value(&deserialized)?, value);
+        Ok(())
+    }
+
+    /// Normalize parts of the document which are not expected to survive the round trip as is.
+    /// OpenAPI 3.0 documents are always serialized as version _`3.0.3`_ and numbers are compared
+    /// as floats since limits e.g. _`maximum`_ are modeled as floats.
+    fn normalize(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(object) => {
+                if let Some(serde_json::Value::String(version)) = object.get_mut("openapi") {
+                    if version.starts_with("3.0.") {
+                        *version = "3.0.3".to_string();
+                    }
+                }
+                object.values_mut().for_each(normalize);
+            }
+            serde_json::Value::Array(array) => array.iter_mut().for_each(normalize),
+            serde_json::Value::Number(number) => {
+                if let Some(number) = number.as_f64().and_then(serde_json::Number::from_f64) {
+                    *value = serde_json::Value::Number(number);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn assert_round_trip(openapi: &OpenApi, mut expected: serde_json::Value) {
+        let mut serialized = serde_json::to_value(openapi).unwrap();
+        normalize(&mut serialized);
+        normalize(&mut expected);
+        assert_eq!(
+            serialized,
+            expected,
+            "serialized OpenAPI document did not match the parsed document: {}",
+            serde_json::to_string_pretty(&serialized).unwrap()
+        );
+    }
+
+    fn assert_json_round_trip(json: &str) -> Result<(), ParseError> {
+        let openapi = OpenApi::from_json(json)?;
+
+        assert_round_trip(&openapi, serde_json::from_str(json).unwrap());
+        Ok(())
+    }
+
+    #[cfg(feature = "yaml")]
+    fn assert_yaml_round_trip(yaml: &str) -> Result<(), ParseError> {
+        let openapi = OpenApi::from_yaml(yaml)?;
+        assert_round_trip(&openapi, serde_yaml::from_str(yaml).unwrap());
+
+        let reparsed = OpenApi::from_yaml(&openapi.to_yaml().unwrap())?;
+        assert_eq!(
+            serde_json::to_value(&reparsed).unwrap(),
+            serde_json::to_value(&openapi).unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_openapi_json_petstore_expanded_round_trip() -> Result<(), ParseError> {
+        assert_json_round_trip(include_str!("openapi/testdata/petstore-expanded.json"))
+    }
+
+    #[test]
+    fn parse_openapi_json_petstore_features_round_trip() -> Result<(), ParseError> {
+        assert_json_round_trip(include_str!("openapi/testdata/petstore-features.json"))
+    }
+
+    #[test]
+    fn parse_openapi_31_json_petstore_features_round_trip() -> Result<(), ParseError> {
+        assert_json_round_trip(include_str!("openapi/testdata/petstore-features-3.1.json"))
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn parse_openapi_yaml_petstore_round_trip() -> Result<(), ParseError> {
+        assert_yaml_round_trip(include_str!("openapi/testdata/petstore.yaml"))
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn parse_openapi_yaml_petstore_expanded_round_trip() -> Result<(), ParseError> {
+        assert_yaml_round_trip(include_str!("openapi/testdata/petstore-expanded.yaml"))
+    }
+
+    fn from_json_error(json: &str) -> ParseError {
+        match OpenApi::from_json(json) {
+            Ok(_) => panic!("expected parsing the OpenAPI document to fail"),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn parse_openapi_json_unsupported_version() {
+        let error = from_json_error(
+            r#"{"openapi": "2.0", "info": {"title": "api", "version": "1.0.0"}, "paths": {}}"#,
+        );
+
+        assert_eq!(error.path(), "openapi");
+        assert!(
+            error
+                .message()
+                .contains("unsupported OpenAPI version `2.0`"),
+            "unexpected message: {}",
+            error.message()
+        );
+    }
+
+    #[test]
+    fn parse_openapi_json_reports_schema_error_path() {
+        let json = r#"{
+            "openapi": "3.0.3",
+            "info": {"title": "api", "version": "1.0.0"},
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "properties": {
+                            "name": {"type": "text"}
+                        }
+                    }
+                }
+            }
+        }"#;
+        let error = from_json_error(json);
+
+        assert_eq!(error.path(), "components.schemas.Pet.properties.name.type");
+        assert!(
+            error.message().contains("unknown variant `text`"),
+            "unexpected message: {}",
+            error.message()
+        );
+    }
+
+    fn schemas_document(schemas: serde_json::Value) -> String {
+        serde_json::json!({
+            "openapi": "3.0.3",
+            "info": {"title": "api", "version": "1.0.0"},
+            "paths": {},
+            "components": {"schemas": schemas}
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn parse_openapi_json_schema_keywords_round_trip() -> Result<(), ParseError> {
+        let schemas = serde_json::json!({
+            "AllOf": {
+                "allOf": [{"$ref": "#/components/schemas/AnyOf"}, {"type": "object"}],
+                "nullable": true,
+                "description": "all of",
+                "discriminator": {"propertyName": "kind"}
+            },
+            "AnyOf": {
+                "anyOf": [{"type": "string"}, {"type": "integer"}],
+                "deprecated": true
+            },
+            "OneOf": {
+                "oneOf": [{"type": "string"}, {"type": "integer"}],
+                "nullable": true,
+                "discriminator": {
+                    "propertyName": "kind",
+                    "mapping": {"dog": "#/components/schemas/Dog"}
+                }
+            },
+            "FreeForm": {"type": "object", "additionalProperties": true},
+            "Closed": {"type": "object", "additionalProperties": false},
+            "Map": {"type": "object", "additionalProperties": {"type": "string"}},
+            "Unique": {"type": "array", "items": {"type": "string"}, "uniqueItems": true},
+            "Numbers": {"type": "number", "enum": [1, 2.5, null], "nullable": true},
+            "Booleans": {"type": "boolean", "enum": [true]}
+        });
+
+        assert_json_round_trip(&schemas_document(schemas))
+    }
+
+    #[test]
+    fn parse_openapi_json_reports_schema_error_path_within_combinators() {
+        let json = schemas_document(serde_json::json!({
+            "Pet": {
+                "allOf": [
+                    {"$ref": "#/components/schemas/NewPet"},
+                    {
+                        "type": "object",
+                        "additionalProperties": {
+                            "anyOf": [{"type": "string"}, {"type": "text"}]
+                        }
+                    }
+                ]
+            }
+        }));
+        let error = from_json_error(&json);
+
+        assert_eq!(
+            error.path(),
+            "components.schemas.Pet.allOf[1].additionalProperties.anyOf[1].type"
+        );
+        assert!(
+            error.message().contains("unknown variant `text`"),
+            "unexpected message: {}",
+            error.message()
+        );
+    }
+
+    #[test]
+    fn parse_openapi_json_reports_error_path_within_reference_or_object() {
+        let json = r#"{
+            "openapi": "3.0.3",
+            "info": {"title": "api", "version": "1.0.0"},
+            "paths": {},
+            "components": {
+                "parameters": {
+                    "Limit": {"$ref": 10},
+                    "Offset": {"name": "offset", "in": "body"}
+                }
+            }
+        }"#;
+        let error = from_json_error(json);
+
+        assert_eq!(error.path(), "components.parameters.Limit.$ref");
+    }
+
+    #[test]
+    fn parse_openapi_json_rejects_reference_not_first() {
+        let json = r##"{
+            "openapi": "3.0.3",
+            "info": {"title": "api", "version": "1.0.0"},
+            "paths": {},
+            "components": {
+                "responses": {
+                    "Error": {"description": "error", "$ref": "#/components/responses/Other"}
+                }
+            }
+        }"##;
+        let error = from_json_error(json);
+
+        assert_eq!(error.path(), "components.responses.Error");
+        assert!(
+            error
+                .message()
+                .contains("`$ref` must be the first keyword of the reference"),
+            "unexpected message: {}",
+            error.message()
+        );
+    }
+
+    #[test]
+    fn parse_openapi_json_reports_response_error_path() {
+        let json = r#"{
+            "openapi": "3.0.3",
+            "info": {"title": "api", "version": "1.0.0"},
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "responses": {
+                            "200": {"description": 200}
+                        }
+                    }
+                }
+            }
+        }"#;
+        let error = from_json_error(json);
+
+        assert_eq!(error.path(), "paths./pets.get.responses.200.description");
+        assert!(
+            error.to_string().starts_with(
+                "paths./pets.get.responses.200.description: invalid type: integer `200`"
+            ),
+            "unexpected error: {}",
+            error
+        );
+    }
+
+    fn pet_api(operation_id: &str, pet: Component) -> OpenApi {
+        OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(PathsBuilder::new().path(
+                "/pets",
+                PathItem::new(
+                    PathItemType::Get,
+                    OperationBuilder::new().operation_id(Some(operation_id)),
+                ),
+            ))
+            .components(Some(ComponentsBuilder::new().component("Pet", pet).build()))
+            .tags(Some([Tag::new("pet")]))
+            .security(Some([SecurityRequirement::new("api_key", ["read:pets"])]))
+            .build()
+    }
+
+    #[test]
+    fn merge_openapi_without_conflicts() {
+        let mut openapi = pet_api("listPets", Property::new(ComponentType::String).into());
+        let store = OpenApiBuilder::new()
+            .info(Info::new("store api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(PathItemType::Post, OperationBuilder::new()),
+                    )
+                    .path(
+                        "/store",
+                        PathItem::new(PathItemType::Get, OperationBuilder::new()),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .component("Pet", Property::new(ComponentType::String))
+                    .component("Order", Object::new())
+                    .build(),
+            ))
+            .tags(Some([Tag::new("pet"), Tag::new("store")]))
+            .security(Some([
+                SecurityRequirement::new("api_key", ["read:pets"]),
+                SecurityRequirement::default(),
+            ]))
+            .build();
+
+        let conflicts = openapi.merge(store);
+
+        assert!(
+            conflicts.is_empty(),
+            "unexpected conflicts: {:?}",
+            conflicts
+        );
+        assert_eq!(openapi.info.title, "pet api");
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/pets", "/store"]
+        );
+        assert_eq!(
+            openapi.paths.paths["/pets"]
+                .operations
+                .keys()
+                .map(PathItemType::as_str)
+                .collect::<Vec<_>>(),
+            ["get", "post"]
+        );
+        let components = openapi.components.as_ref().unwrap();
+        assert!(components.schemas.contains_key("Pet"));
+        assert!(components.schemas.contains_key("Order"));
+        assert_eq!(
+            openapi
+                .tags
+                .iter()
+                .flatten()
+                .map(|tag| &*tag.name)
+                .collect::<Vec<_>>(),
+            ["pet", "store"]
+        );
+        assert_eq!(openapi.security.as_ref().map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn merge_openapi_reports_conflicts() {
+        let mut openapi = pet_api("listPets", Property::new(ComponentType::String).into());
+        let mut other = pet_api("findPets", Object::new().into());
+        other.tags = Some(vec![TagBuilder::new()
+            .name("pet")
+            .description(Some("Pet operations"))
+            .build()]);
+
+        let conflicts = openapi.merge(other);
+
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(MergeConflict::path)
+                .collect::<Vec<_>>(),
+            ["paths./pets.get", "components.schemas.Pet", "tags.pet"]
+        );
+        assert_eq!(
+            conflicts[0].to_string(),
+            "conflicting definitions of `paths./pets.get`"
+        );
+        assert_eq!(
+            openapi.paths.paths["/pets"].operations[&PathItemType::Get]
+                .operation_id
+                .as_deref(),
+            Some("listPets")
+        );
+    }
+
+    #[test]
+    fn merge_deserialized_openapi_without_conflicts() {
+        let mut openapi = pet_api("listPets", Property::new(ComponentType::String).into());
+        let deserialized = OpenApi::from_json(&openapi.to_json().unwrap()).unwrap();
+
+        assert!(deserialized.extensions.is_none());
+        assert!(deserialized.info.extensions.is_none());
+        assert!(deserialized.paths.paths["/pets"].extensions.is_none());
+        assert!(
+            deserialized.paths.paths["/pets"].operations[&PathItemType::Get]
+                .extensions
+                .is_none()
+        );
+
+        let conflicts = openapi.merge(deserialized);
+
+        assert!(
+            conflicts.is_empty(),
+            "unexpected conflicts: {:?}",
+            conflicts
+        );
+    }
+
+    #[test]
+    fn nest_openapi_with_tag() {
+        let mut openapi = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+        let pets = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/",
+                        PathItem::new(PathItemType::Get, OperationBuilder::new()),
+                    )
+                    .path(
+                        "/{id}",
+                        PathItem::new(
+                            PathItemType::Get,
+                            OperationBuilder::new().tags(Some(["pets".to_string()])),
+                        ),
+                    ),
+            )
+            .build();
+
+        let conflicts = openapi.nest_with_tag("/api/v1/pets/", "pets", pets);
+
+        assert!(
+            conflicts.is_empty(),
+            "unexpected conflicts: {:?}",
+            conflicts
+        );
+        let tags = openapi
+            .paths
+            .paths
+            .iter()
+            .map(|(path, path_item)| {
+                (
+                    &**path,
+                    path_item.operations[&PathItemType::Get].tags.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            [
+                ("/api/v1/pets", Some(vec!["pets".to_string()])),
+                ("/api/v1/pets/{id}", Some(vec!["pets".to_string()])),
+            ]
+        );
+        assert!(openapi.tags == Some(vec![Tag::new("pets")]));
+    }
+
+    #[test]
+    fn nest_openapi_with_tag_keeps_defined_tag() {
+        let mut openapi = OpenApi::new(Info::new("api", "1.0.0"), Paths::new());
+        let pets_tag = TagBuilder::new()
+            .name("pets")
+            .description(Some("Everything about your pets"))
+            .build();
+        let pets = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(PathsBuilder::new().path(
+                "/",
+                PathItem::new(PathItemType::Get, OperationBuilder::new()),
+            ))
+            .tags(Some([pets_tag.clone()]))
+            .build();
+
+        let conflicts = openapi.nest_with_tag("/pets", "pets", pets);
+
+        assert!(
+            conflicts.is_empty(),
+            "unexpected conflicts: {:?}",
+            conflicts
+        );
+        assert!(openapi.tags == Some(vec![pets_tag]));
+    }
+
+    #[test]
+    fn nest_openapi_moves_global_security_to_nested_operations() {
+        let api_key = SecurityRequirement::new("api_key", ["read:pets"]);
+        let mut openapi = pet_api("listPets", Property::new(ComponentType::String).into());
+        let store = OpenApiBuilder::new()
+            .info(Info::new("store api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/orders",
+                        PathItem::new(PathItemType::Get, OperationBuilder::new()),
+                    )
+                    .path(
+                        "/inventory",
+                        PathItem::new(
+                            PathItemType::Get,
+                            OperationBuilder::new().security(SecurityRequirement::default()),
+                        ),
+                    ),
+            )
+            .security(Some([SecurityRequirement::new("oauth", ["write:store"])]))
+            .build();
+
+        let conflicts = openapi.nest("/store", store);
+
+        assert!(
+            conflicts.is_empty(),
+            "unexpected conflicts: {:?}",
+            conflicts
+        );
+        assert_eq!(openapi.security, Some(vec![api_key]));
+        let operation_security = |path: &str| {
+            openapi.paths.paths[path].operations[&PathItemType::Get]
+                .security
+                .clone()
+        };
+        assert_eq!(operation_security("/pets"), None);
+        assert_eq!(
+            operation_security("/store/orders"),
+            Some(vec![SecurityRequirement::new("oauth", ["write:store"])])
+        );
+        assert_eq!(
+            operation_security("/store/inventory"),
+            Some(vec![SecurityRequirement::default()])
+        );
+    }
 }
@@ -0,0 +1,485 @@
+//! Implements extraction of repeated inline object schemas of [`OpenApi`] documents to
+//! components. See [`OpenApi::bundle`] for more details.
+use std::collections::HashMap;
+
+use super::{
+    path::PathItem,
+    schema::{AdditionalProperties, AllOf, AnyOf, OneOf},
+    Component, Components, Content, Header, Object, OpenApi, Ref, RefOr, Response,
+};
+
+/// Replace inline [`Object`]s which are equal to a schema of components or which are repeated in
+/// the document with a [`Ref`] to the schema of components.
+pub(super) fn bundle(openapi: &mut OpenApi) {
+    loop {
+        let mut counts = Vec::<(Object, usize)>::new();
+        for (root, _) in schema_roots(openapi) {
+            count_objects(root, &mut counts);
+        }
+
+        let schemas = openapi
+            .components
+            .as_ref()
+            .map(|components| &components.schemas);
+        let mut bundler = Bundler {
+            named: schemas
+                .into_iter()
+                .flatten()
+                .filter_map(|(name, schema)| match schema {
+                    Component::Object(object) => Some((name.clone(), object.clone())),
+                    _ => None,
+                })
+                .collect(),
+            taken: schemas
+                .into_iter()
+                .flatten()
+                .map(|(name, _)| name.clone())
+                .collect(),
+            counts,
+            created: Vec::new(),
+            replaced: false,
+        };
+
+        for (root, hint) in schema_roots(openapi) {
+            bundler.replace(root, &hint);
+        }
+
+        if !bundler.replaced {
+            break;
+        }
+        openapi
+            .components
+            .get_or_insert_with(Components::new)
+            .schemas
+            .extend(
+                bundler
+                    .created
+                    .into_iter()
+                    .map(|(name, object)| (name, Component::Object(object))),
+            );
+    }
+}
+
+/// Object is bundled only if it has properties. Free form objects are left inline.
+fn is_bundled(object: &Object) -> bool {
+    !object.properties.is_empty()
+}
+
+fn count_objects(schema: &Component, counts: &mut Vec<(Object, usize)>) {
+    if let Component::Object(object) = schema {
+        if is_bundled(object) {
+            match counts.iter_mut().find(|(counted, _)| counted == object) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((object.clone(), 1)),
+            }
+        }
+    }
+
+    match schema {
+        Component::Object(object) => object
+            .properties
+            .values()
+            .chain(match object.additional_properties.as_deref() {
+                Some(AdditionalProperties::Component(child)) => Some(child),
+                _ => None,
+            })
+            .chain(object.defs.values())
+            .for_each(|child| count_objects(child, counts)),
+        Component::Array(array) => count_objects(&array.items, counts),
+        Component::OneOf(OneOf { items, .. })
+        | Component::AllOf(AllOf { items, .. })
+        | Component::AnyOf(AnyOf { items, .. }) => {
+            items.iter().for_each(|child| count_objects(child, counts))
+        }
+        Component::Property(_) | Component::Ref(_) => (),
+    }
+}
+
+/// Get mutable child schemas of the schema with name hints of the children. Children are
+/// sorted by the name hint to keep created component names stable.
+fn children<'a>(schema: &'a mut Component, hint: &str) -> Vec<(&'a mut Component, String)> {
+    match schema {
+        Component::Object(object) => {
+            let mut children = object
+                .properties
+                .iter_mut()
+                .chain(object.defs.iter_mut())
+                .map(|(name, child)| (child, name.clone()))
+                .collect::<Vec<_>>();
+            children.sort_by(|(_, a), (_, b)| a.cmp(b));
+            if let Some(AdditionalProperties::Component(child)) =
+                object.additional_properties.as_deref_mut()
+            {
+                children.push((child, format!("{}Value", hint)));
+            }
+            children
+        }
+        Component::Array(array) => vec![(&mut *array.items, hint.to_string())],
+        Component::OneOf(OneOf { items, .. })
+        | Component::AllOf(AllOf { items, .. })
+        | Component::AnyOf(AnyOf { items, .. }) => items
+            .iter_mut()
+            .map(|child| (child, hint.to_string()))
+            .collect(),
+        Component::Property(_) | Component::Ref(_) => Vec::new(),
+    }
+}
+
+/// Get mutable schemas of the document which are not schemas of components. Children of the
+/// schemas of components are included instead.
+fn schema_roots(openapi: &mut OpenApi) -> Vec<(&mut Component, String)> {
+    let mut roots = Vec::new();
+
+    for path_item in openapi
+        .paths
+        .paths
+        .values_mut()
+        .chain(openapi.webhooks.values_mut())
+    {
+        path_item_roots(path_item, &mut roots);
+    }
+
+    if let Some(components) = openapi.components.as_mut() {
+        for (name, schema) in sorted(&mut components.schemas) {
+            let hint = name.clone();
+            roots.extend(children(schema, &hint));
+        }
+        for (_, response) in sorted(&mut components.responses) {
+            if let RefOr::T(response) = response {
+                response_roots(response, &mut roots);
+            }
+        }
+        for (_, parameter) in sorted(&mut components.parameters) {
+            if let RefOr::T(parameter) = parameter {
+                roots.extend(
+                    parameter
+                        .schema
+                        .as_mut()
+                        .map(|schema| (schema, String::new())),
+                );
+            }
+        }
+        for (_, request_body) in sorted(&mut components.request_bodies) {
+            if let RefOr::T(request_body) = request_body {
+                content_roots(request_body.content.values_mut(), &mut roots);
+            }
+        }
+        for (_, header) in sorted(&mut components.headers) {
+            if let RefOr::T(header) = header {
+                header_roots(header, &mut roots);
+            }
+        }
+        for (_, callback) in sorted(&mut components.callbacks) {
+            if let RefOr::T(callback) = callback {
+                for path_item in callback.callbacks.values_mut() {
+                    path_item_roots(path_item, &mut roots);
+                }
+            }
+        }
+    }
+
+    roots
+}
+
+fn sorted<V>(map: &mut HashMap<String, V>) -> Vec<(&String, &mut V)> {
+    let mut entries = map.iter_mut().collect::<Vec<_>>();
+    entries.sort_by_key(|(name, _)| *name);
+    entries
+}
+
+fn path_item_roots<'a>(path_item: &'a mut PathItem, roots: &mut Vec<(&'a mut Component, String)>) {
+    let path_parameters = path_item.parameters.iter_mut().flatten();
+    let operations = path_item.operations.values_mut().collect::<Vec<_>>();
+
+    let mut parameters = path_parameters.collect::<Vec<_>>();
+    let mut responses = Vec::new();
+    let mut request_bodies = Vec::new();
+    let mut callbacks = Vec::new();
+    for operation in operations {
+        parameters.extend(operation.parameters.iter_mut().flatten());
+        request_bodies.extend(operation.request_body.as_mut());
+        responses.extend(operation.responses.responses.values_mut());
+        callbacks.extend(operation.callbacks.iter_mut().flat_map(|c| c.values_mut()));
+    }
+
+    for parameter in parameters {
+        if let RefOr::T(parameter) = parameter {
+            roots.extend(
+                parameter
+                    .schema
+                    .as_mut()
+                    .map(|schema| (schema, String::new())),
+            );
+        }
+    }
+    for request_body in request_bodies {
+        content_roots(request_body.content.values_mut(), roots);
+    }
+    for response in responses {
+        if let RefOr::T(response) = response {
+            response_roots(response, roots);
+        }
+    }
+    for callback in callbacks {
+        if let RefOr::T(callback) = callback {
+            for path_item in callback.callbacks.values_mut() {
+                path_item_roots(path_item, roots);
+            }
+        }
+    }
+}
+
+fn response_roots<'a>(response: &'a mut Response, roots: &mut Vec<(&'a mut Component, String)>) {
+    for header in response.headers.values_mut() {
+        header_roots(header, roots);
+    }
+    content_roots(response.content.values_mut(), roots);
+}
+
+fn header_roots<'a>(header: &'a mut Header, roots: &mut Vec<(&'a mut Component, String)>) {
+    roots.push((&mut header.schema, String::new()));
+}
+
+fn content_roots<'a>(
+    contents: impl Iterator<Item = &'a mut Content>,
+    roots: &mut Vec<(&'a mut Component, String)>,
+) {
+    roots.extend(contents.map(|content| (&mut content.schema, String::new())));
+}
+
+struct Bundler {
+    /// Object schemas of components by name.
+    named: Vec<(String, Object)>,
+    /// Names of the schemas of components including created schemas.
+    taken: Vec<String>,
+    /// Number of occurrences of the inline objects in the document.
+    counts: Vec<(Object, usize)>,
+    /// Schemas created for repeated objects.
+    created: Vec<(String, Object)>,
+    replaced: bool,
+}
+
+impl Bundler {
+    fn replace(&mut self, schema: &mut Component, hint: &str) {
+        if let Component::Object(object) = schema {
+            if is_bundled(object) {
+                if let Some(name) = self.component_name(object, hint) {
+                    *schema = Ref::from_component_name(name).into();
+                    self.replaced = true;
+                    return;
+                }
+            }
+        }
+
+        for (child, hint) in children(schema, hint) {
+            self.replace(child, &hint);
+        }
+    }
+
+    /// Get name of the schema of components for the object if the object is already a schema
+    /// of components or if the object is repeated in the document.
+    fn component_name(&mut self, object: &Object, hint: &str) -> Option<String> {
+        let existing = self
+            .named
+            .iter()
+            .chain(self.created.iter())
+            .find(|(_, named)| named == object);
+        if let Some((name, _)) = existing {
+            return Some(name.clone());
+        }
+
+        let count = self
+            .counts
+            .iter()
+            .find(|(counted, _)| counted == object)
+            .map_or(0, |(_, count)| *count);
+        if count < 2 {
+            return None;
+        }
+
+        let name = self.unique_name(hint);
+        self.taken.push(name.clone());
+        self.created.push((name.clone(), object.clone()));
+        Some(name)
+    }
+
+    /// Create unique _`PascalCase`_ name from the hint.
+    fn unique_name(&self, hint: &str) -> String {
+        let mut base = String::new();
+        let mut uppercase = true;
+        for char in hint.chars() {
+            if char.is_alphanumeric() {
+                if uppercase {
+                    base.extend(char.to_uppercase());
+                } else {
+                    base.push(char);
+                }
+                uppercase = false;
+            } else {
+                uppercase = true;
+            }
+        }
+        if base.is_empty() || base.starts_with(|char: char| char.is_numeric()) {
+            base.insert_str(0, "Schema");
+        }
+
+        let mut name = base.clone();
+        let mut index = 2;
+        while self.taken.contains(&name) {
+            name = format!("{}{}", base, index);
+            index += 1;
+        }
+
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::{
+        path::OperationBuilder, request_body::RequestBodyBuilder, ComponentType, ComponentsBuilder,
+        ContentBuilder, Info, ObjectBuilder, OpenApiBuilder, PathItemType, PathsBuilder, Property,
+        ResponseBuilder,
+    };
+
+    use super::*;
+
+    fn pet() -> Object {
+        ObjectBuilder::new()
+            .property("name", Property::new(ComponentType::String))
+            .property(
+                "owner",
+                ObjectBuilder::new().property("name", Property::new(ComponentType::String)),
+            )
+            .build()
+    }
+
+    fn address(street: &str) -> Object {
+        ObjectBuilder::new()
+            .property(street, Property::new(ComponentType::String))
+            .build()
+    }
+
+    fn content<C: Into<Component>>(schema: C) -> Content {
+        ContentBuilder::new().schema(schema).build()
+    }
+
+    fn get_schema<'a>(
+        openapi: &'a OpenApi,
+        path: &str,
+        path_item_type: PathItemType,
+    ) -> &'a Component {
+        let operation = &openapi.paths.paths[path].operations[&path_item_type];
+        match &operation.responses.responses["200"] {
+            RefOr::T(response) => &response.content["application/json"].schema,
+            RefOr::Ref(_) => panic!("expected inline response"),
+        }
+    }
+
+    fn is_ref(component: &Component, name: &str) -> bool {
+        matches!(component, Component::Ref(reference) if reference.component_name("schemas").as_deref() == Some(name))
+    }
+
+    #[test]
+    fn bundle_extracts_repeated_objects() {
+        let mut openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            PathItemType::Post,
+                            OperationBuilder::new()
+                                .request_body(Some(
+                                    RequestBodyBuilder::new()
+                                        .content("application/json", content(pet()))
+                                        .build(),
+                                ))
+                                .response(
+                                    "200",
+                                    ResponseBuilder::new()
+                                        .description("pet")
+                                        .content("application/json", content(pet())),
+                                ),
+                        ),
+                    )
+                    .path(
+                        "/owners",
+                        PathItem::new(
+                            PathItemType::Get,
+                            OperationBuilder::new().response(
+                                "200",
+                                ResponseBuilder::new().description("owner").content(
+                                    "application/json",
+                                    content(
+                                        ObjectBuilder::new()
+                                            .property("home", address("street"))
+                                            .property("work", address("street")),
+                                    ),
+                                ),
+                            ),
+                        ),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .component(
+                        "Store",
+                        ObjectBuilder::new().property("address", address("road")),
+                    )
+                    .component("Road", address("road"))
+                    .component("Home", Property::new(ComponentType::String))
+                    .build(),
+            ))
+            .build();
+
+        openapi.bundle();
+
+        assert!(is_ref(
+            get_schema(&openapi, "/pets", PathItemType::Post),
+            "Schema"
+        ));
+        let owner = match get_schema(&openapi, "/owners", PathItemType::Get) {
+            Component::Object(owner) => owner,
+            _ => panic!("expected inline owner object"),
+        };
+        assert!(is_ref(&owner.properties["home"], "Home2"));
+        assert!(is_ref(&owner.properties["work"], "Home2"));
+
+        let schemas = &openapi.components.as_ref().unwrap().schemas;
+        assert!(schemas["Schema"] == Component::Object(pet()));
+        assert!(schemas["Home2"] == Component::Object(address("street")));
+        match &schemas["Store"] {
+            Component::Object(store) => assert!(is_ref(&store.properties["address"], "Road")),
+            _ => panic!("expected Store object"),
+        }
+        assert_eq!(schemas.len(), 5);
+    }
+
+    #[test]
+    fn bundle_without_repeated_objects_is_noop() {
+        let mut openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        PathItemType::Get,
+                        OperationBuilder::new().response(
+                            "200",
+                            ResponseBuilder::new()
+                                .description("pet")
+                                .content("application/json", content(pet())),
+                        ),
+                    ),
+                ),
+            )
+            .build();
+        let original = openapi.clone();
+
+        openapi.bundle();
+
+        assert!(openapi == original);
+    }
+}
@@ -0,0 +1,60 @@
+//! Implements [OpenAPI Callback Object][callback] types.
+//!
+//! [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{build_fn, builder, from, new, ref_or_from, PathItem};
+
+builder! {
+    CallbackBuilder;
+
+    /// Implements [OpenAPI Callback Object][callback].
+    ///
+    /// Callback is a map of out-of-band requests that may be initiated by the api provider.
+    /// Each request is identified by a runtime expression e.g. _`{$request.body#/callbackUrl}`_
+    /// which is evaluated at runtime to get the url of the request.
+    ///
+    /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(transparent)]
+    pub struct Callback {
+        /// Map of runtime expressions with [`PathItem`]s describing the callback requests.
+        pub callbacks: BTreeMap<String, PathItem>,
+    }
+}
+
+impl Callback {
+    /// Construct a new empty [`Callback`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl CallbackBuilder {
+    /// Add [`PathItem`] describing the callback request identified by runtime expression. If
+    /// expression already exists the [`Operation`][operation]s of the [`PathItem`] will be merged
+    /// with the operations of the existing path item.
+    ///
+    /// [operation]: ../path/struct.Operation.html
+    pub fn expression<S: Into<String>, P: Into<PathItem>>(
+        mut self,
+        expression: S,
+        path_item: P,
+    ) -> Self {
+        let mut path_item = path_item.into();
+        let expression = expression.into();
+        if let Some(existing_item) = self.callbacks.get_mut(&expression) {
+            existing_item.operations.append(&mut path_item.operations);
+        } else {
+            self.callbacks.insert(expression, path_item);
+        }
+
+        self
+    }
+}
+
+ref_or_from!(Callback CallbackBuilder);
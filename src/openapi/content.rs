@@ -1,13 +1,15 @@
 //! Implements content object for request body and response.
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "serde_json")]
 use serde_json::Value;
 
-use super::{build_fn, from, new, set_value, Component};
+use super::{build_fn, example::Example, from, new, set_value, Component, RefOr};
 
 /// Content holds request body content or response content.
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[non_exhaustive]
 pub struct Content {
@@ -23,13 +25,19 @@ pub struct Content {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg(not(feature = "serde_json"))]
     pub example: Option<String>,
+
+    /// Map of named [`Example`]s for request body or response body. Examples are either inline
+    /// or references to [`Example`]s in components. This should not be used together with
+    /// [`Content::example`].
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub examples: BTreeMap<String, RefOr<Example>>,
 }
 
 impl Content {
     pub fn new<I: Into<Component>>(schema: I) -> Self {
         Self {
             schema: schema.into(),
-            example: None,
+            ..Default::default()
         }
     }
 }
@@ -44,9 +52,11 @@ pub struct ContentBuilder {
 
     #[cfg(not(feature = "serde_json"))]
     example: Option<String>,
+
+    examples: BTreeMap<String, RefOr<Example>>,
 }
 
-from!(Content ContentBuilder schema, example);
+from!(Content ContentBuilder schema, example, examples);
 
 impl ContentBuilder {
     new!(pub ContentBuilder);
@@ -68,5 +78,24 @@ impl ContentBuilder {
         set_value!(self example example.map(|example| example.into()))
     }
 
-    build_fn!(pub Content schema, example);
+    /// Add iterator of named [`Example`]s of schema. Examples can be either inline [`Example`]s or
+    /// references to [`Example`]s in components.
+    pub fn examples_from_iter<
+        I: IntoIterator<Item = (N, E)>,
+        N: Into<String>,
+        E: Into<RefOr<Example>>,
+    >(
+        mut self,
+        examples: I,
+    ) -> Self {
+        self.examples.extend(
+            examples
+                .into_iter()
+                .map(|(name, example)| (name.into(), example.into())),
+        );
+
+        self
+    }
+
+    build_fn!(pub Content schema, example, examples);
 }
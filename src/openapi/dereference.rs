@@ -0,0 +1,441 @@
+//! Implements inlining of references of [`OpenApi`] documents. See [`OpenApi::dereference`]
+//! for more details.
+use std::{collections::HashMap, fmt};
+
+use super::{
+    callback::Callback,
+    content::Content,
+    header::Header,
+    path::{Operation, Parameter},
+    request_body::RequestBody,
+    schema::{AdditionalProperties, AllOf, AnyOf, OneOf},
+    Component, Components, OpenApi, PathItem, RefOr, Response,
+};
+
+/// Error of [`OpenApi::dereference`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DereferenceError {
+    /// Reference does not resolve to a component of the document. Only local references to
+    /// components e.g. _`#/components/schemas/Pet`_ can be resolved.
+    Unresolved(String),
+    /// References form a cycle e.g. recursive schema which cannot be inlined. Contains the
+    /// references of the cycle starting and ending with the same reference.
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for DereferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unresolved(reference) => {
+                write!(
+                    f,
+                    "reference `{}` does not resolve to a component",
+                    reference
+                )
+            }
+            Self::Cycle(references) => write!(
+                f,
+                "references form a cycle which cannot be inlined: {}",
+                references.join(" -> ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DereferenceError {}
+
+/// Inline every reference of the [`OpenApi`] document with the referenced component.
+pub(super) fn dereference(openapi: &mut OpenApi) -> Result<(), DereferenceError> {
+    let components = openapi.components.clone().unwrap_or_default();
+    let mut dereferencer = Dereferencer {
+        components: &components,
+        references: Vec::new(),
+    };
+
+    for path_item in openapi
+        .paths
+        .paths
+        .values_mut()
+        .chain(openapi.webhooks.values_mut())
+    {
+        dereferencer.path_item(path_item)?;
+    }
+    if let Some(components) = openapi.components.as_mut() {
+        dereferencer.components(components)?;
+    }
+
+    Ok(())
+}
+
+struct Dereferencer<'a> {
+    components: &'a Components,
+    /// References currently being inlined used to detect reference cycles.
+    references: Vec<String>,
+}
+
+impl<'a> Dereferencer<'a> {
+    fn enter(&mut self, reference: String) -> Result<(), DereferenceError> {
+        match self
+            .references
+            .iter()
+            .position(|entered| *entered == reference)
+        {
+            Some(start) => {
+                let mut cycle = self.references.split_off(start);
+                cycle.push(reference);
+                Err(DereferenceError::Cycle(cycle))
+            }
+            None => {
+                self.references.push(reference);
+                Ok(())
+            }
+        }
+    }
+
+    fn ref_or<T: Clone>(
+        &mut self,
+        value: &mut RefOr<T>,
+        kind: &str,
+        components: fn(&Components) -> &HashMap<String, RefOr<T>>,
+        inline: fn(&mut Self, &mut T) -> Result<(), DereferenceError>,
+    ) -> Result<(), DereferenceError> {
+        let reference = match value {
+            RefOr::T(value) => return inline(self, value),
+            RefOr::Ref(reference) => reference,
+        };
+        let mut resolved = reference
+            .component_name(kind)
+            .and_then(|name| components(self.components).get(&name))
+            .cloned()
+            .ok_or_else(|| DereferenceError::Unresolved(reference.ref_location.clone()))?;
+
+        self.enter(reference.ref_location.clone())?;
+        self.ref_or(&mut resolved, kind, components, inline)?;
+        self.references.pop();
+
+        *value = resolved;
+        Ok(())
+    }
+
+    fn components(&mut self, components: &mut Components) -> Result<(), DereferenceError> {
+        for schema in components.schemas.values_mut() {
+            self.schema(schema)?;
+        }
+        for response in components.responses.values_mut() {
+            self.response_ref_or(response)?;
+        }
+        for parameter in components.parameters.values_mut() {
+            self.parameter_ref_or(parameter)?;
+        }
+        for example in components.examples.values_mut() {
+            self.ref_or(example, "examples", |c| &c.examples, |_, _| Ok(()))?;
+        }
+        for request_body in components.request_bodies.values_mut() {
+            self.ref_or(
+                request_body,
+                "requestBodies",
+                |c| &c.request_bodies,
+                Self::request_body,
+            )?;
+        }
+        for header in components.headers.values_mut() {
+            self.ref_or(header, "headers", |c| &c.headers, Self::header)?;
+        }
+        for link in components.links.values_mut() {
+            self.ref_or(link, "links", |c| &c.links, |_, _| Ok(()))?;
+        }
+        for callback in components.callbacks.values_mut() {
+            self.callback_ref_or(callback)?;
+        }
+
+        Ok(())
+    }
+
+    fn path_item(&mut self, path_item: &mut PathItem) -> Result<(), DereferenceError> {
+        for parameter in path_item.parameters.iter_mut().flatten() {
+            self.parameter_ref_or(parameter)?;
+        }
+        for operation in path_item.operations.values_mut() {
+            self.operation(operation)?;
+        }
+
+        Ok(())
+    }
+
+    fn operation(&mut self, operation: &mut Operation) -> Result<(), DereferenceError> {
+        for parameter in operation.parameters.iter_mut().flatten() {
+            self.parameter_ref_or(parameter)?;
+        }
+        if let Some(request_body) = operation.request_body.as_mut() {
+            self.request_body(request_body)?;
+        }
+        for response in operation.responses.responses.values_mut() {
+            self.response_ref_or(response)?;
+        }
+        for callback in operation.callbacks.iter_mut().flat_map(|c| c.values_mut()) {
+            self.callback_ref_or(callback)?;
+        }
+
+        Ok(())
+    }
+
+    fn parameter_ref_or(
+        &mut self,
+        parameter: &mut RefOr<Parameter>,
+    ) -> Result<(), DereferenceError> {
+        self.ref_or(parameter, "parameters", |c| &c.parameters, Self::parameter)
+    }
+
+    fn parameter(&mut self, parameter: &mut Parameter) -> Result<(), DereferenceError> {
+        if let Some(schema) = parameter.schema.as_mut() {
+            self.schema(schema)?;
+        }
+        for example in parameter.examples.values_mut() {
+            self.ref_or(example, "examples", |c| &c.examples, |_, _| Ok(()))?;
+        }
+
+        Ok(())
+    }
+
+    fn request_body(&mut self, request_body: &mut RequestBody) -> Result<(), DereferenceError> {
+        for content in request_body.content.values_mut() {
+            self.content(content)?;
+        }
+
+        Ok(())
+    }
+
+    fn response_ref_or(&mut self, response: &mut RefOr<Response>) -> Result<(), DereferenceError> {
+        self.ref_or(response, "responses", |c| &c.responses, Self::response)
+    }
+
+    fn response(&mut self, response: &mut Response) -> Result<(), DereferenceError> {
+        for header in response.headers.values_mut() {
+            self.header(header)?;
+        }
+        for content in response.content.values_mut() {
+            self.content(content)?;
+        }
+        for link in response.links.values_mut() {
+            self.ref_or(link, "links", |c| &c.links, |_, _| Ok(()))?;
+        }
+
+        Ok(())
+    }
+
+    fn content(&mut self, content: &mut Content) -> Result<(), DereferenceError> {
+        self.schema(&mut content.schema)?;
+        for example in content.examples.values_mut() {
+            self.ref_or(example, "examples", |c| &c.examples, |_, _| Ok(()))?;
+        }
+
+        Ok(())
+    }
+
+    fn header(&mut self, header: &mut Header) -> Result<(), DereferenceError> {
+        self.schema(&mut header.schema)?;
+        for example in header.examples.values_mut() {
+            self.ref_or(example, "examples", |c| &c.examples, |_, _| Ok(()))?;
+        }
+
+        Ok(())
+    }
+
+    fn callback_ref_or(&mut self, callback: &mut RefOr<Callback>) -> Result<(), DereferenceError> {
+        self.ref_or(
+            callback,
+            "callbacks",
+            |c| &c.callbacks,
+            |dereferencer, callback| {
+                for path_item in callback.callbacks.values_mut() {
+                    dereferencer.path_item(path_item)?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn schema(&mut self, schema: &mut Component) -> Result<(), DereferenceError> {
+        match schema {
+            Component::Ref(reference) => {
+                let mut resolved = reference
+                    .component_name("schemas")
+                    .and_then(|name| self.components.schemas.get(&name))
+                    .cloned()
+                    .ok_or_else(|| DereferenceError::Unresolved(reference.ref_location.clone()))?;
+
+                self.enter(reference.ref_location.clone())?;
+                self.schema(&mut resolved)?;
+                self.references.pop();
+
+                *schema = resolved;
+            }
+            Component::Object(object) => {
+                for property in object.properties.values_mut() {
+                    self.schema(property)?;
+                }
+                if let Some(AdditionalProperties::Component(additional_properties)) =
+                    object.additional_properties.as_deref_mut()
+                {
+                    self.schema(additional_properties)?;
+                }
+                for definition in object.defs.values_mut() {
+                    self.schema(definition)?;
+                }
+            }
+            Component::Array(array) => self.schema(&mut array.items)?,
+            Component::OneOf(OneOf { items, .. })
+            | Component::AllOf(AllOf { items, .. })
+            | Component::AnyOf(AnyOf { items, .. }) => {
+                for item in items.iter_mut() {
+                    self.schema(item)?;
+                }
+            }
+            Component::Property(_) => (),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::{
+        path::{OperationBuilder, ParameterBuilder},
+        ArrayBuilder, ComponentType, ComponentsBuilder, ContentBuilder, Info, ObjectBuilder,
+        OpenApiBuilder, PathItemType, PathsBuilder, Property, Ref, ResponseBuilder,
+    };
+
+    use super::*;
+
+    fn openapi(components: Components) -> OpenApi {
+        OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets",
+                    PathItem::new(
+                        PathItemType::Get,
+                        OperationBuilder::new()
+                            .parameter(Ref::new("#/components/parameters/Limit"))
+                            .response("200", Ref::new("#/components/responses/Pets")),
+                    ),
+                ),
+            )
+            .components(Some(components))
+            .build()
+    }
+
+    fn pets_response() -> Response {
+        ResponseBuilder::new()
+            .description("pets")
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Ref::from_component_name("Pet").to_array_builder())
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn dereference_inlines_references() {
+        let mut openapi = openapi(
+            ComponentsBuilder::new()
+                .component(
+                    "Pet",
+                    ObjectBuilder::new()
+                        .property("name", Ref::from_component_name("Name"))
+                        .property("owner", Ref::from_component_name("Owner")),
+                )
+                .component("Owner", Ref::from_component_name("Person"))
+                .component(
+                    "Person",
+                    ObjectBuilder::new().property("name", Ref::from_component_name("Name")),
+                )
+                .component("Name", Property::new(ComponentType::String))
+                .response("Pets", pets_response())
+                .parameter(
+                    "Limit",
+                    ParameterBuilder::new()
+                        .name("limit")
+                        .schema(Some(Property::new(ComponentType::Integer))),
+                )
+                .build(),
+        );
+
+        openapi.dereference().unwrap();
+
+        let operation = &openapi.paths.paths["/pets"].operations[&PathItemType::Get];
+        assert!(matches!(
+            operation.parameters.as_deref(),
+            Some([RefOr::T(parameter)]) if parameter.name == "limit"
+        ));
+
+        let response = match &operation.responses.responses["200"] {
+            RefOr::T(response) => response,
+            RefOr::Ref(_) => panic!("expected response to be inlined"),
+        };
+        let pet = match &response.content["application/json"].schema {
+            Component::Array(array) => &*array.items,
+            _ => panic!("expected array schema"),
+        };
+        let expected_pet = Component::from(
+            ObjectBuilder::new()
+                .property("name", Property::new(ComponentType::String))
+                .property(
+                    "owner",
+                    ObjectBuilder::new().property("name", Property::new(ComponentType::String)),
+                ),
+        );
+        assert!(*pet == expected_pet, "unexpected inlined Pet schema");
+        assert!(openapi.components.unwrap().schemas["Pet"] == expected_pet);
+    }
+
+    #[test]
+    fn dereference_detects_cycles() {
+        let mut openapi = openapi(
+            ComponentsBuilder::new()
+                .component(
+                    "Pet",
+                    ObjectBuilder::new().property(
+                        "children",
+                        ArrayBuilder::new().items(Ref::from_component_name("Pet")),
+                    ),
+                )
+                .response("Pets", pets_response())
+                .parameter("Limit", ParameterBuilder::new().name("limit"))
+                .build(),
+        );
+        let original = openapi.clone();
+
+        assert_eq!(
+            openapi.dereference(),
+            Err(DereferenceError::Cycle(vec![
+                "#/components/schemas/Pet".to_string(),
+                "#/components/schemas/Pet".to_string(),
+            ]))
+        );
+        assert!(openapi == original, "document must be unchanged on error");
+    }
+
+    #[test]
+    fn dereference_unresolved_reference() {
+        let mut openapi = openapi(
+            ComponentsBuilder::new()
+                .response("Pets", pets_response())
+                .build(),
+        );
+
+        let error = openapi.dereference().unwrap_err();
+
+        assert_eq!(
+            error,
+            DereferenceError::Unresolved("#/components/parameters/Limit".to_string())
+        );
+        assert_eq!(
+            error.to_string(),
+            "reference `#/components/parameters/Limit` does not resolve to a component"
+        );
+    }
+}
@@ -0,0 +1,926 @@
+//! Implements comparison of two [`OpenApi`] documents. See [`OpenApi::diff`] for more details.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use super::{
+    content::Content,
+    path::{Operation, Parameter},
+    pointer_child, Component, Components, Object, OpenApi, PathItem, Property, RefOr, Required,
+};
+
+/// Compatibility of the [`Change`] for the existing clients of the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Compatibility {
+    /// Existing clients keep working e.g. new optional property was added.
+    NonBreaking,
+    /// Existing clients may break e.g. path was removed or type of property was changed.
+    Breaking,
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonBreaking => f.write_str("non-breaking"),
+            Self::Breaking => f.write_str("breaking"),
+        }
+    }
+}
+
+/// Difference between two [`OpenApi`] documents found by [`OpenApi::diff`].
+///
+/// Location of the change is given as [JSON pointer][json_pointer] within the serialized
+/// document e.g. _`/paths/~1pets/get/parameters/0`_. Location of removed definitions is given
+/// within the old document and location of other changes within the new document.
+///
+/// [json_pointer]: https://www.rfc-editor.org/rfc/rfc6901
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pointer: String,
+    compatibility: Compatibility,
+    message: String,
+}
+
+impl Change {
+    fn new(pointer: String, compatibility: Compatibility, message: String) -> Self {
+        Self {
+            pointer,
+            compatibility,
+            message,
+        }
+    }
+
+    /// JSON pointer to the location of the change within the document.
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    /// Compatibility of the change.
+    pub fn compatibility(&self) -> Compatibility {
+        self.compatibility
+    }
+
+    /// Returns `true` if the change may break existing clients.
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility == Compatibility::Breaking
+    }
+
+    /// Message describing the change.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} change at {}: {}",
+            self.compatibility, self.pointer, self.message
+        )
+    }
+}
+
+/// Compare `old` and `new` [`OpenApi`] documents returning changes ordered by path and name.
+pub(super) fn diff(old: &OpenApi, new: &OpenApi) -> Vec<Change> {
+    let mut differ = Differ {
+        old: old.components.as_ref(),
+        new: new.components.as_ref(),
+        references: Vec::new(),
+        changes: Vec::new(),
+    };
+
+    let path_items = [
+        ("/paths", "path", &old.paths.paths, &new.paths.paths),
+        ("/webhooks", "webhook", &old.webhooks, &new.webhooks),
+    ];
+    for (parent, kind, old, new) in path_items {
+        for (name, pair) in pairs(old, new) {
+            let pointer = pointer_child(parent, name);
+            match pair {
+                Pair::Removed(_) => {
+                    differ.breaking(pointer, format!("{} `{}` was removed", kind, name))
+                }
+                Pair::Added(_) => {
+                    differ.non_breaking(pointer, format!("{} `{}` was added", kind, name))
+                }
+                Pair::Both(old, new) => differ.path_item(&pointer, old, new),
+            }
+        }
+    }
+
+    differ.changes
+}
+
+/// Value of the same key in old and new map.
+enum Pair<T> {
+    Removed(T),
+    Added(T),
+    Both(T, T),
+}
+
+/// Pair values of the `old` and `new` map by key in key order.
+fn pairs<'m, K, V>(
+    old: impl IntoIterator<Item = (&'m K, &'m V)>,
+    new: impl IntoIterator<Item = (&'m K, &'m V)>,
+) -> BTreeMap<&'m K, Pair<&'m V>>
+where
+    K: Ord + ?Sized + 'm,
+    V: 'm,
+{
+    let mut pairs = old
+        .into_iter()
+        .map(|(key, old)| (key, Pair::Removed(old)))
+        .collect::<BTreeMap<_, _>>();
+
+    for (key, new) in new {
+        let pair = match pairs.remove(key) {
+            Some(Pair::Removed(old)) => Pair::Both(old, new),
+            _ => Pair::Added(new),
+        };
+        pairs.insert(key, pair);
+    }
+
+    pairs
+}
+
+/// Resolve the value of [`RefOr`] from `components` of given `kind` if it is a reference.
+fn resolve<'r, T>(
+    ref_or: &'r RefOr<T>,
+    components: Option<&'r HashMap<String, RefOr<T>>>,
+    kind: &str,
+) -> Option<&'r T> {
+    match ref_or {
+        RefOr::T(value) => Some(value),
+        RefOr::Ref(reference) => match components?.get(&reference.component_name(kind)?)? {
+            RefOr::T(value) => Some(value),
+            RefOr::Ref(_) => None,
+        },
+    }
+}
+
+/// Resolve the schema from `components` if it is a reference.
+fn resolve_schema<'r>(
+    components: Option<&'r Components>,
+    schema: &'r Component,
+) -> Option<&'r Component> {
+    match schema {
+        Component::Ref(reference) => components?.resolve(reference),
+        schema => Some(schema),
+    }
+}
+
+/// Get parameters of the [`Operation`] including parameters of the [`PathItem`] which are not
+/// overridden by the operation. Parameters are keyed by location and name.
+fn parameters<'p>(
+    components: Option<&'p Components>,
+    pointer: &str,
+    path_item: &'p PathItem,
+    operation_pointer: &str,
+    operation: &'p Operation,
+) -> BTreeMap<(&'p str, &'p str), (String, &'p Parameter)> {
+    let parameters = |pointer: &str, parameters: &'p Option<Vec<RefOr<Parameter>>>| {
+        let pointer = pointer_child(pointer, "parameters");
+        parameters
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter_map(move |(index, parameter)| {
+                let parameter = resolve(
+                    parameter,
+                    components.map(|components| &components.parameters),
+                    "parameters",
+                )?;
+                Some((pointer_child(&pointer, index), parameter))
+            })
+    };
+
+    parameters(pointer, &path_item.parameters)
+        .chain(parameters(operation_pointer, &operation.parameters))
+        .map(|(pointer, parameter)| {
+            let key = (parameter.parameter_in.as_str(), &*parameter.name);
+            (key, (pointer, parameter))
+        })
+        .collect()
+}
+
+fn is_required(required: &Option<Required>) -> bool {
+    matches!(required, Some(Required::True))
+}
+
+/// Name of the type of the resolved schema.
+fn schema_type(schema: &Component) -> &'static str {
+    match schema {
+        Component::Property(property) => property.component_type.as_str(),
+        Component::Object(_) => "object",
+        Component::Array(_) => "array",
+        Component::OneOf(_) => "oneOf",
+        Component::AllOf(_) => "allOf",
+        Component::AnyOf(_) => "anyOf",
+        Component::Ref(_) => "$ref",
+    }
+}
+
+/// Enum value as shown in the change messages. Strings are shown without quotes.
+#[cfg(feature = "serde_json")]
+fn enum_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Enum value as shown in the change messages.
+#[cfg(not(feature = "serde_json"))]
+fn enum_value(value: &str) -> String {
+    value.to_string()
+}
+
+fn is_nullable(schema: &Component) -> bool {
+    let nullable = match schema {
+        Component::Property(property) => property.nullable,
+        Component::Object(object) => object.nullable,
+        Component::Array(array) => array.nullable,
+        Component::OneOf(one_of) => one_of.nullable,
+        Component::AllOf(all_of) => all_of.nullable,
+        Component::AnyOf(any_of) => any_of.nullable,
+        Component::Ref(_) => None,
+    };
+
+    nullable == Some(true)
+}
+
+/// Whether schema describes values sent by the client or values returned to the client.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Request,
+    Response,
+}
+
+struct Differ<'a> {
+    old: Option<&'a Components>,
+    new: Option<&'a Components>,
+    /// References of the schemas currently being compared to stop on cyclic references.
+    references: Vec<(Option<&'a str>, Option<&'a str>)>,
+    changes: Vec<Change>,
+}
+
+impl<'a> Differ<'a> {
+    fn breaking(&mut self, pointer: String, message: String) {
+        self.changes
+            .push(Change::new(pointer, Compatibility::Breaking, message));
+    }
+
+    fn non_breaking(&mut self, pointer: String, message: String) {
+        self.changes
+            .push(Change::new(pointer, Compatibility::NonBreaking, message));
+    }
+
+    /// Report change which narrows the accepted values. This breaks clients sending requests
+    /// but not clients receiving responses.
+    fn narrowed(&mut self, direction: Direction, pointer: String, message: String) {
+        match direction {
+            Direction::Request => self.breaking(pointer, message),
+            Direction::Response => self.non_breaking(pointer, message),
+        }
+    }
+
+    /// Report change which widens the accepted values. This breaks clients receiving responses
+    /// but not clients sending requests.
+    fn widened(&mut self, direction: Direction, pointer: String, message: String) {
+        match direction {
+            Direction::Request => self.non_breaking(pointer, message),
+            Direction::Response => self.breaking(pointer, message),
+        }
+    }
+
+    fn path_item(&mut self, pointer: &str, old: &'a PathItem, new: &'a PathItem) {
+        for (path_item_type, pair) in pairs(&old.operations, &new.operations) {
+            let operation_pointer = pointer_child(pointer, path_item_type.as_str());
+            let operation = path_item_type.as_str();
+            match pair {
+                Pair::Removed(_) => self.breaking(
+                    operation_pointer,
+                    format!("operation `{}` was removed", operation),
+                ),
+                Pair::Added(_) => self.non_breaking(
+                    operation_pointer,
+                    format!("operation `{}` was added", operation),
+                ),
+                Pair::Both(old_operation, new_operation) => {
+                    self.parameters(
+                        parameters(self.old, pointer, old, &operation_pointer, old_operation),
+                        parameters(self.new, pointer, new, &operation_pointer, new_operation),
+                    );
+                    self.operation(&operation_pointer, old_operation, new_operation);
+                }
+            }
+        }
+    }
+
+    fn parameters(
+        &mut self,
+        old: BTreeMap<(&'a str, &'a str), (String, &'a Parameter)>,
+        new: BTreeMap<(&'a str, &'a str), (String, &'a Parameter)>,
+    ) {
+        for ((location, name), pair) in pairs(&old, &new) {
+            match pair {
+                Pair::Removed((pointer, _)) => self.breaking(
+                    pointer.clone(),
+                    format!("{} parameter `{}` was removed", location, name),
+                ),
+                Pair::Added((pointer, parameter)) => {
+                    if matches!(parameter.required, Required::True) {
+                        self.breaking(
+                            pointer.clone(),
+                            format!("required {} parameter `{}` was added", location, name),
+                        )
+                    } else {
+                        self.non_breaking(
+                            pointer.clone(),
+                            format!("{} parameter `{}` was added", location, name),
+                        )
+                    }
+                }
+                Pair::Both((_, old), (pointer, new)) => {
+                    match (&old.required, &new.required) {
+                        (Required::False, Required::True) => self.breaking(
+                            pointer.clone(),
+                            format!("{} parameter `{}` became required", location, name),
+                        ),
+                        (Required::True, Required::False) => self.non_breaking(
+                            pointer.clone(),
+                            format!("{} parameter `{}` became optional", location, name),
+                        ),
+                        _ => (),
+                    }
+                    if let (Some(old), Some(new)) = (&old.schema, &new.schema) {
+                        self.schema(
+                            pointer_child(pointer, "schema"),
+                            old,
+                            new,
+                            Direction::Request,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn operation(&mut self, pointer: &str, old: &'a Operation, new: &'a Operation) {
+        let request_body_pointer = pointer_child(pointer, "requestBody");
+        match (&old.request_body, &new.request_body) {
+            (Some(old), Some(new)) => {
+                match (is_required(&old.required), is_required(&new.required)) {
+                    (false, true) => self.breaking(
+                        request_body_pointer.clone(),
+                        "request body became required".to_string(),
+                    ),
+                    (true, false) => self.non_breaking(
+                        request_body_pointer.clone(),
+                        "request body became optional".to_string(),
+                    ),
+                    _ => (),
+                }
+                self.content(
+                    &pointer_child(&request_body_pointer, "content"),
+                    &old.content,
+                    &new.content,
+                    Direction::Request,
+                );
+            }
+            (Some(_), None) => {
+                self.breaking(request_body_pointer, "request body was removed".to_string())
+            }
+            (None, Some(new)) if is_required(&new.required) => self.breaking(
+                request_body_pointer,
+                "required request body was added".to_string(),
+            ),
+            (None, Some(_)) => {
+                self.non_breaking(request_body_pointer, "request body was added".to_string())
+            }
+            (None, None) => (),
+        }
+
+        let responses_pointer = pointer_child(pointer, "responses");
+        for (status, pair) in pairs(&old.responses.responses, &new.responses.responses) {
+            let pointer = pointer_child(&responses_pointer, status);
+            match pair {
+                Pair::Removed(_) => {
+                    self.breaking(pointer, format!("response `{}` was removed", status))
+                }
+                Pair::Added(_) => {
+                    self.non_breaking(pointer, format!("response `{}` was added", status))
+                }
+                Pair::Both(old, new) => {
+                    let old = resolve(
+                        old,
+                        self.old.map(|components| &components.responses),
+                        "responses",
+                    );
+                    let new = resolve(
+                        new,
+                        self.new.map(|components| &components.responses),
+                        "responses",
+                    );
+                    if let (Some(old), Some(new)) = (old, new) {
+                        self.content(
+                            &pointer_child(&pointer, "content"),
+                            &old.content,
+                            &new.content,
+                            Direction::Response,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn content(
+        &mut self,
+        pointer: &str,
+        old: &'a HashMap<String, Content>,
+        new: &'a HashMap<String, Content>,
+        direction: Direction,
+    ) {
+        for (media_type, pair) in pairs(old, new) {
+            let pointer = pointer_child(pointer, media_type);
+            match pair {
+                Pair::Removed(_) => {
+                    self.breaking(pointer, format!("media type `{}` was removed", media_type))
+                }
+                Pair::Added(_) => {
+                    self.non_breaking(pointer, format!("media type `{}` was added", media_type))
+                }
+                Pair::Both(old, new) => self.schema(
+                    pointer_child(&pointer, "schema"),
+                    &old.schema,
+                    &new.schema,
+                    direction,
+                ),
+            }
+        }
+    }
+
+    /// Compare schemas following references. Referenced schemas are compared at every location
+    /// they are used at because compatibility of the change depends on the [`Direction`].
+    fn schema(
+        &mut self,
+        pointer: String,
+        old: &'a Component,
+        new: &'a Component,
+        direction: Direction,
+    ) {
+        let reference = |schema: &'a Component| match schema {
+            Component::Ref(reference) => Some(&*reference.ref_location),
+            _ => None,
+        };
+        let references = (reference(old), reference(new));
+        let is_reference = references != (None, None);
+        if is_reference {
+            if self.references.contains(&references) {
+                return;
+            }
+            self.references.push(references);
+        }
+
+        if let (Some(old), Some(new)) =
+            (resolve_schema(self.old, old), resolve_schema(self.new, new))
+        {
+            self.resolved_schema(pointer, old, new, direction);
+        }
+
+        if is_reference {
+            self.references.pop();
+        }
+    }
+
+    fn resolved_schema(
+        &mut self,
+        pointer: String,
+        old: &'a Component,
+        new: &'a Component,
+        direction: Direction,
+    ) {
+        let (old_type, new_type) = (schema_type(old), schema_type(new));
+        if old_type != new_type {
+            return self.breaking(
+                pointer,
+                format!("type changed from `{}` to `{}`", old_type, new_type),
+            );
+        }
+
+        match (is_nullable(old), is_nullable(new)) {
+            (true, false) => self.narrowed(
+                direction,
+                pointer.clone(),
+                "schema is no longer nullable".to_string(),
+            ),
+            (false, true) => self.widened(
+                direction,
+                pointer.clone(),
+                "schema became nullable".to_string(),
+            ),
+            _ => (),
+        }
+
+        match (old, new) {
+            (Component::Property(old), Component::Property(new)) => {
+                self.property(&pointer, old, new, direction)
+            }
+            (Component::Object(old), Component::Object(new)) => {
+                self.object(&pointer, old, new, direction)
+            }
+            (Component::Array(old), Component::Array(new)) => self.schema(
+                pointer_child(&pointer, "items"),
+                &old.items,
+                &new.items,
+                direction,
+            ),
+            (Component::OneOf(old), Component::OneOf(new)) => {
+                self.items(&pointer, "oneOf", &old.items, &new.items, direction)
+            }
+            (Component::AllOf(old), Component::AllOf(new)) => {
+                self.items(&pointer, "allOf", &old.items, &new.items, direction)
+            }
+            (Component::AnyOf(old), Component::AnyOf(new)) => {
+                self.items(&pointer, "anyOf", &old.items, &new.items, direction)
+            }
+            _ => (),
+        }
+    }
+
+    fn property(
+        &mut self,
+        pointer: &str,
+        old: &'a Property,
+        new: &'a Property,
+        direction: Direction,
+    ) {
+        let format_pointer = || pointer_child(pointer, "format");
+        match (&old.format, &new.format) {
+            (Some(old), Some(new)) if old != new => self.breaking(
+                format_pointer(),
+                format!(
+                    "format changed from `{}` to `{}`",
+                    old.as_str(),
+                    new.as_str()
+                ),
+            ),
+            (None, Some(new)) => self.narrowed(
+                direction,
+                format_pointer(),
+                format!("format `{}` was added", new.as_str()),
+            ),
+            (Some(old), None) => self.widened(
+                direction,
+                format_pointer(),
+                format!("format `{}` was removed", old.as_str()),
+            ),
+            _ => (),
+        }
+
+        let enum_pointer = || pointer_child(pointer, "enum");
+        match (&old.enum_values, &new.enum_values) {
+            (Some(old), Some(new)) => {
+                for value in old.iter().filter(|value| !new.contains(value)) {
+                    self.narrowed(
+                        direction,
+                        enum_pointer(),
+                        format!("enum value `{}` was removed", enum_value(value)),
+                    );
+                }
+                for value in new.iter().filter(|value| !old.contains(value)) {
+                    self.widened(
+                        direction,
+                        enum_pointer(),
+                        format!("enum value `{}` was added", enum_value(value)),
+                    );
+                }
+            }
+            (None, Some(_)) => self.narrowed(
+                direction,
+                enum_pointer(),
+                "values were restricted to enum".to_string(),
+            ),
+            (Some(_), None) => self.widened(
+                direction,
+                enum_pointer(),
+                "enum restriction was removed".to_string(),
+            ),
+            (None, None) => (),
+        }
+    }
+
+    fn object(&mut self, pointer: &str, old: &'a Object, new: &'a Object, direction: Direction) {
+        let properties_pointer = pointer_child(pointer, "properties");
+        for (name, pair) in pairs(&old.properties, &new.properties) {
+            let pointer = pointer_child(&properties_pointer, name);
+            let was_required = old.required.contains(name);
+            let is_required = new.required.contains(name);
+            match pair {
+                Pair::Removed(_) => {
+                    self.breaking(pointer, format!("property `{}` was removed", name))
+                }
+                Pair::Added(_) if is_required => self.narrowed(
+                    direction,
+                    pointer,
+                    format!("required property `{}` was added", name),
+                ),
+                Pair::Added(_) => {
+                    self.non_breaking(pointer, format!("property `{}` was added", name))
+                }
+                Pair::Both(old, new) => {
+                    match (was_required, is_required) {
+                        (false, true) => self.narrowed(
+                            direction,
+                            pointer.clone(),
+                            format!("property `{}` became required", name),
+                        ),
+                        (true, false) => self.widened(
+                            direction,
+                            pointer.clone(),
+                            format!("property `{}` became optional", name),
+                        ),
+                        _ => (),
+                    }
+                    self.schema(pointer, old, new, direction);
+                }
+            }
+        }
+    }
+
+    /// Compare items of _`oneOf`_, _`allOf`_ or _`anyOf`_ by index. Adding an item to
+    /// _`allOf`_ restricts the values where adding an item to others allows more values.
+    fn items(
+        &mut self,
+        pointer: &str,
+        keyword: &str,
+        old: &'a [Component],
+        new: &'a [Component],
+        direction: Direction,
+    ) {
+        let items_pointer = pointer_child(pointer, keyword);
+        let is_all_of = keyword == "allOf";
+        for index in 0..old.len().max(new.len()) {
+            let pointer = pointer_child(&items_pointer, index);
+            let (message, is_added) = match (old.get(index), new.get(index)) {
+                (Some(old), Some(new)) => {
+                    self.schema(pointer, old, new, direction);
+                    continue;
+                }
+                (Some(_), None) => (format!("{} item {} was removed", keyword, index), false),
+                (None, Some(_)) => (format!("{} item {} was added", keyword, index), true),
+                (None, None) => continue,
+            };
+
+            if is_added == is_all_of {
+                self.narrowed(direction, pointer, message)
+            } else {
+                self.widened(direction, pointer, message)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn openapi(paths: Value, schemas: Value) -> OpenApi {
+        let openapi = json!({
+            "openapi": "3.0.3",
+            "info": { "title": "pet api", "version": "1.0.0" },
+            "paths": paths,
+            "components": { "schemas": schemas },
+        });
+
+        match OpenApi::from_json(&openapi.to_string()) {
+            Ok(openapi) => openapi,
+            Err(error) => panic!("invalid test document: {}", error),
+        }
+    }
+
+    fn changes(old: &OpenApi, new: &OpenApi) -> Vec<String> {
+        old.diff(new).iter().map(ToString::to_string).collect()
+    }
+
+    fn operation() -> Value {
+        json!({ "responses": { "200": { "description": "success" } } })
+    }
+
+    #[test]
+    fn diff_identical_openapi_has_no_changes() {
+        let openapi = OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+
+        assert_eq!(changes(&openapi, &openapi.clone()), Vec::<String>::new());
+    }
+
+    #[test]
+    fn diff_paths_and_operations() {
+        let old = openapi(
+            json!({
+                "/pets": { "get": operation(), "post": operation() },
+                "/pets/{id}": { "get": operation() },
+            }),
+            json!({}),
+        );
+        let new = openapi(
+            json!({
+                "/owners": { "get": operation() },
+                "/pets": { "get": operation() },
+            }),
+            json!({}),
+        );
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                "non-breaking change at /paths/~1owners: path `/owners` was added",
+                "breaking change at /paths/~1pets/post: operation `post` was removed",
+                "breaking change at /paths/~1pets~1{id}: path `/pets/{id}` was removed",
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_webhooks() {
+        let webhooks = |webhooks: Value| {
+            let openapi = json!({
+                "openapi": "3.1.0",
+                "info": { "title": "pet api", "version": "1.0.0" },
+                "paths": {},
+                "webhooks": webhooks,
+            });
+            OpenApi::from_json(&openapi.to_string()).unwrap()
+        };
+        let old = webhooks(json!({
+            "newPet": { "post": operation(), "put": operation() },
+            "soldPet": { "post": operation() },
+        }));
+        let new = webhooks(json!({
+            "newOwner": { "post": operation() },
+            "newPet": { "post": operation() },
+        }));
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                "non-breaking change at /webhooks/newOwner: webhook `newOwner` was added",
+                "breaking change at /webhooks/newPet/put: operation `put` was removed",
+                "breaking change at /webhooks/soldPet: webhook `soldPet` was removed",
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_parameters() {
+        let old = openapi(
+            json!({
+                "/pets/{id}": {
+                    "parameters": [
+                        { "name": "id", "in": "path", "required": true, "schema": { "type": "integer" } },
+                    ],
+                    "get": {
+                        "parameters": [
+                            { "name": "limit", "in": "query", "schema": { "type": "integer" } },
+                            { "name": "offset", "in": "query", "schema": { "type": "integer" } },
+                        ],
+                        "responses": { "200": { "description": "success" } },
+                    },
+                },
+            }),
+            json!({}),
+        );
+        let new = openapi(
+            json!({
+                "/pets/{id}": {
+                    "get": {
+                        "parameters": [
+                            { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+                            { "name": "limit", "in": "query", "required": true, "schema": { "type": "integer" } },
+                            { "name": "sort", "in": "query", "schema": { "type": "string" } },
+                            { "name": "x-tenant", "in": "header", "required": true, "schema": { "type": "string" } },
+                        ],
+                        "responses": { "200": { "description": "success" } },
+                    },
+                },
+            }),
+            json!({}),
+        );
+
+        assert_eq!(
+            changes(&old, &new),
+            [
+                "breaking change at /paths/~1pets~1{id}/get/parameters/3: required header parameter `x-tenant` was added",
+                "breaking change at /paths/~1pets~1{id}/get/parameters/0/schema: type changed from `integer` to `string`",
+                "breaking change at /paths/~1pets~1{id}/get/parameters/1: query parameter `limit` became required",
+                "breaking change at /paths/~1pets~1{id}/get/parameters/1: query parameter `offset` was removed",
+                "non-breaking change at /paths/~1pets~1{id}/get/parameters/2: query parameter `sort` was added",
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_schemas_by_direction() {
+        let paths = json!({
+            "/pets": {
+                "get": {
+                    "responses": {
+                        "200": {
+                            "description": "success",
+                            "content": {
+                                "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } },
+                            },
+                        },
+                    },
+                },
+                "post": {
+                    "requestBody": {
+                        "content": {
+                            "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } },
+                        },
+                    },
+                    "responses": { "200": { "description": "success" } },
+                },
+            },
+        });
+        let old = openapi(
+            paths.clone(),
+            json!({
+                "Pet": {
+                    "type": "object",
+                    "required": ["id"],
+                    "properties": {
+                        "id": { "type": "integer", "format": "int64" },
+                        "status": { "type": "string", "enum": ["available", "sold"] },
+                        "tag": { "type": "string" },
+                        "parent": { "$ref": "#/components/schemas/Pet" },
+                    },
+                },
+            }),
+        );
+        let new = openapi(
+            paths,
+            json!({
+                "Pet": {
+                    "type": "object",
+                    "required": ["id", "name"],
+                    "properties": {
+                        "id": { "type": "integer", "format": "int32" },
+                        "name": { "type": "string" },
+                        "status": { "type": "string", "enum": ["available"] },
+                        "parent": { "$ref": "#/components/schemas/Pet" },
+                    },
+                },
+            }),
+        );
+
+        let pointer = |path: &str, property: &str| {
+            format!(
+                "/paths/~1pets/{}/content/application~1json/schema/properties/{}",
+                path, property
+            )
+        };
+        assert_eq!(
+            changes(&old, &new),
+            [
+                format!(
+                    "breaking change at {}/format: format changed from `int64` to `int32`",
+                    pointer("get/responses/200", "id")
+                ),
+                format!(
+                    "non-breaking change at {}: required property `name` was added",
+                    pointer("get/responses/200", "name")
+                ),
+                format!(
+                    "non-breaking change at {}/enum: enum value `sold` was removed",
+                    pointer("get/responses/200", "status")
+                ),
+                format!(
+                    "breaking change at {}: property `tag` was removed",
+                    pointer("get/responses/200", "tag")
+                ),
+                format!(
+                    "breaking change at {}/format: format changed from `int64` to `int32`",
+                    pointer("post/requestBody", "id")
+                ),
+                format!(
+                    "breaking change at {}: required property `name` was added",
+                    pointer("post/requestBody", "name")
+                ),
+                format!(
+                    "breaking change at {}/enum: enum value `sold` was removed",
+                    pointer("post/requestBody", "status")
+                ),
+                format!(
+                    "breaking change at {}: property `tag` was removed",
+                    pointer("post/requestBody", "tag")
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Implements [OpenAPI Example Object][example] types.
+//!
+//! [example]: https://spec.openapis.org/oas/latest.html#example-object
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use super::{build_fn, from, new, ref_or_from, set_value};
+
+/// Implements [OpenAPI Example Object][example].
+///
+/// Example is used on path operations to describe possible values of [`Content`][content],
+/// [`Parameter`][parameter] or [`Header`][header]. Either [`Example::value`] or
+/// [`Example::external_value`] should be defined but not both.
+///
+/// [example]: https://spec.openapis.org/oas/latest.html#example-object
+/// [content]: ../content/struct.Content.html
+/// [parameter]: ../path/struct.Parameter.html
+/// [header]: ../header/struct.Header.html
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct Example {
+    /// Short description for the [`Example`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// Long description for the [`Example`]. Markdown syntax is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Embedded literal example value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub value: Option<Value>,
+
+    /// Embedded literal example value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub value: Option<String>,
+
+    /// Url pointing to a literal example value which cannot be easily embedded to the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_value: Option<String>,
+}
+
+impl Example {
+    /// Construct a new empty [`Example`]. This is effectively same as calling [`Example::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Builder for [`Example`] with chainable configuration methods to create a new [`Example`].
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ExampleBuilder {
+    summary: Option<String>,
+
+    description: Option<String>,
+
+    #[cfg(feature = "serde_json")]
+    value: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    value: Option<String>,
+
+    external_value: Option<String>,
+}
+
+from!(Example ExampleBuilder summary, description, value, external_value);
+
+impl ExampleBuilder {
+    new!(pub ExampleBuilder);
+
+    /// Add or change short summary of the [`Example`].
+    pub fn summary<S: Into<String>>(mut self, summary: Option<S>) -> Self {
+        set_value!(self summary summary.map(|summary| summary.into()))
+    }
+
+    /// Add or change long description of the [`Example`]. Markdown syntax is supported.
+    pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add or change embedded literal example value.
+    #[cfg(feature = "serde_json")]
+    pub fn value(mut self, value: Option<Value>) -> Self {
+        set_value!(self value value)
+    }
+
+    /// Add or change embedded literal example value.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn value<S: Into<String>>(mut self, value: Option<S>) -> Self {
+        set_value!(self value value.map(|value| value.into()))
+    }
+
+    /// Add or change url pointing to a literal example value.
+    pub fn external_value<S: Into<String>>(mut self, external_value: Option<S>) -> Self {
+        set_value!(self external_value external_value.map(|external_value| external_value.into()))
+    }
+
+    build_fn!(pub Example summary, description, value, external_value);
+}
+
+ref_or_from!(Example ExampleBuilder);
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::openapi::{schema::Ref, ComponentsBuilder, ContentBuilder, Property, RefOr};
+
+    #[test]
+    fn serialize_content_with_examples() {
+        let content = ContentBuilder::new()
+            .schema(Property::default())
+            .examples_from_iter([
+                (
+                    "ok",
+                    RefOr::from(
+                        ExampleBuilder::new()
+                            .summary(Some("Ok example"))
+                            .value(Some(json!("ok"))),
+                    ),
+                ),
+                (
+                    "external",
+                    ExampleBuilder::new()
+                        .external_value(Some("https://example.com/ok.json"))
+                        .into(),
+                ),
+                (
+                    "shared",
+                    RefOr::Ref(Ref::new("#/components/examples/Shared")),
+                ),
+            ])
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(content).unwrap(),
+            json!({
+                "schema": {
+                    "type": "object"
+                },
+                "examples": {
+                    "ok": {
+                        "summary": "Ok example",
+                        "value": "ok"
+                    },
+                    "external": {
+                        "externalValue": "https://example.com/ok.json"
+                    },
+                    "shared": {
+                        "$ref": "#/components/examples/Shared"
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_components_with_examples() {
+        let components = ComponentsBuilder::new()
+            .example(
+                "Shared",
+                ExampleBuilder::new()
+                    .description(Some("Shared example"))
+                    .value(Some(json!({"id": 1}))),
+            )
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(components).unwrap(),
+            json!({
+                "examples": {
+                    "Shared": {
+                        "description": "Shared example",
+                        "value": {
+                            "id": 1
+                        }
+                    }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Implements [OpenAPI Extensions][extensions].
+//!
+//! [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use serde::{
+    de::{IgnoredAny, MapAccess, Visitor},
+    Deserialize, Serialize,
+};
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+/// Value of a single extension. This is [`serde_json::Value`] if **json** feature is enabled
+/// otherwise it is plain [`String`].
+#[cfg(feature = "serde_json")]
+pub type ExtensionValue = Value;
+
+/// Value of a single extension. This is [`serde_json::Value`] if **json** feature is enabled
+/// otherwise it is plain [`String`].
+#[cfg(not(feature = "serde_json"))]
+pub type ExtensionValue = String;
+
+/// Prefix every extension name must start with.
+const EXTENSION_PREFIX: &str = "x-";
+
+/// Additional [data for extending][extensions] the OpenAPI specification.
+///
+/// Extensions are flattened to the object they extend. Only names starting with `x-` are
+/// allowed by the specification, other names are ignored when deserializing.
+///
+/// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
+#[non_exhaustive]
+#[derive(Serialize, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Extensions {
+    #[serde(flatten)]
+    extensions: HashMap<String, ExtensionValue>,
+}
+
+impl Extensions {
+    /// Construct a new empty [`Extensions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merge other [`Extensions`] into this one. Existing extensions with same name are
+    /// overwritten.
+    pub fn merge(&mut self, other: Extensions) {
+        self.extensions.extend(other.extensions);
+    }
+}
+
+impl Deref for Extensions {
+    type Target = HashMap<String, ExtensionValue>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.extensions
+    }
+}
+
+impl DerefMut for Extensions {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.extensions
+    }
+}
+
+impl<'de> Deserialize<'de> for Extensions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ExtensionsVisitor;
+
+        impl<'de> Visitor<'de> for ExtensionsVisitor {
+            type Value = Extensions;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("map of extensions")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut extensions = HashMap::new();
+                while let Some(name) = map.next_key::<String>()? {
+                    if name.starts_with(EXTENSION_PREFIX) {
+                        extensions.insert(name, map.next_value::<ExtensionValue>()?);
+                    } else {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+
+                Ok(Extensions { extensions })
+            }
+        }
+
+        deserializer.deserialize_map(ExtensionsVisitor)
+    }
+}
+
+/// Deserialize optional [`Extensions`] flattened to the extended object. Extensions are `None`
+/// when the object does not have any extensions so that deserialized objects are equal to the
+/// objects built without extensions.
+pub(crate) fn deserialize_optional<'de, D>(deserializer: D) -> Result<Option<Extensions>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Extensions::deserialize(deserializer)
+        .map(|extensions| (!extensions.is_empty()).then(|| extensions))
+}
+
+/// Builder for [`Extensions`] with chainable configuration methods to create a new [`Extensions`].
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ExtensionsBuilder {
+    extensions: HashMap<String, ExtensionValue>,
+}
+
+impl ExtensionsBuilder {
+    /// Construct a new [`ExtensionsBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add new extension. Name of the extension should start with `x-`.
+    pub fn add<K: Into<String>, V: Into<ExtensionValue>>(mut self, name: K, value: V) -> Self {
+        self.extensions.insert(name.into(), value.into());
+
+        self
+    }
+
+    /// Build [`Extensions`] from the builder.
+    pub fn build(self) -> Extensions {
+        Extensions {
+            extensions: self.extensions,
+        }
+    }
+}
+
+impl From<ExtensionsBuilder> for Extensions {
+    fn from(builder: ExtensionsBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<Extensions> for ExtensionsBuilder {
+    fn from(extensions: Extensions) -> Self {
+        Self {
+            extensions: extensions.extensions,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_only_prefixed_extensions() {
+        let extensions: Extensions = serde_json::from_value(json!({
+            "type": "string",
+            "x-enum-varnames": ["Foo", "Bar"],
+            "properties": { "name": { "type": "string" } }
+        }))
+        .unwrap();
+
+        assert_eq!(extensions.len(), 1);
+        assert_eq!(extensions["x-enum-varnames"], json!(["Foo", "Bar"]));
+    }
+}
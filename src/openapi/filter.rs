@@ -0,0 +1,313 @@
+//! Implements filtering of [`OpenApi`] documents and removal of unused components. See
+//! [`OpenApi::filter`] and [`OpenApi::remove_unused_components`] for more details.
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    path::Operation,
+    visit::{self, Visit},
+    Components, OpenApi, PathItemType, Ref, RefOr, SecurityRequirement,
+};
+
+/// Retain only operations matching the `predicate` and remove definitions which are no longer
+/// used by the retained operations.
+pub(super) fn filter<F>(openapi: &mut OpenApi, mut predicate: F)
+where
+    F: FnMut(&str, &PathItemType, &Operation) -> bool,
+{
+    openapi.paths.paths.retain(|path, path_item| {
+        path_item
+            .operations
+            .retain(|path_item_type, operation| predicate(path, path_item_type, operation));
+        !path_item.operations.is_empty()
+    });
+
+    let references = references(openapi);
+    remove_unused_components(openapi, &references);
+    if let Some(tags) = &mut openapi.tags {
+        tags.retain(|tag| references.tags.contains(&tag.name));
+    }
+}
+
+/// Remove components and security schemes which are not used by the paths, webhooks or
+/// security requirements of the document either directly or through other components.
+pub(super) fn remove_unused(openapi: &mut OpenApi) {
+    let references = references(openapi);
+    remove_unused_components(openapi, &references);
+}
+
+fn remove_unused_components(openapi: &mut OpenApi, references: &References) {
+    if let Some(components) = &mut openapi.components {
+        let is_used = |kind: &str, name: &String| {
+            references
+                .components
+                .get(kind)
+                .map_or(false, |names| names.contains(name))
+        };
+
+        components
+            .schemas
+            .retain(|name, _| is_used("schemas", name));
+        components
+            .responses
+            .retain(|name, _| is_used("responses", name));
+        components
+            .parameters
+            .retain(|name, _| is_used("parameters", name));
+        components
+            .examples
+            .retain(|name, _| is_used("examples", name));
+        components
+            .request_bodies
+            .retain(|name, _| is_used("requestBodies", name));
+        components
+            .headers
+            .retain(|name, _| is_used("headers", name));
+        components.links.retain(|name, _| is_used("links", name));
+        components
+            .callbacks
+            .retain(|name, _| is_used("callbacks", name));
+        components
+            .security_schemes
+            .retain(|name, _| references.security_schemes.contains(name));
+    }
+}
+
+/// Collect definitions used by the document outside of components and the components used by
+/// them recursively.
+fn references(openapi: &OpenApi) -> References {
+    let mut references = References::default();
+    references.visit_paths(&openapi.paths);
+    openapi
+        .webhooks
+        .iter()
+        .for_each(|(name, path_item)| references.visit_path_item(name, path_item));
+    openapi
+        .security
+        .iter()
+        .flatten()
+        .for_each(|requirement| references.visit_security_requirement(requirement));
+
+    if let Some(components) = &openapi.components {
+        while let Some((kind, name)) = references.pending.pop() {
+            references.visit_named_component(components, &kind, &name);
+        }
+    }
+
+    references
+}
+
+/// Names of the used components by kind e.g. _`Pet`_ of _`schemas`_, security schemes and tags.
+#[derive(Default)]
+struct References {
+    components: BTreeMap<String, BTreeSet<String>>,
+    /// Components found but not yet visited.
+    pending: Vec<(String, String)>,
+    security_schemes: BTreeSet<String>,
+    tags: BTreeSet<String>,
+}
+
+impl References {
+    fn visit_named_component(&mut self, components: &Components, kind: &str, name: &str) {
+        match kind {
+            "schemas" => {
+                if let Some(schema) = components.schemas.get(name) {
+                    self.visit_component(schema);
+                }
+            }
+            "responses" => self.visit_ref_or(components.responses.get(name), Self::visit_response),
+            "parameters" => {
+                self.visit_ref_or(components.parameters.get(name), Self::visit_parameter)
+            }
+            "examples" => self.visit_ref_or(components.examples.get(name), Self::visit_example),
+            "requestBodies" => self.visit_ref_or(
+                components.request_bodies.get(name),
+                Self::visit_request_body,
+            ),
+            "headers" => self.visit_ref_or(components.headers.get(name), Self::visit_header),
+            "links" => self.visit_ref_or(components.links.get(name), Self::visit_link),
+            "callbacks" => self.visit_ref_or(components.callbacks.get(name), Self::visit_callback),
+            _ => (),
+        }
+    }
+
+    fn visit_ref_or<'a, T>(&mut self, ref_or: Option<&'a RefOr<T>>, visit: fn(&mut Self, &'a T)) {
+        match ref_or {
+            Some(RefOr::Ref(reference)) => self.visit_ref(reference),
+            Some(RefOr::T(value)) => visit(self, value),
+            None => (),
+        }
+    }
+}
+
+impl<'a> Visit<'a> for References {
+    fn visit_operation(&mut self, path_item_type: &'a PathItemType, operation: &'a Operation) {
+        self.tags.extend(operation.tags.iter().flatten().cloned());
+        visit::visit_operation(self, path_item_type, operation);
+    }
+
+    fn visit_ref(&mut self, reference: &'a Ref) {
+        let kind = reference
+            .ref_location
+            .strip_prefix("#/components/")
+            .and_then(|location| location.split('/').next());
+
+        if let Some((kind, name)) =
+            kind.and_then(|kind| Some((kind, reference.component_name(kind)?)))
+        {
+            let names = self.components.entry(kind.to_string()).or_default();
+            if names.insert(name.clone()) {
+                self.pending.push((kind.to_string(), name));
+            }
+        }
+    }
+
+    fn visit_security_requirement(&mut self, security_requirement: &'a SecurityRequirement) {
+        self.security_schemes
+            .extend(security_requirement.value.keys().cloned());
+    }
+}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn openapi() -> OpenApi {
+        let response = |schema: &str| {
+            json!({
+                "description": "success",
+                "content": {
+                    "application/json": { "schema": { "$ref": format!("#/components/schemas/{}", schema) } },
+                },
+            })
+        };
+        let openapi = json!({
+            "openapi": "3.0.3",
+            "info": { "title": "pet api", "version": "1.0.0" },
+            "paths": {
+                "/api/pets": {
+                    "get": {
+                        "tags": ["pets"],
+                        "security": [{ "api_key": [] }],
+                        "responses": {
+                            "200": response("Pet"),
+                            "500": { "$ref": "#/components/responses/Error" },
+                        },
+                    },
+                },
+                "/apis": {
+                    "get": { "tags": ["apis"], "responses": { "200": response("Api") } },
+                },
+                "/internal/metrics": {
+                    "get": {
+                        "tags": ["internal"],
+                        "security": [{ "basic": [] }],
+                        "responses": { "200": response("Metrics") },
+                    },
+                },
+            },
+            "components": {
+                "schemas": {
+                    "Api": { "type": "string" },
+                    "Error": { "type": "string" },
+                    "Metrics": { "type": "object" },
+                    "Owner": { "type": "object" },
+                    "Pet": {
+                        "type": "object",
+                        "properties": { "owner": { "$ref": "#/components/schemas/Owner" } },
+                    },
+                },
+                "responses": { "Error": response("Error") },
+                "securitySchemes": {
+                    "api_key": { "type": "apiKey", "name": "api_key", "in": "header" },
+                    "basic": { "type": "http", "scheme": "basic" },
+                },
+            },
+            "tags": [{ "name": "apis" }, { "name": "internal" }, { "name": "pets" }],
+        });
+
+        match OpenApi::from_json(&openapi.to_string()) {
+            Ok(openapi) => openapi,
+            Err(error) => panic!("invalid test document: {}", error),
+        }
+    }
+
+    fn component_names(openapi: &OpenApi) -> Value {
+        let components = openapi.components.as_ref().unwrap();
+        let names = |names: Vec<&String>| {
+            let mut names = names;
+            names.sort();
+            json!(names)
+        };
+
+        json!({
+            "schemas": names(components.schemas.keys().collect()),
+            "responses": names(components.responses.keys().collect()),
+            "securitySchemes": names(components.security_schemes.keys().collect()),
+            "tags": openapi.tags.iter().flatten().map(|tag| &tag.name).collect::<Vec<_>>(),
+        })
+    }
+
+    #[test]
+    fn filter_by_path_prefix_removes_unused_definitions() {
+        let openapi = openapi().filter_by_path_prefix("/api/");
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/api/pets"]
+        );
+        assert_eq!(
+            component_names(&openapi),
+            json!({
+                "schemas": ["Error", "Owner", "Pet"],
+                "responses": ["Error"],
+                "securitySchemes": ["api_key"],
+                "tags": ["pets"],
+            })
+        );
+    }
+
+    #[test]
+    fn filter_by_tag_removes_unused_definitions() {
+        let openapi = openapi().filter_by_tag("internal");
+
+        assert_eq!(
+            openapi.paths.paths.keys().collect::<Vec<_>>(),
+            ["/internal/metrics"]
+        );
+        assert_eq!(
+            component_names(&openapi),
+            json!({
+                "schemas": ["Metrics"],
+                "responses": [],
+                "securitySchemes": ["basic"],
+                "tags": ["internal"],
+            })
+        );
+    }
+
+    #[test]
+    fn remove_unused_components_retains_paths_and_tags() {
+        let mut openapi = openapi();
+        openapi
+            .components
+            .as_mut()
+            .unwrap()
+            .schemas
+            .insert("Unused".to_string(), Default::default());
+
+        openapi.remove_unused_components();
+
+        assert_eq!(openapi.paths.paths.len(), 3);
+        assert_eq!(
+            component_names(&openapi),
+            json!({
+                "schemas": ["Api", "Error", "Metrics", "Owner", "Pet"],
+                "responses": ["Error"],
+                "securitySchemes": ["api_key", "basic"],
+                "tags": ["apis", "internal", "pets"],
+            })
+        );
+    }
+}
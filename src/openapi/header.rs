@@ -2,9 +2,14 @@
 //!
 //! [header]: https://spec.openapis.org/oas/latest.html#header-object
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-use super::{build_fn, builder, from, new, set_value, Component, ComponentType, Property};
+use super::{
+    build_fn, builder, example::Example, from, new, ref_or_from, set_value, Component,
+    ComponentType, Property, RefOr,
+};
 
 builder! {
     HeaderBuilder;
@@ -13,7 +18,7 @@ builder! {
     ///
     /// [header]: https://spec.openapis.org/oas/latest.html#header-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Clone)]
+    #[derive(Serialize, Deserialize, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct Header {
         /// Schema of header type.
@@ -22,6 +27,10 @@ builder! {
         /// Additional descripiton of the header value.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+
+        /// Map of named [`Example`]s of the header value.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+        pub examples: BTreeMap<String, RefOr<Example>>,
     }
 }
 
@@ -57,6 +66,7 @@ impl Default for Header {
         Self {
             description: Default::default(),
             schema: Property::new(ComponentType::String).into(),
+            examples: Default::default(),
         }
     }
 }
@@ -71,4 +81,25 @@ impl HeaderBuilder {
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
     }
+
+    /// Add iterator of named [`Example`]s of the header value. Examples can be either inline
+    /// [`Example`]s or references to [`Example`]s in components.
+    pub fn examples_from_iter<
+        I: IntoIterator<Item = (N, E)>,
+        N: Into<String>,
+        E: Into<RefOr<Example>>,
+    >(
+        mut self,
+        examples: I,
+    ) -> Self {
+        self.examples.extend(
+            examples
+                .into_iter()
+                .map(|(name, example)| (name.into(), example.into())),
+        );
+
+        self
+    }
 }
+
+ref_or_from!(Header HeaderBuilder);
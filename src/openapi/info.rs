@@ -8,7 +8,11 @@
 //! [derive]: ../../derive.OpenApi.html
 use serde::{Deserialize, Serialize};
 
-use super::{build_fn, builder, from, new, set_value};
+use super::{
+    build_fn, builder,
+    extensions::{self, Extensions},
+    from, new, set_value,
+};
 
 builder! {
     /// # Examples
@@ -35,13 +39,22 @@ builder! {
     ///
     /// [info]: <https://spec.openapis.org/oas/latest.html#info-object>
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Info {
         /// Title of the API.
         pub title: String,
 
+        /// Optional short summary of the API. This is only serialized with
+        /// [`OpenApiVersion::Version31`][version].
+        ///
+        /// [version]: ../enum.OpenApiVersion.html#variant.Version31
+        #[serde(
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub summary: Option<String>,
+
         /// Optional description of the API.
         ///
         /// Value supports markdown syntax.
@@ -66,6 +79,14 @@ builder! {
 
         /// Document version typically the API version.
         pub version: String,
+
+        /// Optional extensions `x-something` of the [`Info`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -101,6 +122,11 @@ impl InfoBuilder {
         set_value!(self version version.into())
     }
 
+    /// Add short summary of the API. This is only serialized with OpenAPI 3.1.
+    pub fn summary<S: Into<String>>(mut self, summary: Option<S>) -> Self {
+        set_value!(self summary summary.map(|summary| summary.into()))
+    }
+
     /// Add description of the API.
     pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
         set_value!(self description description.map(|description| description.into()))
@@ -120,6 +146,11 @@ impl InfoBuilder {
     pub fn license(mut self, license: Option<License>) -> Self {
         set_value!(self license license)
     }
+
+    /// Add or change optional extensions `x-something` of the [`Info`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 builder! {
@@ -133,7 +164,7 @@ builder! {
     ///
     /// [contact]: <https://spec.openapis.org/oas/latest.html#contact-object>
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Contact {
@@ -182,13 +213,23 @@ builder! {
     ///
     /// [license]: <https://spec.openapis.org/oas/latest.html#license-object>
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct License {
         /// Name of the license used e.g MIT or Apache-2.0
         pub name: String,
 
+        /// Optional [SPDX][spdx] license expression of the API e.g. _`MIT OR Apache-2.0`_. This is
+        /// only serialized with [`OpenApiVersion::Version31`][version].
+        ///
+        /// [spdx]: https://spdx.org/licenses/
+        /// [version]: ../enum.OpenApiVersion.html#variant.Version31
+        #[serde(
+            skip_serializing_if = "Option::is_none"
+        )]
+        pub identifier: Option<String>,
+
         /// Optional url pointing to the license.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub url: Option<String>,
@@ -213,6 +254,13 @@ impl LicenseBuilder {
         set_value!(self name name.into())
     }
 
+    /// Add [SPDX][spdx] license expression of the API. This is only serialized with OpenAPI 3.1.
+    ///
+    /// [spdx]: https://spdx.org/licenses/
+    pub fn identifier<S: Into<String>>(mut self, identifier: Option<S>) -> Self {
+        set_value!(self identifier identifier.map(|identifier| identifier.into()))
+    }
+
     /// Add url pointing to the license used in API.
     pub fn url<S: Into<String>>(mut self, url: Option<S>) -> Self {
         set_value!(self url url.map(|url| url.into()))
@@ -0,0 +1,481 @@
+//! Implements export of [`Component`]s as standalone [JSON Schema][json_schema] documents.
+//!
+//! OpenAPI schemas are extended subset of JSON Schema. Exported JSON Schema is self-contained
+//! so that all components referenced by the exported component are embedded to the document
+//! and references are rewritten to point to the embedded definitions. OpenAPI only keywords
+//! are either translated to JSON Schema equivalents or dropped.
+//!
+//! **json** feature need to be enabled.
+//!
+//! [json_schema]: https://json-schema.org/
+use std::collections::BTreeSet;
+
+use serde_json::{Map, Value};
+
+use super::schema::{Component, Components};
+
+const COMPONENTS_SCHEMAS: &str = "#/components/schemas/";
+
+/// JSON Schema draft used in exported JSON Schema document.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[non_exhaustive]
+pub enum JsonSchemaDraft {
+    /// [Draft-07](https://json-schema.org/draft-07/json-schema-release-notes.html). Definitions
+    /// are embedded to _`definitions`_.
+    Draft07,
+    /// [Draft 2020-12](https://json-schema.org/draft/2020-12/release-notes.html). Definitions
+    /// are embedded to _`$defs`_.
+    Draft202012,
+}
+
+impl JsonSchemaDraft {
+    fn schema_uri(&self) -> &'static str {
+        match self {
+            Self::Draft07 => "http://json-schema.org/draft-07/schema#",
+            Self::Draft202012 => "https://json-schema.org/draft/2020-12/schema",
+        }
+    }
+
+    fn definitions_key(&self) -> &'static str {
+        match self {
+            Self::Draft07 => "definitions",
+            Self::Draft202012 => "$defs",
+        }
+    }
+}
+
+/// Export [`Component`] as standalone JSON Schema document.
+///
+/// All components referenced directly or transitively by the `component` are looked up from
+/// given `components` and embedded to the document. References to components which cannot be
+/// found from `components` are left as is.
+///
+/// Following OpenAPI only keywords are translated or dropped:
+/// * `nullable: true` is translated to `null` type.
+/// * `example` is translated to `examples` array.
+/// * Boolean `exclusiveMinimum` and `exclusiveMaximum` are translated to numeric ones.
+/// * `xml`, `discriminator` and `externalDocs` are dropped.
+///
+/// # Examples
+///
+/// Export `Pet` component as draft 2020-12 JSON Schema.
+/// ```rust
+/// # use utoipa::openapi::{ComponentsBuilder, ObjectBuilder, PropertyBuilder, ComponentType, Ref};
+/// # use utoipa::openapi::json_schema::{to_json_schema, JsonSchemaDraft};
+/// let components = ComponentsBuilder::new()
+///     .component(
+///         "Owner",
+///         ObjectBuilder::new()
+///             .property("name", PropertyBuilder::new().component_type(ComponentType::String)),
+///     )
+///     .build();
+/// let pet = ObjectBuilder::new()
+///     .property("owner", Ref::from_component_name("Owner"))
+///     .build()
+///     .into();
+///
+/// let schema = to_json_schema(&pet, &components, JsonSchemaDraft::Draft202012);
+/// assert_eq!(schema["properties"]["owner"]["$ref"], "#/$defs/Owner");
+/// assert_eq!(schema["$defs"]["Owner"]["type"], "object");
+/// ```
+pub fn to_json_schema(
+    component: &Component,
+    components: &Components,
+    draft: JsonSchemaDraft,
+) -> Value {
+    let mut schema = to_value(component);
+
+    let mut referenced = BTreeSet::new();
+    collect_references(&schema, components, &mut referenced);
+
+    let definitions = referenced
+        .into_iter()
+        .map(|name| {
+            let mut definition = to_value(&components.schemas[&name]);
+            translate(&mut definition, components, draft);
+
+            (name, definition)
+        })
+        .collect::<Map<String, Value>>();
+
+    translate(&mut schema, components, draft);
+
+    let mut document = Map::new();
+    document.insert("$schema".to_string(), Value::from(draft.schema_uri()));
+    match schema {
+        Value::Object(schema) => document.extend(schema),
+        // Component always serializes to an object but keep the schema in case it does not.
+        schema => {
+            document.insert("allOf".to_string(), Value::Array(vec![schema]));
+        }
+    }
+    if !definitions.is_empty() {
+        document.insert(
+            draft.definitions_key().to_string(),
+            Value::Object(definitions),
+        );
+    }
+
+    Value::Object(document)
+}
+
+fn to_value(component: &Component) -> Value {
+    serde_json::to_value(component).expect("Component should serialize to JSON")
+}
+
+/// Get name of the component referenced from components schemas if the reference is such.
+fn component_name(reference: &Value) -> Option<&str> {
+    reference.as_str()?.strip_prefix(COMPONENTS_SCHEMAS)
+}
+
+/// Collect names of all components from `components` referenced by the schema directly or
+/// transitively through other referenced components.
+fn collect_references(schema: &Value, components: &Components, referenced: &mut BTreeSet<String>) {
+    for_each_subschema(schema, &mut |subschema| {
+        if let Some(name) = subschema.get("$ref").and_then(component_name) {
+            if let Some(component) = components.schemas.get(name) {
+                if referenced.insert(name.to_string()) {
+                    collect_references(&to_value(component), components, referenced);
+                }
+            }
+        }
+    });
+}
+
+/// Call `op` for the given schema and all subschemas within it. Only keywords containing
+/// schemas are visited so that e.g. property names and examples are never confused with
+/// schema keywords.
+fn for_each_subschema(schema: &Value, op: &mut impl FnMut(&Value)) {
+    op(schema);
+
+    if let Some(schema) = schema.as_object() {
+        for (keyword, value) in schema {
+            match keyword.as_str() {
+                "properties" | "$defs" => value
+                    .as_object()
+                    .into_iter()
+                    .flat_map(|properties| properties.values())
+                    .for_each(|property| for_each_subschema(property, op)),
+                "items" | "additionalProperties" | "not" => for_each_subschema(value, op),
+                "oneOf" | "anyOf" | "allOf" => value
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .for_each(|item| for_each_subschema(item, op)),
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Translate OpenAPI schema to JSON Schema in place.
+fn translate(schema: &mut Value, components: &Components, draft: JsonSchemaDraft) {
+    for_each_subschema_mut(schema, &mut |schema| {
+        if let Some(reference) = schema.get_mut("$ref") {
+            if let Some(name) = component_name(reference) {
+                if components.schemas.contains_key(name) {
+                    *reference = Value::from(format!("#/{}/{}", draft.definitions_key(), name));
+                }
+            }
+        }
+
+        schema.remove("xml");
+        schema.remove("discriminator");
+        schema.remove("externalDocs");
+
+        translate_keywords(schema);
+    });
+}
+
+/// Call `op` for the given schema and all subschemas within it by mutable reference. Subschemas
+/// are visited after `op` is called for the schema containing them so that subschemas added by
+/// `op` are visited as well.
+pub(super) fn for_each_subschema_mut(
+    schema: &mut Value,
+    op: &mut impl FnMut(&mut Map<String, Value>),
+) {
+    let schema = match schema.as_object_mut() {
+        Some(schema) => schema,
+        None => return,
+    };
+
+    op(schema);
+
+    for (keyword, value) in schema.iter_mut() {
+        match keyword.as_str() {
+            "properties" | "$defs" => {
+                if let Some(properties) = value.as_object_mut() {
+                    properties
+                        .values_mut()
+                        .for_each(|property| for_each_subschema_mut(property, op))
+                }
+            }
+            "items" | "additionalProperties" | "not" => for_each_subschema_mut(value, op),
+            "oneOf" | "anyOf" | "allOf" => {
+                if let Some(items) = value.as_array_mut() {
+                    items
+                        .iter_mut()
+                        .for_each(|item| for_each_subschema_mut(item, op))
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Translate OpenAPI 3.0 only keywords of the schema to JSON Schema equivalents. Only keywords
+/// of the given schema are translated, subschemas are left untouched.
+pub(super) fn translate_keywords(schema: &mut Map<String, Value>) {
+    if let Some(example) = schema.remove("example") {
+        schema.insert("examples".to_string(), Value::Array(vec![example]));
+    }
+
+    translate_exclusive_limit(schema, "exclusiveMinimum", "minimum");
+    translate_exclusive_limit(schema, "exclusiveMaximum", "maximum");
+
+    if let Some(Value::Bool(true)) = schema.remove("nullable") {
+        match schema.get_mut("type") {
+            Some(Value::String(component_type)) => {
+                let component_type = Value::from(component_type.as_str());
+                schema.insert(
+                    "type".to_string(),
+                    Value::Array(vec![component_type, Value::from("null")]),
+                );
+            }
+            _ => {
+                let nullable = match single_all_of(schema) {
+                    Some(item) => item,
+                    None => Value::Object(std::mem::take(schema)),
+                };
+                schema.insert(
+                    "anyOf".to_string(),
+                    Value::Array(vec![nullable, serde_json::json!({ "type": "null" })]),
+                );
+            }
+        }
+    }
+}
+
+/// Take the only item of the schema if _`allOf`_ with single item is the only keyword of the
+/// schema. OpenAPI 3.0 references cannot have other keywords so nullable references are wrapped
+/// to _`allOf`_ which is unnecessary for JSON Schema.
+fn single_all_of(schema: &mut Map<String, Value>) -> Option<Value> {
+    match schema.get("allOf") {
+        Some(Value::Array(items)) if schema.len() == 1 && items.len() == 1 => schema
+            .remove("allOf")
+            .and_then(|items| items.as_array()?.first().cloned()),
+        _ => None,
+    }
+}
+
+/// OpenAPI 3.0 has boolean exclusive limits modifying the actual limit where JSON Schema has
+/// numeric exclusive limits.
+fn translate_exclusive_limit(schema: &mut Map<String, Value>, exclusive: &str, limit: &str) {
+    match schema.get(exclusive) {
+        Some(Value::Bool(true)) => {
+            schema.remove(exclusive);
+            if let Some(limit) = schema.remove(limit) {
+                schema.insert(exclusive.to_string(), limit);
+            }
+        }
+        Some(Value::Bool(false)) => {
+            schema.remove(exclusive);
+        }
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::openapi::{
+        xml::XmlBuilder, ComponentType, ComponentsBuilder, ObjectBuilder, PropertyBuilder, Ref,
+        ToArray,
+    };
+
+    fn components() -> Components {
+        ComponentsBuilder::new()
+            .component(
+                "Owner",
+                ObjectBuilder::new()
+                    .property(
+                        "name",
+                        PropertyBuilder::new().component_type(ComponentType::String),
+                    )
+                    .property("address", Ref::from_component_name("Address"))
+                    .required("name"),
+            )
+            .component(
+                "Address",
+                ObjectBuilder::new()
+                    .property(
+                        "street",
+                        PropertyBuilder::new().component_type(ComponentType::String),
+                    )
+                    .xml(Some(XmlBuilder::new().name(Some("addr")).build())),
+            )
+            .component("Unused", ObjectBuilder::new())
+            .build()
+    }
+
+    #[test]
+    fn export_component_with_transitive_references() {
+        let pet = ObjectBuilder::new()
+            .property(
+                "age",
+                PropertyBuilder::new()
+                    .component_type(ComponentType::Integer)
+                    .minimum(Some(0.0))
+                    .exclusive_minimum(Some(true))
+                    .example(Some(json!(3))),
+            )
+            .property("owner", Ref::from_component_name("Owner"))
+            .property("missing", Ref::from_component_name("Missing"))
+            .build()
+            .into();
+
+        let schema = to_json_schema(&pet, &components(), JsonSchemaDraft::Draft202012);
+
+        assert_eq!(
+            schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "age": {
+                        "type": "integer",
+                        "exclusiveMinimum": 0.0,
+                        "examples": [3]
+                    },
+                    "owner": {
+                        "$ref": "#/$defs/Owner"
+                    },
+                    "missing": {
+                        "$ref": "#/components/schemas/Missing"
+                    }
+                },
+                "$defs": {
+                    "Owner": {
+                        "type": "object",
+                        "required": ["name"],
+                        "properties": {
+                            "name": {
+                                "type": "string"
+                            },
+                            "address": {
+                                "$ref": "#/$defs/Address"
+                            }
+                        }
+                    },
+                    "Address": {
+                        "type": "object",
+                        "properties": {
+                            "street": {
+                                "type": "string"
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn export_component_as_draft_07() {
+        let owner = components().schemas.remove("Owner").unwrap();
+
+        let schema = to_json_schema(&owner, &components(), JsonSchemaDraft::Draft07);
+
+        assert_eq!(
+            schema["$schema"],
+            json!("http://json-schema.org/draft-07/schema#")
+        );
+        assert_eq!(
+            schema["properties"]["address"]["$ref"],
+            json!("#/definitions/Address")
+        );
+        assert_eq!(schema["definitions"]["Address"]["type"], json!("object"));
+        assert_eq!(schema.get("$defs"), None);
+    }
+
+    #[test]
+    fn export_recursive_components() {
+        let components = ComponentsBuilder::new()
+            .component(
+                "Node",
+                ObjectBuilder::new()
+                    .property("children", Ref::from_component_name("Node").to_array())
+                    .property(
+                        "named",
+                        ObjectBuilder::new()
+                            .additional_properties(Some(Ref::from_component_name("Leaf"))),
+                    ),
+            )
+            .component(
+                "Leaf",
+                ObjectBuilder::new().property("parent", Ref::from_component_name("Node")),
+            )
+            .build();
+        let node = components.schemas["Node"].clone();
+
+        let schema = to_json_schema(&node, &components, JsonSchemaDraft::Draft202012);
+
+        assert_eq!(
+            schema["properties"]["children"]["items"]["$ref"],
+            json!("#/$defs/Node")
+        );
+        assert_eq!(
+            schema["properties"]["named"]["additionalProperties"]["$ref"],
+            json!("#/$defs/Leaf")
+        );
+        assert_eq!(
+            schema["$defs"]["Leaf"]["properties"]["parent"]["$ref"],
+            json!("#/$defs/Node")
+        );
+        assert_eq!(schema["$defs"].as_object().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn translate_nullable() {
+        let mut schema = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "nullable": true },
+                "owner": { "$ref": "#/components/schemas/Owner", "nullable": true },
+                "address": {
+                    "allOf": [{ "$ref": "#/components/schemas/Address" }],
+                    "nullable": true
+                },
+                "xml": { "type": "string" }
+            }
+        });
+
+        translate(&mut schema, &components(), JsonSchemaDraft::Draft202012);
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": { "type": ["string", "null"] },
+                    "owner": {
+                        "anyOf": [
+                            { "$ref": "#/$defs/Owner" },
+                            { "type": "null" }
+                        ]
+                    },
+                    "address": {
+                        "anyOf": [
+                            { "$ref": "#/$defs/Address" },
+                            { "type": "null" }
+                        ]
+                    },
+                    "xml": { "type": "string" }
+                }
+            })
+        );
+    }
+}
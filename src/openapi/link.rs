@@ -0,0 +1,161 @@
+//! Implements [OpenAPI Link Object][link] types.
+//!
+//! [link]: https://spec.openapis.org/oas/latest.html#link-object
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use super::{build_fn, from, new, ref_or_from, set_value, Server};
+
+/// Implements [OpenAPI Link Object][link].
+///
+/// Link represents a possible design-time link for a response. It describes how values
+/// of the response can be used as an input for another operation. Either
+/// [`Link::operation_ref`] or [`Link::operation_id`] should be defined but not both.
+///
+/// [link]: https://spec.openapis.org/oas/latest.html#link-object
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct Link {
+    /// Relative or absolute uri reference to an [`Operation`][operation].
+    ///
+    /// [operation]: ../path/struct.Operation.html
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_ref: Option<String>,
+
+    /// Name of an existing resolvable [`Operation`][operation] identified by its unique
+    /// operation id.
+    ///
+    /// [operation]: ../path/struct.Operation.html
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+
+    /// Map of parameter names and their values or runtime expressions passed to the linked
+    /// operation.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    #[cfg(feature = "serde_json")]
+    pub parameters: BTreeMap<String, Value>,
+
+    /// Map of parameter names and their values or runtime expressions passed to the linked
+    /// operation.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    #[cfg(not(feature = "serde_json"))]
+    pub parameters: BTreeMap<String, String>,
+
+    /// Literal value or runtime expression used as request body of the linked operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub request_body: Option<Value>,
+
+    /// Literal value or runtime expression used as request body of the linked operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub request_body: Option<String>,
+
+    /// Description of the [`Link`]. Markdown syntax is supported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Alternative [`Server`] to be used by the linked operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<Server>,
+}
+
+impl Link {
+    /// Construct a new empty [`Link`]. This is effectively same as calling [`Link::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Builder for [`Link`] with chainable configuration methods to create a new [`Link`].
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct LinkBuilder {
+    operation_ref: Option<String>,
+
+    operation_id: Option<String>,
+
+    #[cfg(feature = "serde_json")]
+    parameters: BTreeMap<String, Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    parameters: BTreeMap<String, String>,
+
+    #[cfg(feature = "serde_json")]
+    request_body: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    request_body: Option<String>,
+
+    description: Option<String>,
+
+    server: Option<Server>,
+}
+
+from!(Link LinkBuilder operation_ref, operation_id, parameters, request_body, description, server);
+
+impl LinkBuilder {
+    new!(pub LinkBuilder);
+
+    /// Add or change relative or absolute uri reference to the linked operation.
+    pub fn operation_ref<S: Into<String>>(mut self, operation_ref: Option<S>) -> Self {
+        set_value!(self operation_ref operation_ref.map(|operation_ref| operation_ref.into()))
+    }
+
+    /// Add or change operation id of the linked operation.
+    pub fn operation_id<S: Into<String>>(mut self, operation_id: Option<S>) -> Self {
+        set_value!(self operation_id operation_id.map(|operation_id| operation_id.into()))
+    }
+
+    /// Add parameter passed to the linked operation. Value is either literal value or
+    /// runtime expression e.g. _`$response.body#/id`_.
+    #[cfg(feature = "serde_json")]
+    pub fn parameter<S: Into<String>, V: Into<Value>>(mut self, name: S, value: V) -> Self {
+        self.parameters.insert(name.into(), value.into());
+
+        self
+    }
+
+    /// Add parameter passed to the linked operation. Value is either literal value or
+    /// runtime expression e.g. _`$response.body#/id`_.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn parameter<S: Into<String>, V: Into<String>>(mut self, name: S, value: V) -> Self {
+        self.parameters.insert(name.into(), value.into());
+
+        self
+    }
+
+    /// Add or change literal value or runtime expression used as request body of the
+    /// linked operation.
+    #[cfg(feature = "serde_json")]
+    pub fn request_body(mut self, request_body: Option<Value>) -> Self {
+        set_value!(self request_body request_body)
+    }
+
+    /// Add or change literal value or runtime expression used as request body of the
+    /// linked operation.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn request_body<S: Into<String>>(mut self, request_body: Option<S>) -> Self {
+        set_value!(self request_body request_body.map(|request_body| request_body.into()))
+    }
+
+    /// Add or change description of the [`Link`]. Markdown syntax is supported.
+    pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add or change alternative [`Server`] used by the linked operation.
+    pub fn server<S: Into<Server>>(mut self, server: Option<S>) -> Self {
+        set_value!(self server server.map(|server| server.into()))
+    }
+
+    build_fn!(pub Link operation_ref, operation_id, parameters, request_body, description, server);
+}
+
+ref_or_from!(Link LinkBuilder);
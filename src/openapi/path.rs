@@ -1,17 +1,24 @@
 //! Implements [OpenAPI Path Object][paths] types.
 //!
 //! [paths]: https://spec.openapis.org/oas/latest.html#paths-object
-use std::{collections::BTreeMap, iter};
+use std::{collections::BTreeMap, fmt, iter};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, IgnoredAny, IntoDeserializer, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 #[cfg(feature = "serde_json")]
 use serde_json::Value;
 
 use super::{
-    build_fn, builder, from, new,
+    build_fn, builder,
+    callback::Callback,
+    example::Example,
+    extensions::{self, ExtensionValue, Extensions},
+    from, new, ref_or_from,
     request_body::RequestBody,
     response::{Response, Responses},
-    set_value, Component, Deprecated, ExternalDocs, Required, SecurityRequirement, Server,
+    set_value, Component, Deprecated, ExternalDocs, RefOr, Required, SecurityRequirement, Server,
 };
 
 builder! {
@@ -24,8 +31,9 @@ builder! {
     ///
     /// [paths]: https://spec.openapis.org/oas/latest.html#paths-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(transparent)]
     pub struct Paths {
         /// Map of relative paths with [`PathItem`]s holding [`Operation`]s matching
         /// api endpoints.
@@ -83,7 +91,7 @@ builder! {
     ///
     /// [path_item]: https://spec.openapis.org/oas/latest.html#path-item-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct PathItem {
@@ -105,12 +113,20 @@ builder! {
         /// contain duplicate parameters. They can be overridden in [`Operation`] level but cannot be
         /// removed there.
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
         /// Map of operations in this [`PathItem`]. Operations can hold only one operation
         /// per [`PathItemType`].
         #[serde(flatten)]
         pub operations: BTreeMap<PathItemType, Operation>,
+
+        /// Optional extensions `x-something` of the [`PathItem`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -126,6 +142,67 @@ impl PathItem {
     }
 }
 
+/// Path item is deserialized manually because [`Operation`]s and extensions share the same map
+/// with the other fields of the [`PathItem`].
+impl<'de> Deserialize<'de> for PathItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PathItemVisitor;
+
+        impl<'de> Visitor<'de> for PathItemVisitor {
+            type Value = PathItem;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("path item object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path_item = PathItem::default();
+                let mut extensions = Extensions::new();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "summary" => path_item.summary = map.next_value()?,
+                        "description" => path_item.description = map.next_value()?,
+                        "servers" => path_item.servers = map.next_value()?,
+                        "parameters" => path_item.parameters = map.next_value()?,
+                        _ if key.starts_with("x-") => {
+                            extensions.insert(key, map.next_value::<ExtensionValue>()?);
+                        }
+                        _ => {
+                            let path_item_type: Result<PathItemType, de::value::Error> =
+                                PathItemType::deserialize(key.as_str().into_deserializer());
+
+                            match path_item_type {
+                                Ok(path_item_type) => {
+                                    let operation = map.next_value::<Operation>()?;
+                                    path_item.operations.insert(path_item_type, operation);
+                                }
+                                Err(_) => {
+                                    map.next_value::<IgnoredAny>()?;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !extensions.is_empty() {
+                    path_item.extensions = Some(extensions);
+                }
+
+                Ok(path_item)
+            }
+        }
+
+        deserializer.deserialize_map(PathItemVisitor)
+    }
+}
+
 impl PathItemBuilder {
     /// Append a new [`Operation`] by [`PathItemType`] to this [`PathItem`]. Operations can
     /// hold only one operation per [`PathItemType`].
@@ -157,8 +234,19 @@ impl PathItemBuilder {
     }
 
     /// Append list of [`Parameter`]s common to all [`Operation`]s to this [`PathItem`].
-    pub fn parameters<I: IntoIterator<Item = Parameter>>(mut self, parameters: Option<I>) -> Self {
-        set_value!(self parameters parameters.map(|parameters| parameters.into_iter().collect()))
+    /// Parameters can be given either inline or as [`Ref`][ref]s to reusable parameters.
+    ///
+    /// [ref]: ../schema/struct.Ref.html
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        parameters: Option<I>,
+    ) -> Self {
+        set_value!(self parameters parameters.map(|parameters| parameters.into_iter().map(|parameter| parameter.into()).collect()))
+    }
+
+    /// Add or change optional extensions `x-something` of the [`PathItem`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
     }
 }
 
@@ -187,6 +275,23 @@ pub enum PathItemType {
     Connect,
 }
 
+impl PathItemType {
+    /// Lowercase name of the type as used in the keys of [`PathItem`].
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::Post => "post",
+            Self::Put => "put",
+            Self::Delete => "delete",
+            Self::Options => "options",
+            Self::Head => "head",
+            Self::Patch => "patch",
+            Self::Trace => "trace",
+            Self::Connect => "connect",
+        }
+    }
+}
+
 builder! {
     OperationBuilder;
 
@@ -194,7 +299,7 @@ builder! {
     ///
     /// [operation]: https://spec.openapis.org/oas/latest.html#operation-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Operation {
@@ -243,7 +348,7 @@ builder! {
 
         /// List of applicable parameters for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub parameters: Option<Vec<Parameter>>,
+        pub parameters: Option<Vec<RefOr<Parameter>>>,
 
         /// Optional request body for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -252,9 +357,13 @@ builder! {
         /// List of possible responses returned by the [`Operation`].
         pub responses: Responses,
 
-        // TODO
+        /// Map of [`Callback`]s identified by callback name. Callbacks describe out-of-band requests
+        /// that may be initiated by the api provider related to this [`Operation`]. Callback is either
+        /// inline [`Callback`] or [`Ref`][ref] to reusable callback in components.
+        ///
+        /// [ref]: ../schema/struct.Ref.html
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub callbacks: Option<String>,
+        pub callbacks: Option<BTreeMap<String, RefOr<Callback>>>,
 
         /// Define whether the operation is deprecated or not and thus should be avoided consuming.
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -271,6 +380,14 @@ builder! {
         /// Alternative [`Server`]s for this [`Operation`].
         #[serde(skip_serializing_if = "Option::is_none")]
         pub servers: Option<Vec<Server>>,
+
+        /// Optional extensions `x-something` of the [`Operation`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -316,7 +433,7 @@ impl OperationBuilder {
     }
 
     /// Add or change parameters of the [`Operation`].
-    pub fn parameters<I: IntoIterator<Item = P>, P: Into<Parameter>>(
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<RefOr<Parameter>>>(
         mut self,
         parameters: Option<I>,
     ) -> Self {
@@ -336,7 +453,7 @@ impl OperationBuilder {
     }
 
     /// Append parameter to [`Operation`] parameters.
-    pub fn parameter<P: Into<Parameter>>(mut self, parameter: P) -> Self {
+    pub fn parameter<P: Into<RefOr<Parameter>>>(mut self, parameter: P) -> Self {
         match self.parameters {
             Some(ref mut parameters) => parameters.push(parameter.into()),
             None => {
@@ -360,9 +477,41 @@ impl OperationBuilder {
     /// Append status code and a [`Response`] to the [`Operation`] responses map.
     ///
     /// * `code` must be valid HTTP status code.
-    /// * `response` is instances of [`Response`].
-    pub fn response<S: Into<String>>(mut self, code: S, response: Response) -> Self {
-        self.responses.responses.insert(code.into(), response);
+    /// * `response` is instances of [`Response`] or [`Ref`][ref] to reusable response.
+    ///
+    /// [ref]: ../schema/struct.Ref.html
+    pub fn response<S: Into<String>, R: Into<RefOr<Response>>>(
+        mut self,
+        code: S,
+        response: R,
+    ) -> Self {
+        self.responses
+            .responses
+            .insert(code.into(), response.into());
+
+        self
+    }
+
+    /// Add or change callbacks of the [`Operation`].
+    pub fn callbacks<I: IntoIterator<Item = (S, C)>, S: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        callbacks: Option<I>,
+    ) -> Self {
+        set_value!(self callbacks callbacks.map(|callbacks| callbacks
+            .into_iter()
+            .map(|(name, callback)| (name.into(), callback.into()))
+            .collect()))
+    }
+
+    /// Append [`Callback`] identified by name to the [`Operation`] callbacks.
+    pub fn callback<S: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        name: S,
+        callback: C,
+    ) -> Self {
+        self.callbacks
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), callback.into());
 
         self
     }
@@ -406,13 +555,18 @@ impl OperationBuilder {
 
         self
     }
+
+    /// Add or change optional extensions `x-something` of the [`Operation`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 /// Implements [OpenAPI Parameter Object][parameter] for [`Operation`].
 ///
 /// [parameter]: https://spec.openapis.org/oas/latest.html#parameter-object
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(rename_all = "camelCase")]
 pub struct Parameter {
@@ -433,6 +587,7 @@ pub struct Parameter {
     /// Declares whether the parameter is required or not for api.
     ///
     /// * For [`ParameterIn::Path`] this must and will be [`Required::True`].
+    #[serde(default)]
     pub required: Required,
 
     /// Delcares the parameter deprecated status.
@@ -480,6 +635,19 @@ pub struct Parameter {
     #[cfg(not(feature = "serde_json"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     example: Option<String>,
+
+    /// Map of named [`Example`]s of [`Parameter`]'s potential value. This should not be used
+    /// together with [`Parameter`]'s example.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub examples: BTreeMap<String, RefOr<Example>>,
+
+    /// Optional extensions `x-something` of the [`Parameter`].
+    #[serde(
+        flatten,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl Parameter {
@@ -519,9 +687,13 @@ pub struct ParameterBuilder {
 
     #[cfg(not(feature = "serde_json"))]
     example: Option<String>,
+
+    examples: BTreeMap<String, RefOr<Example>>,
+
+    extensions: Option<Extensions>,
 }
 
-from!(Parameter ParameterBuilder name, parameter_in, description, required, deprecated, schema, style, explode, allow_reserved, example);
+from!(Parameter ParameterBuilder name, parameter_in, description, required, deprecated, schema, style, explode, allow_reserved, example, examples, extensions);
 
 impl ParameterBuilder {
     new!(pub ParameterBuilder);
@@ -589,9 +761,35 @@ impl ParameterBuilder {
         set_value!(self example example)
     }
 
-    build_fn!(pub Parameter name, parameter_in, required, description, deprecated, schema, style, explode, allow_reserved, example);
+    /// Add iterator of named [`Example`]s of [`Parameter`]'s potential value. Examples can be either
+    /// inline [`Example`]s or references to [`Example`]s in components.
+    pub fn examples_from_iter<
+        I: IntoIterator<Item = (N, E)>,
+        N: Into<String>,
+        E: Into<RefOr<Example>>,
+    >(
+        mut self,
+        examples: I,
+    ) -> Self {
+        self.examples.extend(
+            examples
+                .into_iter()
+                .map(|(name, example)| (name.into(), example.into())),
+        );
+
+        self
+    }
+
+    /// Add or change optional extensions `x-something` of the [`Parameter`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
+    build_fn!(pub Parameter name, parameter_in, required, description, deprecated, schema, style, explode, allow_reserved, example, examples, extensions);
 }
 
+ref_or_from!(Parameter ParameterBuilder);
+
 /// In definition of [`Parameter`].
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "lowercase")]
@@ -613,8 +811,20 @@ impl Default for ParameterIn {
     }
 }
 
+impl ParameterIn {
+    /// Lowercase name of the location as it is serialized to the OpenAPI document.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Query => "query",
+            Self::Path => "path",
+            Self::Header => "header",
+            Self::Cookie => "cookie",
+        }
+    }
+}
+
 /// Defines how [`Parameter`] should be serialized.
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(rename_all = "camelCase")]
 pub enum ParameterStyle {
@@ -646,8 +856,10 @@ pub enum ParameterStyle {
 
 #[cfg(test)]
 mod tests {
-    use super::{Operation, OperationBuilder};
-    use crate::openapi::{security::SecurityRequirement, server::Server};
+    use super::{Operation, OperationBuilder, PathItem, PathItemType};
+    use crate::openapi::{
+        callback::CallbackBuilder, security::SecurityRequirement, server::Server, RefOr,
+    };
 
     #[test]
     fn operation_new() {
@@ -691,4 +903,34 @@ mod tests {
 
         assert!(operation.servers.is_some());
     }
+
+    #[test]
+    fn operation_builder_callback_merges_expression_operations() {
+        let operation = OperationBuilder::new()
+            .callback(
+                "onEvent",
+                CallbackBuilder::new()
+                    .expression(
+                        "{$request.body#/callbackUrl}",
+                        PathItem::new(PathItemType::Post, Operation::new()),
+                    )
+                    .expression(
+                        "{$request.body#/callbackUrl}",
+                        PathItem::new(PathItemType::Put, Operation::new()),
+                    ),
+            )
+            .build();
+
+        let callbacks = operation
+            .callbacks
+            .expect("operation should have callbacks");
+        match callbacks.get("onEvent") {
+            Some(RefOr::T(callback)) => {
+                let path_item = &callback.callbacks["{$request.body#/callbackUrl}"];
+                assert!(path_item.operations.contains_key(&PathItemType::Post));
+                assert!(path_item.operations.contains_key(&PathItemType::Put));
+            }
+            _ => panic!("expected inline callback onEvent"),
+        }
+    }
 }
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::{build_fn, builder, from, new, set_value, Content, Required};
+use super::{build_fn, builder, from, new, ref_or_from, set_value, Content, Required};
 
 builder! {
     RequestBodyBuilder;
@@ -14,7 +14,7 @@ builder! {
     ///
     /// [request_body]: https://spec.openapis.org/oas/latest.html#request-body-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct RequestBody {
@@ -57,6 +57,8 @@ impl RequestBodyBuilder {
     }
 }
 
+ref_or_from!(RequestBody RequestBodyBuilder);
+
 #[cfg(test)]
 mod tests {
     use super::RequestBody;
@@ -5,7 +5,14 @@ use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize};
 
-use super::{build_fn, builder, from, header::Header, new, set_value, Content};
+use super::{
+    build_fn, builder,
+    extensions::{self, Extensions},
+    from,
+    header::Header,
+    link::Link,
+    new, ref_or_from, set_value, Content, RefOr,
+};
 
 builder! {
     ResponsesBuilder;
@@ -16,13 +23,17 @@ builder! {
     ///
     /// [responses]: https://spec.openapis.org/oas/latest.html#responses-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
-    #[serde(rename_all = "camelCase")]
+    #[serde(transparent)]
     pub struct Responses {
-        /// Map containing status code as a key with represented response as a value.
-        #[serde(flatten)]
-        pub responses: BTreeMap<String, Response>,
+        /// Map containing status code as a key with represented response as a value. Status code is
+        /// either http status code e.g. _`200`_, range of status codes e.g. _`4XX`_ or _`default`_.
+        /// Keys are ordered so that status codes are before their ranges and _`default`_ is the last one.
+        /// Response can be defined inline or as a [`Ref`][ref] to reusable response in components.
+        ///
+        /// [ref]: ../schema/struct.Ref.html
+        pub responses: BTreeMap<String, RefOr<Response>>,
     }
 }
 
@@ -34,22 +45,48 @@ impl Responses {
 
 impl ResponsesBuilder {
     /// Add response to responses.
-    pub fn response<S: Into<String>, R: Into<Response>>(mut self, code: S, response: R) -> Self {
+    pub fn response<S: Into<String>, R: Into<RefOr<Response>>>(
+        mut self,
+        code: S,
+        response: R,
+    ) -> Self {
         self.responses.insert(code.into(), response.into());
 
         self
     }
+
+    /// Add responses from iterator of status codes and responses.
+    ///
+    /// This is useful when adding responses of type implementing [`IntoResponses`][into_responses].
+    ///
+    /// [into_responses]: ../../trait.IntoResponses.html
+    pub fn responses_from_iter<
+        I: IntoIterator<Item = (C, R)>,
+        C: Into<String>,
+        R: Into<RefOr<Response>>,
+    >(
+        mut self,
+        iter: I,
+    ) -> Self {
+        self.responses.extend(
+            iter.into_iter()
+                .map(|(code, response)| (code.into(), response.into())),
+        );
+
+        self
+    }
 }
 
-impl<C> FromIterator<(C, Response)> for Responses
+impl<C, R> FromIterator<(C, R)> for Responses
 where
     C: Into<String>,
+    R: Into<RefOr<Response>>,
 {
-    fn from_iter<T: IntoIterator<Item = (C, Response)>>(iter: T) -> Self {
+    fn from_iter<T: IntoIterator<Item = (C, R)>>(iter: T) -> Self {
         Self {
             responses: BTreeMap::from_iter(
                 iter.into_iter()
-                    .map(|(code, response)| (code.into(), response)),
+                    .map(|(code, response)| (code.into(), response.into())),
             ),
         }
     }
@@ -64,7 +101,7 @@ builder! {
     ///
     /// [response]: https://spec.openapis.org/oas/latest.html#response-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Response {
@@ -72,12 +109,24 @@ builder! {
         pub description: String,
 
         /// Map of headers identified by their name. `Content-Type` header will be ignored.
-        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
         pub headers: HashMap<String, Header>,
 
         /// Map of response [`Content`] objects identified by response body content type e.g `application/json`.
-        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
         pub content: HashMap<String, Content>,
+
+        /// Map of operation [`Link`]s that can be followed from the response identified by link name.
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub links: HashMap<String, RefOr<Link>>,
+
+        /// Optional extensions `x-something` of the [`Response`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -112,11 +161,28 @@ impl ResponseBuilder {
 
         self
     }
+
+    /// Add [`Link`] to operation that can be followed from the [`Response`]. Link is either
+    /// inline [`Link`] or [`Ref`][ref] to reusable link in components.
+    ///
+    /// [ref]: ../schema/struct.Ref.html
+    pub fn link<S: Into<String>, L: Into<RefOr<Link>>>(mut self, name: S, link: L) -> Self {
+        self.links.insert(name.into(), link.into());
+
+        self
+    }
+
+    /// Add or change optional extensions `x-something` of the [`Response`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
+ref_or_from!(Response ResponseBuilder);
+
 #[cfg(test)]
 mod tests {
-    use super::Responses;
+    use super::{Response, Responses, ResponsesBuilder};
 
     #[test]
     fn responses_new() {
@@ -124,4 +190,20 @@ mod tests {
 
         assert!(responses.responses.is_empty());
     }
+
+    #[test]
+    fn responses_with_ranges_and_default_are_ordered() {
+        let responses = ResponsesBuilder::new()
+            .response("default", Response::new("Unexpected error"))
+            .response("4XX", Response::new("Client error"))
+            .response("404", Response::new("Not found"))
+            .response("200", Response::new("Success"))
+            .response("2XX", Response::new("Other success"))
+            .build();
+
+        assert_eq!(
+            responses.responses.keys().collect::<Vec<_>>(),
+            ["200", "2XX", "404", "4XX", "default"]
+        );
+    }
 }
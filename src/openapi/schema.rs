@@ -2,16 +2,39 @@
 //! used to define field properties, enum values, array or object types.
 //!
 //! [schema]: https://spec.openapis.org/oas/latest.html#schema-object
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, value::MapAccessDeserializer, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 #[cfg(feature = "serde_json")]
 use serde_json::Value;
 
+use self::keywords::Keywords;
 use super::{
-    build_fn, builder, from, new, security::SecurityScheme, set_value, xml::Xml, Deprecated,
+    build_fn, builder,
+    callback::Callback,
+    example::Example,
+    extensions::{self, Extensions},
+    from,
+    header::Header,
+    link::Link,
+    new,
+    path::Parameter,
+    request_body::RequestBody,
+    response::Response,
+    security::SecurityScheme,
+    set_value,
+    xml::Xml,
+    Deprecated, RefOr,
 };
 
+mod keywords;
+
 macro_rules! component_from_builder {
     ( $name:ident ) => {
         impl From<$name> for Component {
@@ -39,21 +62,63 @@ builder! {
     ///
     /// [components]: https://spec.openapis.org/oas/latest.html#components-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Components {
         /// Map of reusable [OpenAPI Schema Object][schema]s.
         ///
         /// [schema]: https://spec.openapis.org/oas/latest.html#schema-object
-        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
         pub schemas: HashMap<String, Component>,
 
         /// Map of reusable [OpenAPI Security Schema Object][security_schema]s.
         ///
         /// [security_schema]: https://spec.openapis.org/oas/latest.html#security-scheme-object
-        #[serde(skip_serializing_if = "HashMap::is_empty")]
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
         pub security_schemes: HashMap<String, SecurityScheme>,
+
+        /// Map of reusable [OpenAPI Example Object][example]s.
+        ///
+        /// [example]: https://spec.openapis.org/oas/latest.html#example-object
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub examples: HashMap<String, RefOr<Example>>,
+
+        /// Map of reusable [OpenAPI Response Object][response]s.
+        ///
+        /// [response]: https://spec.openapis.org/oas/latest.html#response-object
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub responses: HashMap<String, RefOr<Response>>,
+
+        /// Map of reusable [OpenAPI Parameter Object][parameter]s.
+        ///
+        /// [parameter]: https://spec.openapis.org/oas/latest.html#parameter-object
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub parameters: HashMap<String, RefOr<Parameter>>,
+
+        /// Map of reusable [OpenAPI Request Body Object][request_body]s.
+        ///
+        /// [request_body]: https://spec.openapis.org/oas/latest.html#request-body-object
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub request_bodies: HashMap<String, RefOr<RequestBody>>,
+
+        /// Map of reusable [OpenAPI Header Object][header]s.
+        ///
+        /// [header]: https://spec.openapis.org/oas/latest.html#header-object
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub headers: HashMap<String, RefOr<Header>>,
+
+        /// Map of reusable [OpenAPI Link Object][link]s.
+        ///
+        /// [link]: https://spec.openapis.org/oas/latest.html#link-object
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub links: HashMap<String, RefOr<Link>>,
+
+        /// Map of reusable [OpenAPI Callback Object][callback]s.
+        ///
+        /// [callback]: https://spec.openapis.org/oas/latest.html#callback-object
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub callbacks: HashMap<String, RefOr<Callback>>,
     }
 }
 
@@ -64,6 +129,43 @@ impl Components {
             ..Default::default()
         }
     }
+
+    /// Resolve [`Ref`] to reusable schema of [`Components::schemas`].
+    ///
+    /// Reference must be local reference to the schemas e.g. _`#/components/schemas/Pet`_.
+    /// If the referenced schema is also a [`Ref`] the references are followed until a schema
+    /// which is not a reference is found. Returns `None` if the reference does not resolve or
+    /// the references form a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use utoipa::openapi::{Component, ComponentsBuilder, ComponentType, Property, Ref};
+    /// let components = ComponentsBuilder::new()
+    ///     .component("Name", Property::new(ComponentType::String))
+    ///     .component("PetName", Ref::from_component_name("Name"))
+    ///     .build();
+    ///
+    /// let resolved = components.resolve(&Ref::from_component_name("PetName"));
+    /// assert!(matches!(resolved, Some(Component::Property(_))));
+    /// assert!(components.resolve(&Ref::from_component_name("Pet")).is_none());
+    /// ```
+    pub fn resolve(&self, reference: &Ref) -> Option<&Component> {
+        let mut visited = Vec::new();
+        let mut reference = reference;
+
+        loop {
+            if visited.contains(&&*reference.ref_location) {
+                return None;
+            }
+            visited.push(&*reference.ref_location);
+
+            match self.schemas.get(&reference.component_name("schemas")?)? {
+                Component::Ref(next) => reference = next,
+                component => return Some(component),
+            }
+        }
+    }
     /// Add [`SecurityScheme`] to [`Components`]
     ///
     /// Accepts two arguments where first is the name of the [`SecurityScheme`]. This is later when
@@ -102,6 +204,124 @@ impl Components {
 }
 
 impl ComponentsBuilder {
+    /// Add reusable [`Example`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`Example`] which is used to reference
+    /// it with [`Ref`] e.g. _`#/components/examples/MyExample`_. Second is the [`Example`] itself.
+    pub fn example<S: Into<String>, E: Into<RefOr<Example>>>(
+        mut self,
+        name: S,
+        example: E,
+    ) -> Self {
+        self.examples.insert(name.into(), example.into());
+
+        self
+    }
+
+    /// Add reusable [`Response`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`Response`] which is used to reference
+    /// it with [`Ref`] e.g. _`#/components/responses/NotFound`_. Second is the [`Response`] itself.
+    pub fn response<S: Into<String>, R: Into<RefOr<Response>>>(
+        mut self,
+        name: S,
+        response: R,
+    ) -> Self {
+        self.responses.insert(name.into(), response.into());
+
+        self
+    }
+
+    /// Add reusable [`Response`]s from iterator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use utoipa::openapi::{ComponentsBuilder, ResponseBuilder};
+    /// ComponentsBuilder::new().responses_from_iter([
+    ///     ("NotFound", ResponseBuilder::new().description("Resource not found")),
+    ///     ("Unauthorized", ResponseBuilder::new().description("Missing credentials")),
+    /// ]);
+    /// ```
+    pub fn responses_from_iter<
+        I: IntoIterator<Item = (S, R)>,
+        S: Into<String>,
+        R: Into<RefOr<Response>>,
+    >(
+        mut self,
+        responses: I,
+    ) -> Self {
+        self.responses.extend(
+            responses
+                .into_iter()
+                .map(|(name, response)| (name.into(), response.into())),
+        );
+
+        self
+    }
+
+    /// Add reusable [`Parameter`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`Parameter`] which is used to reference
+    /// it with [`Ref`] e.g. _`#/components/parameters/PageSize`_. Second is the [`Parameter`] itself.
+    pub fn parameter<S: Into<String>, P: Into<RefOr<Parameter>>>(
+        mut self,
+        name: S,
+        parameter: P,
+    ) -> Self {
+        self.parameters.insert(name.into(), parameter.into());
+
+        self
+    }
+
+    /// Add reusable [`RequestBody`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`RequestBody`] which is used to
+    /// reference it with [`Ref`] e.g. _`#/components/requestBodies/Pet`_. Second is the
+    /// [`RequestBody`] itself.
+    pub fn request_body<S: Into<String>, R: Into<RefOr<RequestBody>>>(
+        mut self,
+        name: S,
+        request_body: R,
+    ) -> Self {
+        self.request_bodies.insert(name.into(), request_body.into());
+
+        self
+    }
+
+    /// Add reusable [`Header`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`Header`] which is used to reference
+    /// it with [`Ref`] e.g. _`#/components/headers/X-Rate-Limit`_. Second is the [`Header`] itself.
+    pub fn header<S: Into<String>, H: Into<RefOr<Header>>>(mut self, name: S, header: H) -> Self {
+        self.headers.insert(name.into(), header.into());
+
+        self
+    }
+
+    /// Add reusable [`Link`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`Link`] which is used to reference
+    /// it with [`Ref`] e.g. _`#/components/links/GetUser`_. Second is the [`Link`] itself.
+    pub fn link<S: Into<String>, L: Into<RefOr<Link>>>(mut self, name: S, link: L) -> Self {
+        self.links.insert(name.into(), link.into());
+
+        self
+    }
+
+    /// Add reusable [`Callback`] to [`Components`].
+    ///
+    /// Accepts two arguments where first is the name of the [`Callback`] which is used to reference
+    /// it with [`Ref`] e.g. _`#/components/callbacks/OnEvent`_. Second is the [`Callback`] itself.
+    pub fn callback<S: Into<String>, C: Into<RefOr<Callback>>>(
+        mut self,
+        name: S,
+        callback: C,
+    ) -> Self {
+        self.callbacks.insert(name.into(), callback.into());
+
+        self
+    }
+
     /// Add [`Component`] to [`Components`].
     ///
     /// Accpets two arguments where first is name of the component and second is the component itself.
@@ -168,7 +388,7 @@ impl ComponentsBuilder {
 ///
 /// [components]: https://spec.openapis.org/oas/latest.html#components-object
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum Component {
@@ -192,6 +412,12 @@ pub enum Component {
     ///
     /// [discriminator]: https://spec.openapis.org/oas/latest.html#components-object
     OneOf(OneOf),
+    /// Creates an _AllOf_ type component. Value must be valid against all of the components
+    /// e.g. to combine [`Component::Ref`] to a base component with additional properties.
+    AllOf(AllOf),
+    /// Creates an _AnyOf_ type component. Value must be valid against at least one of the
+    /// components.
+    AnyOf(AnyOf),
 }
 
 impl Default for Component {
@@ -200,6 +426,20 @@ impl Default for Component {
     }
 }
 
+/// Components are deserialized by the keywords of the schema instead of trying each variant in
+/// order so that errors are reported at the invalid keyword. OpenAPI 3.1 schemas are translated
+/// back to OpenAPI 3.0 keywords e.g. _`"type": ["string", "null"]`_ to _`nullable`_.
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Keywords::deserialize(deserializer)?
+            .into_component()
+            .map_err(de::Error::custom)
+    }
+}
+
 builder! {
     OneOfBuilder;
 
@@ -209,15 +449,35 @@ builder! {
     /// See [`Component::OneOf`] for more details.
     ///
     /// [discriminator]: https://spec.openapis.org/oas/latest.html#components-object
-    #[derive(Serialize, Deserialize, Clone, Default)]
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct OneOf {
         /// Components of _OneOf_ component.
         #[serde(rename = "oneOf")]
         pub items: Vec<Component>,
 
+        /// Whether the value of the [`OneOf`] can be `null`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nullable: Option<bool>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+
+        /// Changes the [`OneOf`] deprecated status.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<Deprecated>,
+
+        /// Optional [`Discriminator`] telling which of the components the value is.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub discriminator: Option<Discriminator>,
+
+        /// Optional extensions `x-something` of the [`OneOf`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -244,7 +504,11 @@ impl OneOf {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             items: Vec::with_capacity(capacity),
+            nullable: None,
             description: None,
+            deprecated: None,
+            discriminator: None,
+            extensions: None,
         }
     }
 }
@@ -259,11 +523,31 @@ impl OneOfBuilder {
         self
     }
 
+    /// Add or change whether the value of the [`OneOf`] can be `null`.
+    pub fn nullable(mut self, nullable: Option<bool>) -> Self {
+        set_value!(self nullable nullable)
+    }
+
     /// Add or change optional description for `OneOf` component.
     pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
         set_value!(self description description.map(|description| description.into()))
     }
 
+    /// Add or change deprecated status for [`OneOf`].
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change optional [`Discriminator`] of the [`OneOf`].
+    pub fn discriminator(mut self, discriminator: Option<Discriminator>) -> Self {
+        set_value!(self discriminator discriminator)
+    }
+
+    /// Add or change optional extensions `x-something` of the [`OneOf`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -275,11 +559,228 @@ impl From<OneOf> for Component {
 
 component_from_builder!(OneOfBuilder);
 
+builder! {
+    AllOfBuilder;
+
+    /// AllOf component holds multiple components together where the value must be valid
+    /// against all of them.
+    ///
+    /// See [`Component::AllOf`] for more details.
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct AllOf {
+        /// Components of _AllOf_ component.
+        #[serde(rename = "allOf")]
+        pub items: Vec<Component>,
+
+        /// Whether the value of the [`AllOf`] can be `null`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nullable: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Changes the [`AllOf`] deprecated status.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<Deprecated>,
+
+        /// Optional [`Discriminator`] telling which of the components the value is.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub discriminator: Option<Discriminator>,
+
+        /// Optional extensions `x-something` of the [`AllOf`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl AllOf {
+    /// Construct a new [`AllOf`] component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AllOfBuilder {
+    /// Adds a given [`Component`] to [`AllOf`].
+    pub fn item<I: Into<Component>>(mut self, component: I) -> Self {
+        self.items.push(component.into());
+
+        self
+    }
+
+    /// Add or change whether the value of the [`AllOf`] can be `null`.
+    pub fn nullable(mut self, nullable: Option<bool>) -> Self {
+        set_value!(self nullable nullable)
+    }
+
+    /// Add or change optional description for `AllOf` component.
+    pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add or change deprecated status for [`AllOf`].
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change optional [`Discriminator`] of the [`AllOf`].
+    pub fn discriminator(mut self, discriminator: Option<Discriminator>) -> Self {
+        set_value!(self discriminator discriminator)
+    }
+
+    /// Add or change optional extensions `x-something` of the [`AllOf`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
+    to_array_builder!();
+}
+
+impl From<AllOf> for Component {
+    fn from(all_of: AllOf) -> Self {
+        Self::AllOf(all_of)
+    }
+}
+
+component_from_builder!(AllOfBuilder);
+
+builder! {
+    AnyOfBuilder;
+
+    /// AnyOf component holds multiple components together where the value must be valid
+    /// against at least one of them.
+    ///
+    /// See [`Component::AnyOf`] for more details.
+    #[non_exhaustive]
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct AnyOf {
+        /// Components of _AnyOf_ component.
+        #[serde(rename = "anyOf")]
+        pub items: Vec<Component>,
+
+        /// Whether the value of the [`AnyOf`] can be `null`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nullable: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Changes the [`AnyOf`] deprecated status.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<Deprecated>,
+
+        /// Optional [`Discriminator`] telling which of the components the value is.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub discriminator: Option<Discriminator>,
+
+        /// Optional extensions `x-something` of the [`AnyOf`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
+    }
+}
+
+impl AnyOf {
+    /// Construct a new [`AnyOf`] component.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AnyOfBuilder {
+    /// Adds a given [`Component`] to [`AnyOf`].
+    pub fn item<I: Into<Component>>(mut self, component: I) -> Self {
+        self.items.push(component.into());
+
+        self
+    }
+
+    /// Add or change whether the value of the [`AnyOf`] can be `null`.
+    pub fn nullable(mut self, nullable: Option<bool>) -> Self {
+        set_value!(self nullable nullable)
+    }
+
+    /// Add or change optional description for `AnyOf` component.
+    pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
+        set_value!(self description description.map(|description| description.into()))
+    }
+
+    /// Add or change deprecated status for [`AnyOf`].
+    pub fn deprecated(mut self, deprecated: Option<Deprecated>) -> Self {
+        set_value!(self deprecated deprecated)
+    }
+
+    /// Add or change optional [`Discriminator`] of the [`AnyOf`].
+    pub fn discriminator(mut self, discriminator: Option<Discriminator>) -> Self {
+        set_value!(self discriminator discriminator)
+    }
+
+    /// Add or change optional extensions `x-something` of the [`AnyOf`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
+    to_array_builder!();
+}
+
+impl From<AnyOf> for Component {
+    fn from(any_of: AnyOf) -> Self {
+        Self::AnyOf(any_of)
+    }
+}
+
+component_from_builder!(AnyOfBuilder);
+
+/// [Discriminator Object][discriminator] telling which of the components of [`OneOf`], [`AllOf`]
+/// or [`AnyOf`] the value is by the value of the given property.
+///
+/// [discriminator]: https://spec.openapis.org/oas/latest.html#discriminator-object
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct Discriminator {
+    /// Name of the property holding the discriminator value.
+    pub property_name: String,
+
+    /// Map of discriminator values with references to the components of the values.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub mapping: BTreeMap<String, String>,
+}
+
+impl Discriminator {
+    /// Construct a new [`Discriminator`] by the name of the property holding the discriminator
+    /// value.
+    pub fn new<I: Into<String>>(property_name: I) -> Self {
+        Self {
+            property_name: property_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add mapping of discriminator `value` to the `reference` of the component of the value.
+    pub fn mapping<V: Into<String>, R: Into<String>>(mut self, value: V, reference: R) -> Self {
+        self.mapping.insert(value.into(), reference.into());
+
+        self
+    }
+}
+
 /// Implements special subset of [OpenAPI Schema Object][schema] which can be
 /// used to define field property or enum values or type for array items.
 ///
 /// [schema]: https://spec.openapis.org/oas/latest.html#schema-object
-#[derive(Default, Serialize, Deserialize, Clone)]
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(rename_all = "camelCase")]
 pub struct Property {
@@ -291,6 +792,11 @@ pub struct Property {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<ComponentFormat>,
 
+    /// Whether the value of the property can be `null`. With OpenAPI 3.1 this is serialized as
+    /// _`null`_ type of the property.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nullable: Option<bool>,
+
     /// Description of the property. Markdown syntax is supported.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -307,8 +813,24 @@ pub struct Property {
 
     /// Enum type property possible variants.
     #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub enum_values: Option<Vec<Value>>,
+
+    /// Enum type property possible variants.
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
     pub enum_values: Option<Vec<String>>,
 
+    /// The only allowed value of the property. This is only serialized with OpenAPI 3.1.
+    #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+    #[cfg(feature = "serde_json")]
+    pub const_value: Option<Value>,
+
+    /// The only allowed value of the property. This is only serialized with OpenAPI 3.1.
+    #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "serde_json"))]
+    pub const_value: Option<String>,
+
     /// Example shown in UI of the value for richier documentation.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg(not(feature = "serde_json"))]
@@ -334,6 +856,42 @@ pub struct Property {
     /// Additional [`Xml`] formatting of the [`Property`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xml: Option<Xml>,
+
+    /// Inclusive upper limit of numeric value of the [`Property`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+
+    /// Inclusive lower limit of numeric value of the [`Property`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+
+    /// If `true` the [`Property::maximum`] is exclusive upper limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_maximum: Option<bool>,
+
+    /// If `true` the [`Property::minimum`] is exclusive lower limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_minimum: Option<bool>,
+
+    /// Maximum length of string value of the [`Property`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Minimum length of string value of the [`Property`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// ECMA-262 regular expression string value of the [`Property`] must match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Optional extensions `x-something` of the [`Property`].
+    #[serde(
+        flatten,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl Property {
@@ -360,6 +918,8 @@ pub struct PropertyBuilder {
 
     format: Option<ComponentFormat>,
 
+    nullable: Option<bool>,
+
     description: Option<String>,
 
     #[cfg(feature = "serde_json")]
@@ -368,8 +928,18 @@ pub struct PropertyBuilder {
     #[cfg(not(feature = "serde_json"))]
     default: Option<String>,
 
+    #[cfg(feature = "serde_json")]
+    enum_values: Option<Vec<Value>>,
+
+    #[cfg(not(feature = "serde_json"))]
     enum_values: Option<Vec<String>>,
 
+    #[cfg(feature = "serde_json")]
+    const_value: Option<Value>,
+
+    #[cfg(not(feature = "serde_json"))]
+    const_value: Option<String>,
+
     #[cfg(not(feature = "serde_json"))]
     example: Option<String>,
 
@@ -383,10 +953,27 @@ pub struct PropertyBuilder {
     read_only: Option<bool>,
 
     xml: Option<Xml>,
+
+    maximum: Option<f64>,
+
+    minimum: Option<f64>,
+
+    exclusive_maximum: Option<bool>,
+
+    exclusive_minimum: Option<bool>,
+
+    max_length: Option<usize>,
+
+    min_length: Option<usize>,
+
+    pattern: Option<String>,
+
+    extensions: Option<Extensions>,
 }
 
 from!(Property PropertyBuilder
-    component_type, format, description, default, enum_values, example, deprecated, write_only, read_only, xml);
+    component_type, format, nullable, description, default, enum_values, const_value, example, deprecated, write_only, read_only, xml,
+    maximum, minimum, exclusive_maximum, exclusive_minimum, max_length, min_length, pattern, extensions);
 
 impl PropertyBuilder {
     new!(pub PropertyBuilder);
@@ -401,6 +988,11 @@ impl PropertyBuilder {
         set_value!(self format format)
     }
 
+    /// Add or change whether the value of the property can be `null`.
+    pub fn nullable(mut self, nullable: Option<bool>) -> Self {
+        set_value!(self nullable nullable)
+    }
+
     /// Add or change description of the property. Markdown syntax is supported.
     pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
         set_value!(self description description.map(|description| description.into()))
@@ -419,6 +1011,17 @@ impl PropertyBuilder {
     }
 
     /// Add or change enum property variants.
+    #[cfg(feature = "serde_json")]
+    pub fn enum_values<I: IntoIterator<Item = E>, E: Into<Value>>(
+        mut self,
+        enum_values: Option<I>,
+    ) -> Self {
+        set_value!(self enum_values
+            enum_values.map(|values| values.into_iter().map(|enum_value| enum_value.into()).collect()))
+    }
+
+    /// Add or change enum property variants.
+    #[cfg(not(feature = "serde_json"))]
     pub fn enum_values<I: IntoIterator<Item = E>, E: Into<String>>(
         mut self,
         enum_values: Option<I>,
@@ -427,6 +1030,20 @@ impl PropertyBuilder {
             enum_values.map(|values| values.into_iter().map(|enum_value| enum_value.into()).collect()))
     }
 
+    /// Add or change the only allowed value of the property. This is only serialized with
+    /// OpenAPI 3.1.
+    #[cfg(feature = "serde_json")]
+    pub fn const_value(mut self, const_value: Option<Value>) -> Self {
+        set_value!(self const_value const_value)
+    }
+
+    /// Add or change the only allowed value of the property. This is only serialized with
+    /// OpenAPI 3.1.
+    #[cfg(not(feature = "serde_json"))]
+    pub fn const_value<I: Into<String>>(mut self, const_value: Option<I>) -> Self {
+        set_value!(self const_value const_value.map(|const_value| const_value.into()))
+    }
+
     /// Add or change example shown in UI of the value for richier documentation.
     #[cfg(not(feature = "serde_json"))]
     pub fn example<I: Into<String>>(mut self, example: Option<I>) -> Self {
@@ -459,10 +1076,51 @@ impl PropertyBuilder {
         set_value!(self xml xml)
     }
 
+    /// Add or change inclusive upper limit of numeric value of the [`Property`].
+    pub fn maximum(mut self, maximum: Option<f64>) -> Self {
+        set_value!(self maximum maximum)
+    }
+
+    /// Add or change inclusive lower limit of numeric value of the [`Property`].
+    pub fn minimum(mut self, minimum: Option<f64>) -> Self {
+        set_value!(self minimum minimum)
+    }
+
+    /// Add or change whether [`Property`] maximum is exclusive upper limit.
+    pub fn exclusive_maximum(mut self, exclusive_maximum: Option<bool>) -> Self {
+        set_value!(self exclusive_maximum exclusive_maximum)
+    }
+
+    /// Add or change whether [`Property`] minimum is exclusive lower limit.
+    pub fn exclusive_minimum(mut self, exclusive_minimum: Option<bool>) -> Self {
+        set_value!(self exclusive_minimum exclusive_minimum)
+    }
+
+    /// Add or change maximum length of string value of the [`Property`].
+    pub fn max_length(mut self, max_length: Option<usize>) -> Self {
+        set_value!(self max_length max_length)
+    }
+
+    /// Add or change minimum length of string value of the [`Property`].
+    pub fn min_length(mut self, min_length: Option<usize>) -> Self {
+        set_value!(self min_length min_length)
+    }
+
+    /// Add or change regular expression pattern string value of the [`Property`] must match.
+    pub fn pattern<I: Into<String>>(mut self, pattern: Option<I>) -> Self {
+        set_value!(self pattern pattern.map(|pattern| pattern.into()))
+    }
+
+    /// Add or change optional extensions `x-something` of the [`Property`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 
     build_fn!(pub Property
-        component_type, format, description, default, enum_values, example, deprecated, write_only, read_only, xml);
+        component_type, format, nullable, description, default, enum_values, const_value, example, deprecated, write_only, read_only, xml,
+        maximum, minimum, exclusive_maximum, exclusive_minimum, max_length, min_length, pattern, extensions);
 }
 
 component_from_builder!(PropertyBuilder);
@@ -472,7 +1130,7 @@ component_from_builder!(PropertyBuilder);
 ///
 /// [schema]: https://spec.openapis.org/oas/latest.html#schema-object
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(rename_all = "camelCase")]
 pub struct Object {
@@ -481,13 +1139,18 @@ pub struct Object {
     component_type: ComponentType,
 
     /// Vector of required field names.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub required: Vec<String>,
 
     /// Map of fields with their [`Component`] types.
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     pub properties: HashMap<String, Component>,
 
+    /// Whether the value of the [`Object`] can be `null`. With OpenAPI 3.1 this is serialized as
+    /// _`null`_ type of the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nullable: Option<bool>,
+
     /// Description of the [`Object`]. Markdown syntax is supported.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -509,6 +1172,29 @@ pub struct Object {
     /// Additional [`Xml`] formatting of the [`Object`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub xml: Option<Xml>,
+
+    /// [`Component`] of the values of additional properties e.g. values of a map or whether
+    /// additional properties are allowed at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<Box<AdditionalProperties>>,
+
+    /// Optional [`Discriminator`] telling which of the components extending this [`Object`]
+    /// the value is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discriminator: Option<Discriminator>,
+
+    /// Map of schema definitions local to the [`Object`] serialized as _`$defs`_. This is only
+    /// serialized with OpenAPI 3.1.
+    #[serde(rename = "$defs", skip_serializing_if = "HashMap::is_empty", default)]
+    pub defs: HashMap<String, Component>,
+
+    /// Optional extensions `x-something` of the [`Object`].
+    #[serde(
+        flatten,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl Object {
@@ -536,6 +1222,8 @@ pub struct ObjectBuilder {
 
     properties: HashMap<String, Component>,
 
+    nullable: Option<bool>,
+
     description: Option<String>,
 
     deprecated: Option<Deprecated>,
@@ -547,6 +1235,14 @@ pub struct ObjectBuilder {
     example: Option<String>,
 
     xml: Option<Xml>,
+
+    additional_properties: Option<Box<AdditionalProperties>>,
+
+    discriminator: Option<Discriminator>,
+
+    defs: HashMap<String, Component>,
+
+    extensions: Option<Extensions>,
 }
 
 impl ObjectBuilder {
@@ -573,6 +1269,11 @@ impl ObjectBuilder {
         self
     }
 
+    /// Add or change whether the value of the [`Object`] can be `null`.
+    pub fn nullable(mut self, nullable: Option<bool>) -> Self {
+        set_value!(self nullable nullable)
+    }
+
     /// Add or change description of the property. Markdown syntax is supported.
     pub fn description<I: Into<String>>(mut self, description: Option<I>) -> Self {
         set_value!(self description description.map(|description| description.into()))
@@ -600,20 +1301,103 @@ impl ObjectBuilder {
         set_value!(self xml xml)
     }
 
+    /// Add or change [`Component`] of the additional properties of the [`Object`]. This is used
+    /// to define type of the values of a map. Use `bool` to only allow or forbid additional
+    /// properties.
+    pub fn additional_properties<I: Into<AdditionalProperties>>(
+        mut self,
+        additional_properties: Option<I>,
+    ) -> Self {
+        set_value!(self additional_properties additional_properties.map(|additional_properties| Box::new(additional_properties.into())))
+    }
+
+    /// Add or change optional [`Discriminator`] of the [`Object`].
+    pub fn discriminator(mut self, discriminator: Option<Discriminator>) -> Self {
+        set_value!(self discriminator discriminator)
+    }
+
+    /// Add new schema definition local to the [`Object`]. Definitions are only serialized with
+    /// OpenAPI 3.1.
+    ///
+    /// Method accepts definition name and definition component as an arguments.
+    pub fn def<S: Into<String>, I: Into<Component>>(mut self, name: S, component: I) -> Self {
+        self.defs.insert(name.into(), component.into());
+
+        self
+    }
+
     to_array_builder!();
 
-    build_fn!(pub Object component_type, required, properties, description, deprecated, example, xml);
+    build_fn!(pub Object component_type, required, properties, nullable, description, deprecated, example, xml, additional_properties, discriminator, defs, extensions);
+
+    /// Add or change optional extensions `x-something` of the [`Object`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
-from!(Object ObjectBuilder component_type, required, properties, description, deprecated, example, xml);
+from!(Object ObjectBuilder component_type, required, properties, nullable, description, deprecated, example, xml, additional_properties, discriminator, defs, extensions);
 component_from_builder!(ObjectBuilder);
 
+/// Value of _`additionalProperties`_ keyword of [`Object`]. This is either [`Component`] of the
+/// values of additional properties or `bool` telling whether additional properties of any value
+/// are allowed.
+#[derive(Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[serde(untagged)]
+pub enum AdditionalProperties {
+    /// Additional properties of any value are allowed with `true` and forbidden with `false`.
+    FreeForm(bool),
+    /// Values of additional properties must be valid against the [`Component`].
+    Component(Component),
+}
+
+impl<'de> Deserialize<'de> for AdditionalProperties {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AdditionalPropertiesVisitor;
+
+        impl<'de> Visitor<'de> for AdditionalPropertiesVisitor {
+            type Value = AdditionalProperties;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a bool or schema object")
+            }
+
+            fn visit_bool<E: de::Error>(self, free_form: bool) -> Result<Self::Value, E> {
+                Ok(AdditionalProperties::FreeForm(free_form))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                Component::deserialize(MapAccessDeserializer::new(map))
+                    .map(AdditionalProperties::Component)
+            }
+        }
+
+        deserializer.deserialize_any(AdditionalPropertiesVisitor)
+    }
+}
+
+impl From<bool> for AdditionalProperties {
+    fn from(free_form: bool) -> Self {
+        Self::FreeForm(free_form)
+    }
+}
+
+impl<T: Into<Component>> From<T> for AdditionalProperties {
+    fn from(component: T) -> Self {
+        Self::Component(component.into())
+    }
+}
+
 /// Implements [OpenAPI Reference Object][reference] that can be used to reference
 /// reusable components.
 ///
 /// [reference]: https://spec.openapis.org/oas/latest.html#reference-object
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Ref {
     /// Reference location of the actual component.
@@ -636,6 +1420,22 @@ impl Ref {
         Self::new(&format!("#/components/schemas/{}", component_name.into()))
     }
 
+    /// Get name of the referenced component if this is a local reference to the components of
+    /// given `kind` e.g. _`Pet`_ of _`#/components/schemas/Pet`_ with kind _`schemas`_.
+    pub(crate) fn component_name(&self, kind: &str) -> Option<String> {
+        let name = self
+            .ref_location
+            .strip_prefix("#/components/")?
+            .strip_prefix(kind)?
+            .strip_prefix('/')?;
+
+        if name.contains('/') {
+            None
+        } else {
+            Some(name.replace("~1", "/").replace("~0", "~"))
+        }
+    }
+
     to_array_builder!();
 }
 
@@ -654,7 +1454,7 @@ builder! {
     ///
     /// See [`Component::Array`] for more details.
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Array {
@@ -665,6 +1465,11 @@ builder! {
         /// Component representing the array items type.
         pub items: Box<Component>,
 
+        /// Whether the value of the [`Array`] can be `null`. With OpenAPI 3.1 this is serialized
+        /// as _`null`_ type of the array.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub nullable: Option<bool>,
+
         /// Max length of the array.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub max_items: Option<usize>,
@@ -673,9 +1478,21 @@ builder! {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub min_items: Option<usize>,
 
+        /// Whether the items of the array must be unique.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unique_items: Option<bool>,
+
         /// Xml format of the array.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub xml: Option<Xml>,
+
+        /// Optional extensions `x-something` of the [`Array`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -709,6 +1526,11 @@ impl ArrayBuilder {
         set_value!(self items Box::new(component.into()))
     }
 
+    /// Set whether the value of the [`Array`] can be `null`.
+    pub fn nullable(mut self, nullable: Option<bool>) -> Self {
+        set_value!(self nullable nullable)
+    }
+
     /// Set maximun allowed lenght for [`Array`].
     pub fn max_items(mut self, max_items: Option<usize>) -> Self {
         set_value!(self max_items max_items)
@@ -719,11 +1541,21 @@ impl ArrayBuilder {
         set_value!(self min_items min_items)
     }
 
+    /// Set whether the items of the [`Array`] must be unique.
+    pub fn unique_items(mut self, unique_items: Option<bool>) -> Self {
+        set_value!(self unique_items unique_items)
+    }
+
     /// Set [`Xml`] formatting for [`Array`].
     pub fn xml(mut self, xml: Option<Xml>) -> Self {
         set_value!(self xml xml)
     }
 
+    /// Add or change optional extensions `x-something` of the [`Array`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
+
     to_array_builder!();
 }
 
@@ -748,7 +1580,7 @@ where
 }
 
 /// Represents data type of [`Component`].
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[serde(rename_all = "lowercase")]
 pub enum ComponentType {
@@ -774,11 +1606,35 @@ impl Default for ComponentType {
     }
 }
 
+impl ComponentType {
+    /// Lowercase name of the type as it is serialized to the OpenAPI document.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Object => "object",
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Array => "array",
+        }
+    }
+}
+
 /// Additional format for [`ComponentType`] to fine tune the data type used. If the **format** is not
 /// supported by the UI it may default back to [`ComponentType`] alone.
-#[derive(Serialize, Deserialize, Clone)]
+///
+/// Format is an open value in OpenAPI. Formats not listed here can be used with
+/// [`ComponentFormat::Custom`] or by converting any `&str` or [`String`] to [`ComponentFormat`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::openapi::ComponentFormat;
+/// assert!(ComponentFormat::from("email") == ComponentFormat::Email);
+/// assert!(ComponentFormat::from("decimal") == ComponentFormat::Custom("decimal".to_string()));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[serde(rename_all = "lowercase")]
 pub enum ComponentFormat {
     /// 32 bit integer.
     Int32,
@@ -795,8 +1651,11 @@ pub enum ComponentFormat {
     /// ISO-8601 full date [FRC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14).
     Date,
     /// ISO-8601 full date time [FRC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14).
-    #[serde(rename = "date-time")]
     DateTime,
+    /// ISO-8601 full time [FRC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#anchor14).
+    Time,
+    /// ISO-8601 duration [FRC3339](https://xml2rfc.ietf.org/public/rfc/html/rfc3339.html#appendix-A).
+    Duration,
     /// Hint to UI to obsucre input.
     Password,
     /// Used with [`String`] values to indicate value is in UUID format.
@@ -804,6 +1663,134 @@ pub enum ComponentFormat {
     /// **uuid** feature need to be enabled.
     #[cfg(feature = "uuid")]
     Uuid,
+    /// Internet email address [RFC5321](https://www.rfc-editor.org/rfc/rfc5321#section-4.1.2).
+    Email,
+    /// Internationalized email address [RFC6531](https://www.rfc-editor.org/rfc/rfc6531).
+    IdnEmail,
+    /// Internet host name [RFC1123](https://www.rfc-editor.org/rfc/rfc1123#section-2.1).
+    Hostname,
+    /// Internationalized internet host name [RFC5890](https://www.rfc-editor.org/rfc/rfc5890#section-2.3.2.3).
+    IdnHostname,
+    /// IPv4 address [RFC2673](https://www.rfc-editor.org/rfc/rfc2673#section-3.2).
+    Ipv4,
+    /// IPv6 address [RFC4291](https://www.rfc-editor.org/rfc/rfc4291#section-2.2).
+    Ipv6,
+    /// Universal resource identifier [RFC3986](https://www.rfc-editor.org/rfc/rfc3986).
+    Uri,
+    /// Universal resource identifier reference [RFC3986](https://www.rfc-editor.org/rfc/rfc3986#section-4.1).
+    UriReference,
+    /// Internationalized resource identifier [RFC3987](https://www.rfc-editor.org/rfc/rfc3987).
+    Iri,
+    /// Internationalized resource identifier reference [RFC3987](https://www.rfc-editor.org/rfc/rfc3987).
+    IriReference,
+    /// URI template [RFC6570](https://www.rfc-editor.org/rfc/rfc6570).
+    UriTemplate,
+    /// JSON pointer [RFC6901](https://www.rfc-editor.org/rfc/rfc6901).
+    JsonPointer,
+    /// Relative JSON pointer.
+    RelativeJsonPointer,
+    /// Regular expression as defined in ECMA 262.
+    Regex,
+    /// Any other format not known by utoipa. The value is serialized as is.
+    Custom(String),
+}
+
+impl ComponentFormat {
+    /// Get the format as it is serialized to the OpenAPI document.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Int32 => "int32",
+            Self::Int64 => "int64",
+            Self::Float => "float",
+            Self::Double => "double",
+            Self::Byte => "byte",
+            Self::Binary => "binary",
+            Self::Date => "date",
+            Self::DateTime => "date-time",
+            Self::Time => "time",
+            Self::Duration => "duration",
+            Self::Password => "password",
+            #[cfg(feature = "uuid")]
+            Self::Uuid => "uuid",
+            Self::Email => "email",
+            Self::IdnEmail => "idn-email",
+            Self::Hostname => "hostname",
+            Self::IdnHostname => "idn-hostname",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+            Self::Uri => "uri",
+            Self::UriReference => "uri-reference",
+            Self::Iri => "iri",
+            Self::IriReference => "iri-reference",
+            Self::UriTemplate => "uri-template",
+            Self::JsonPointer => "json-pointer",
+            Self::RelativeJsonPointer => "relative-json-pointer",
+            Self::Regex => "regex",
+            Self::Custom(format) => format,
+        }
+    }
+}
+
+impl From<&str> for ComponentFormat {
+    fn from(format: &str) -> Self {
+        match format {
+            "int32" => Self::Int32,
+            "int64" => Self::Int64,
+            "float" => Self::Float,
+            "double" => Self::Double,
+            "byte" => Self::Byte,
+            "binary" => Self::Binary,
+            "date" => Self::Date,
+            "date-time" => Self::DateTime,
+            "time" => Self::Time,
+            "duration" => Self::Duration,
+            "password" => Self::Password,
+            #[cfg(feature = "uuid")]
+            "uuid" => Self::Uuid,
+            "email" => Self::Email,
+            "idn-email" => Self::IdnEmail,
+            "hostname" => Self::Hostname,
+            "idn-hostname" => Self::IdnHostname,
+            "ipv4" => Self::Ipv4,
+            "ipv6" => Self::Ipv6,
+            "uri" => Self::Uri,
+            "uri-reference" => Self::UriReference,
+            "iri" => Self::Iri,
+            "iri-reference" => Self::IriReference,
+            "uri-template" => Self::UriTemplate,
+            "json-pointer" => Self::JsonPointer,
+            "relative-json-pointer" => Self::RelativeJsonPointer,
+            "regex" => Self::Regex,
+            custom => Self::Custom(custom.to_string()),
+        }
+    }
+}
+
+impl From<String> for ComponentFormat {
+    fn from(format: String) -> Self {
+        match Self::from(format.as_str()) {
+            Self::Custom(_) => Self::Custom(format),
+            known => known,
+        }
+    }
+}
+
+impl Serialize for ComponentFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ComponentFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
 }
 
 #[cfg(test)]
@@ -959,9 +1946,213 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_and_deserialize_component_format() {
+        let property = PropertyBuilder::new()
+            .component_type(ComponentType::String)
+            .format(Some(ComponentFormat::from("decimal")))
+            .build();
+        assert_eq!(
+            serde_json::to_string(&property).unwrap(),
+            r#"{"type":"string","format":"decimal"}"#
+        );
+
+        let formats: Vec<ComponentFormat> =
+            serde_json::from_value(json!(["date-time", "email", "uri-reference", "decimal"]))
+                .unwrap();
+        assert!(
+            formats
+                == vec![
+                    ComponentFormat::DateTime,
+                    ComponentFormat::Email,
+                    ComponentFormat::UriReference,
+                    ComponentFormat::Custom("decimal".to_string()),
+                ],
+            "formats did not deserialize to expected variants"
+        );
+    }
+
+    #[test]
+    fn serialize_components_with_reusable_objects() {
+        let operation = path::OperationBuilder::new()
+            .parameter(Ref::new("#/components/parameters/PageSize"))
+            .response("200", ResponseBuilder::new().description("Pets found"))
+            .response("404", Ref::new("#/components/responses/NotFound"))
+            .build();
+
+        let components = ComponentsBuilder::new()
+            .response(
+                "NotFound",
+                ResponseBuilder::new().description("Resource not found"),
+            )
+            .parameter(
+                "PageSize",
+                path::ParameterBuilder::new()
+                    .name("page_size")
+                    .parameter_in(path::ParameterIn::Query)
+                    .schema(Some(
+                        PropertyBuilder::new().component_type(ComponentType::Integer),
+                    )),
+            )
+            .request_body(
+                "Pet",
+                request_body::RequestBodyBuilder::new().content(
+                    "application/json",
+                    Content::new(Ref::from_component_name("Pet")),
+                ),
+            )
+            .header("X-Rate-Limit", Header::default())
+            .link(
+                "GetPet",
+                LinkBuilder::new()
+                    .operation_id(Some("get_pet"))
+                    .parameter("id", "$response.body#/id"),
+            )
+            .callback(
+                "OnPetCreated",
+                CallbackBuilder::new().expression(
+                    "{$request.body#/callbackUrl}",
+                    PathItem::new(
+                        PathItemType::Post,
+                        path::OperationBuilder::new()
+                            .response("200", ResponseBuilder::new().description("Received")),
+                    ),
+                ),
+            )
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(operation).unwrap(),
+            json!({
+                "parameters": [
+                    {
+                        "$ref": "#/components/parameters/PageSize"
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "Pets found"
+                    },
+                    "404": {
+                        "$ref": "#/components/responses/NotFound"
+                    }
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(components).unwrap(),
+            json!({
+                "responses": {
+                    "NotFound": {
+                        "description": "Resource not found"
+                    }
+                },
+                "parameters": {
+                    "PageSize": {
+                        "name": "page_size",
+                        "in": "query",
+                        "required": false,
+                        "schema": {
+                            "type": "integer"
+                        }
+                    }
+                },
+                "requestBodies": {
+                    "Pet": {
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "$ref": "#/components/schemas/Pet"
+                                }
+                            }
+                        }
+                    }
+                },
+                "headers": {
+                    "X-Rate-Limit": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                },
+                "links": {
+                    "GetPet": {
+                        "operationId": "get_pet",
+                        "parameters": {
+                            "id": "$response.body#/id"
+                        }
+                    }
+                },
+                "callbacks": {
+                    "OnPetCreated": {
+                        "{$request.body#/callbackUrl}": {
+                            "post": {
+                                "responses": {
+                                    "200": {
+                                        "description": "Received"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
     fn get_json_path<'a>(value: &'a Value, path: &str) -> &'a Value {
-        path.split('.').into_iter().fold(value, |acc, fragment| {
+        path.split('.').fold(value, |acc, fragment| {
             acc.get(fragment).unwrap_or(&serde_json::value::Value::Null)
         })
     }
+
+    #[test]
+    fn serialize_deserialize_schemas_with_extensions() -> Result<(), serde_json::Error> {
+        let extensions = ExtensionsBuilder::new().add("x-go-type", "Pet").build();
+        let object = ObjectBuilder::new()
+            .description(Some("Pet object"))
+            .extensions(Some(extensions.clone()))
+            .build();
+        let array = Array::new(PropertyBuilder::new().component_type(ComponentType::String))
+            .to_builder()
+            .extensions(Some(extensions))
+            .build();
+
+        let object_value = serde_json::to_value(&object)?;
+        assert_eq!(object_value["x-go-type"], "Pet");
+        let deserialized = serde_json::from_value::<Object>(object_value.clone())?;
+        assert_eq!(serde_json::to_value(&deserialized)?, object_value);
+
+        let array_value = serde_json::to_value(&array)?;
+        assert_eq!(array_value["x-go-type"], "Pet");
+        let deserialized = serde_json::from_value::<Array>(array_value.clone())?;
+        assert_eq!(serde_json::to_value(&deserialized)?, array_value);
+        Ok(())
+    }
+
+    #[test]
+    fn components_resolve_reference_chain() {
+        let components = ComponentsBuilder::new()
+            .component("Pet", ObjectBuilder::new())
+            .component("Animal", Ref::from_component_name("Pet"))
+            .component("Ping", Ref::from_component_name("Pong"))
+            .component("Pong", Ref::from_component_name("Ping"))
+            .component("Escaped/Name", Property::new(ComponentType::String))
+            .build();
+
+        assert!(matches!(
+            components.resolve(&Ref::from_component_name("Animal")),
+            Some(Component::Object(_))
+        ));
+        assert!(matches!(
+            components.resolve(&Ref::new("#/components/schemas/Escaped~1Name")),
+            Some(Component::Property(_))
+        ));
+        assert!(components
+            .resolve(&Ref::from_component_name("Ping"))
+            .is_none());
+        assert!(components
+            .resolve(&Ref::new("#/components/responses/Pet"))
+            .is_none());
+    }
 }
@@ -0,0 +1,407 @@
+//! Implements deserialization of [`Component`]s.
+//!
+//! Schemas are first deserialized to [`Keywords`] holding all the keywords of all the
+//! [`Component`] variants. The variant is then chosen by the keywords present in the schema. This
+//! way errors of invalid keywords are reported at the keyword instead of an error of not matching
+//! any of the variants.
+use std::{collections::HashMap, fmt};
+
+use serde::{
+    de::{self, IntoDeserializer, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+use super::{
+    AdditionalProperties, AllOf, AnyOf, Array, Component, ComponentFormat, ComponentType,
+    Discriminator, Object, OneOf, Property, Ref,
+};
+use crate::openapi::{
+    extensions::{self, Extensions},
+    xml::Xml,
+    Deprecated,
+};
+
+/// Value of keywords such as _`default`_ and _`example`_. This is [`serde_json::Value`] if
+/// **json** feature is enabled otherwise it is plain [`String`].
+#[cfg(feature = "serde_json")]
+type AnyValue = Value;
+
+/// Value of keywords such as _`default`_ and _`example`_. This is [`serde_json::Value`] if
+/// **json** feature is enabled otherwise it is plain [`String`].
+#[cfg(not(feature = "serde_json"))]
+type AnyValue = String;
+
+/// Keywords of all [`Component`] variants. Both OpenAPI 3.0 and OpenAPI 3.1 forms of the keywords
+/// are accepted and OpenAPI 3.1 forms are translated to OpenAPI 3.0 forms with
+/// [`Keywords::into_component`].
+#[derive(Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase", expecting = "schema object")]
+pub(super) struct Keywords {
+    #[serde(rename = "$ref")]
+    reference: Option<String>,
+    #[serde(rename = "type")]
+    types: Option<Types>,
+    format: Option<ComponentFormat>,
+    nullable: Option<bool>,
+    description: Option<String>,
+    default: Option<AnyValue>,
+    #[serde(rename = "enum")]
+    enum_values: Option<Vec<AnyValue>>,
+    #[serde(rename = "const")]
+    const_value: Option<AnyValue>,
+    example: Option<AnyValue>,
+    examples: Option<Vec<AnyValue>>,
+    deprecated: Option<Deprecated>,
+    write_only: Option<bool>,
+    read_only: Option<bool>,
+    xml: Option<Xml>,
+    maximum: Option<f64>,
+    minimum: Option<f64>,
+    exclusive_maximum: Option<ExclusiveLimit>,
+    exclusive_minimum: Option<ExclusiveLimit>,
+    max_length: Option<usize>,
+    min_length: Option<usize>,
+    pattern: Option<String>,
+    #[serde(default)]
+    required: Vec<String>,
+    properties: Option<HashMap<String, Component>>,
+    additional_properties: Option<Box<AdditionalProperties>>,
+    #[serde(rename = "$defs", default)]
+    defs: HashMap<String, Component>,
+    items: Option<Box<Component>>,
+    max_items: Option<usize>,
+    min_items: Option<usize>,
+    unique_items: Option<bool>,
+    one_of: Option<Vec<Component>>,
+    all_of: Option<Vec<Component>>,
+    any_of: Option<Vec<Keywords>>,
+    discriminator: Option<Discriminator>,
+    #[serde(flatten, deserialize_with = "extensions::deserialize_optional")]
+    extensions: Option<Extensions>,
+}
+
+impl Keywords {
+    /// Convert the keywords to the [`Component`] variant chosen by the keywords present.
+    pub(super) fn into_component(mut self) -> Result<Component, String> {
+        let any_of = self.any_of.take();
+        if let Some(any_of) = any_of {
+            return self.into_any_of(any_of);
+        }
+
+        let (component_type, is_nullable_type) = match self.types.take() {
+            Some(types) => types.into_openapi_30()?,
+            None => (None, false),
+        };
+        if is_nullable_type {
+            self.nullable = Some(true);
+        }
+        if let Some(examples) = self.examples.take() {
+            self.example = Some(single_example(examples, self.example.is_some())?);
+        }
+
+        if let Some(reference) = self.reference {
+            Ok(Ref::new(reference).into())
+        } else if let Some(items) = self.one_of {
+            Ok(OneOf {
+                items,
+                nullable: self.nullable,
+                description: self.description,
+                deprecated: self.deprecated,
+                discriminator: self.discriminator,
+                extensions: self.extensions,
+            }
+            .into())
+        } else if let Some(items) = self.all_of {
+            Ok(AllOf {
+                items,
+                nullable: self.nullable,
+                description: self.description,
+                deprecated: self.deprecated,
+                discriminator: self.discriminator,
+                extensions: self.extensions,
+            }
+            .into())
+        } else if component_type == Some(ComponentType::Array)
+            || (component_type.is_none() && self.items.is_some())
+        {
+            self.into_array()
+        } else if component_type == Some(ComponentType::Object)
+            || (component_type.is_none()
+                && (self.properties.is_some() || self.additional_properties.is_some()))
+        {
+            Ok(self.into_object())
+        } else {
+            match component_type {
+                Some(component_type) => self.into_property(component_type),
+                None => Err("missing field `type`".to_string()),
+            }
+        }
+    }
+
+    /// OpenAPI 3.1 nullable schemas without type are _`anyOf`_ of the schema and _`null`_ type.
+    fn into_any_of(self, any_of: Vec<Keywords>) -> Result<Component, String> {
+        let is_only_keyword = self == Keywords::default();
+        let mut items = any_of;
+        if is_only_keyword && items.len() == 2 && items[1].is_null_type() {
+            items.pop();
+            let item = items.pop().map(Keywords::into_component).transpose()?;
+            return Ok(item.map(into_nullable).unwrap_or_default());
+        }
+
+        Ok(AnyOf {
+            items: items
+                .into_iter()
+                .map(Keywords::into_component)
+                .collect::<Result<_, _>>()?,
+            nullable: self.nullable,
+            description: self.description,
+            deprecated: self.deprecated,
+            discriminator: self.discriminator,
+            extensions: self.extensions,
+        }
+        .into())
+    }
+
+    fn is_null_type(&self) -> bool {
+        *self
+            == Keywords {
+                types: Some(Types(vec![None])),
+                ..Default::default()
+            }
+    }
+
+    fn into_array(self) -> Result<Component, String> {
+        let items = self
+            .items
+            .ok_or_else(|| "missing field `items`".to_string())?;
+
+        Ok(Array {
+            component_type: ComponentType::Array,
+            items,
+            nullable: self.nullable,
+            max_items: self.max_items,
+            min_items: self.min_items,
+            unique_items: self.unique_items,
+            xml: self.xml,
+            extensions: self.extensions,
+        }
+        .into())
+    }
+
+    fn into_object(self) -> Component {
+        Object {
+            component_type: ComponentType::Object,
+            required: self.required,
+            properties: self.properties.unwrap_or_default(),
+            nullable: self.nullable,
+            description: self.description,
+            deprecated: self.deprecated,
+            example: self.example,
+            xml: self.xml,
+            additional_properties: self.additional_properties,
+            discriminator: self.discriminator,
+            defs: self.defs,
+            extensions: self.extensions,
+        }
+        .into()
+    }
+
+    fn into_property(self, component_type: ComponentType) -> Result<Component, String> {
+        let (minimum, exclusive_minimum) =
+            into_openapi_30_limit(self.minimum, self.exclusive_minimum, "minimum")?;
+        let (maximum, exclusive_maximum) =
+            into_openapi_30_limit(self.maximum, self.exclusive_maximum, "maximum")?;
+
+        Ok(Property {
+            component_type,
+            format: self.format,
+            nullable: self.nullable,
+            description: self.description,
+            default: self.default,
+            enum_values: self.enum_values,
+            const_value: self.const_value,
+            example: self.example,
+            deprecated: self.deprecated,
+            write_only: self.write_only,
+            read_only: self.read_only,
+            xml: self.xml,
+            maximum,
+            minimum,
+            exclusive_maximum,
+            exclusive_minimum,
+            max_length: self.max_length,
+            min_length: self.min_length,
+            pattern: self.pattern,
+            extensions: self.extensions,
+        }
+        .into())
+    }
+}
+
+/// Make the component nullable. Components which cannot be nullable themselves are wrapped to
+/// nullable [`AllOf`].
+fn into_nullable(component: Component) -> Component {
+    match component {
+        Component::Property(mut property) => {
+            property.nullable = Some(true);
+            property.into()
+        }
+        Component::Object(mut object) => {
+            object.nullable = Some(true);
+            object.into()
+        }
+        Component::Array(mut array) => {
+            array.nullable = Some(true);
+            array.into()
+        }
+        Component::OneOf(mut one_of) => {
+            one_of.nullable = Some(true);
+            one_of.into()
+        }
+        Component::AllOf(mut all_of) => {
+            all_of.nullable = Some(true);
+            all_of.into()
+        }
+        Component::AnyOf(mut any_of) => {
+            any_of.nullable = Some(true);
+            any_of.into()
+        }
+        Component::Ref(reference) => AllOf {
+            items: vec![reference.into()],
+            nullable: Some(true),
+            ..Default::default()
+        }
+        .into(),
+    }
+}
+
+/// OpenAPI 3.1 _`examples`_ is an array of examples where OpenAPI 3.0 only has a single
+/// _`example`_.
+fn single_example(examples: Vec<AnyValue>, has_example: bool) -> Result<AnyValue, String> {
+    match <[AnyValue; 1]>::try_from(examples) {
+        Ok([example]) if !has_example => Ok(example),
+        _ => Err("unsupported keyword `examples` with multiple examples".to_string()),
+    }
+}
+
+/// OpenAPI 3.1 exclusive limits are numeric limits where OpenAPI 3.0 has boolean modifiers of
+/// the actual limits.
+fn into_openapi_30_limit(
+    limit: Option<f64>,
+    exclusive: Option<ExclusiveLimit>,
+    name: &str,
+) -> Result<(Option<f64>, Option<bool>), String> {
+    match (limit, exclusive) {
+        (limit, None) => Ok((limit, None)),
+        (limit, Some(ExclusiveLimit::Modifier(exclusive))) => Ok((limit, Some(exclusive))),
+        (None, Some(ExclusiveLimit::Limit(limit))) => Ok((Some(limit), Some(true))),
+        (Some(_), Some(ExclusiveLimit::Limit(_))) => Err(format!(
+            "unsupported keyword `{}` together with numeric exclusive {}",
+            name, name
+        )),
+    }
+}
+
+/// Value of _`type`_ keyword which is either a single type or OpenAPI 3.1 array of types. `None`
+/// is the _`null`_ type.
+#[derive(PartialEq)]
+struct Types(Vec<Option<ComponentType>>);
+
+impl Types {
+    /// Get the single type and whether the _`null`_ type is one of the types.
+    fn into_openapi_30(self) -> Result<(Option<ComponentType>, bool), String> {
+        let is_nullable = self.0.contains(&None);
+        let mut types = self.0.into_iter().flatten();
+
+        match (types.next(), types.next()) {
+            (Some(component_type), None) => Ok((Some(component_type), is_nullable)),
+            (None, _) => Err("unsupported keyword `type` with only `null` type".to_string()),
+            (Some(_), Some(_)) => Err("unsupported keyword `type` with multiple types".to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Types {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TypesVisitor;
+
+        fn component_type<E: de::Error>(name: &str) -> Result<Option<ComponentType>, E> {
+            if name == "null" {
+                Ok(None)
+            } else {
+                ComponentType::deserialize(name.into_deserializer()).map(Some)
+            }
+        }
+
+        impl<'de> Visitor<'de> for TypesVisitor {
+            type Value = Types;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a type or an array of types")
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+                component_type(name).map(|component_type| Types(vec![component_type]))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut types = Vec::new();
+                while let Some(name) = seq.next_element::<String>()? {
+                    types.push(component_type(&name)?);
+                }
+
+                Ok(Types(types))
+            }
+        }
+
+        deserializer.deserialize_any(TypesVisitor)
+    }
+}
+
+/// Value of _`exclusiveMinimum`_ and _`exclusiveMaximum`_ keywords which is either OpenAPI 3.0
+/// boolean modifier or OpenAPI 3.1 numeric limit.
+#[derive(PartialEq)]
+enum ExclusiveLimit {
+    Modifier(bool),
+    Limit(f64),
+}
+
+impl<'de> Deserialize<'de> for ExclusiveLimit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ExclusiveLimitVisitor;
+
+        impl<'de> Visitor<'de> for ExclusiveLimitVisitor {
+            type Value = ExclusiveLimit;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a bool or a number")
+            }
+
+            fn visit_bool<E: de::Error>(self, exclusive: bool) -> Result<Self::Value, E> {
+                Ok(ExclusiveLimit::Modifier(exclusive))
+            }
+
+            fn visit_i64<E: de::Error>(self, limit: i64) -> Result<Self::Value, E> {
+                Ok(ExclusiveLimit::Limit(limit as f64))
+            }
+
+            fn visit_u64<E: de::Error>(self, limit: u64) -> Result<Self::Value, E> {
+                Ok(ExclusiveLimit::Limit(limit as f64))
+            }
+
+            fn visit_f64<E: de::Error>(self, limit: f64) -> Result<Self::Value, E> {
+                Ok(ExclusiveLimit::Limit(limit))
+            }
+        }
+
+        deserializer.deserialize_any(ExclusiveLimitVisitor)
+    }
+}
@@ -0,0 +1,482 @@
+//! Implements conversion from [schemars](https://crates.io/crates/schemars) JSON schemas to
+//! OpenAPI [`Component`]s.
+//!
+//! This allows using types that derive `schemars::JsonSchema` within OpenAPI documentation
+//! either by converting the schemas manually or with `#[component(schemars)]` marker of
+//! [`Component`][derive] derive.
+//!
+//! **schemars** feature need to be enabled.
+//!
+//! [derive]: ../../derive.Component.html
+use ::schemars::{
+    gen::SchemaSettings,
+    schema::{
+        ArrayValidation, InstanceType, Metadata, ObjectValidation, RootSchema, Schema,
+        SchemaObject, SingleOrVec,
+    },
+    JsonSchema,
+};
+use serde_json::Value;
+
+use super::{
+    schema::{
+        AdditionalProperties, AllOfBuilder, AnyOfBuilder, Array, Component, ComponentFormat,
+        ComponentType, Components, ObjectBuilder, OneOfBuilder, PropertyBuilder, Ref,
+    },
+    Deprecated,
+};
+
+/// Generate [`Component`] for `T` with schemars. Types referenced by `T` are referenced with
+/// [`Ref`]s and can be generated with [`definitions_for`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use schemars::JsonSchema;
+/// #[derive(JsonSchema)]
+/// struct Pet {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// let pet = utoipa::openapi::schemars::component_for::<Pet>();
+/// ```
+pub fn component_for<T: JsonSchema>() -> Component {
+    root_schema_for::<T>().schema.into()
+}
+
+/// Generate [`Component`]s of all types referenced by `T` with schemars. Components are
+/// returned with their names and should be added to [`Components`] along with `T`.
+pub fn definitions_for<T: JsonSchema>() -> Vec<(String, Component)> {
+    root_schema_for::<T>()
+        .definitions
+        .into_iter()
+        .map(|(name, schema)| (name, schema.into()))
+        .collect()
+}
+
+fn root_schema_for<T: JsonSchema>() -> RootSchema {
+    SchemaSettings::openapi3()
+        .into_generator()
+        .into_root_schema_for::<T>()
+}
+
+/// Converts root schema to [`Components`]. Root schema itself is added with its title as name
+/// if it has one and all definitions are added with their names.
+impl From<RootSchema> for Components {
+    fn from(root_schema: RootSchema) -> Self {
+        let mut components = Components::new();
+
+        let title = root_schema
+            .schema
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.title.clone());
+        if let Some(title) = title {
+            components.schemas.insert(title, root_schema.schema.into());
+        }
+
+        for (name, schema) in root_schema.definitions {
+            components.schemas.insert(name, schema.into());
+        }
+
+        components
+    }
+}
+
+impl From<Schema> for Component {
+    fn from(schema: Schema) -> Self {
+        match schema {
+            Schema::Object(schema_object) => schema_object.into(),
+            // Boolean schemas accept either anything or nothing, closest is an untyped object.
+            Schema::Bool(_) => ObjectBuilder::new().into(),
+        }
+    }
+}
+
+impl From<SchemaObject> for Component {
+    fn from(schema: SchemaObject) -> Self {
+        if let Some(ref reference) = schema.reference {
+            return Ref::from_component_name(component_name(reference)).into();
+        }
+
+        if let Some(subschemas) = schema.subschemas.as_deref() {
+            if let Some(items) = subschemas.one_of.clone() {
+                return items
+                    .into_iter()
+                    .fold(OneOfBuilder::new(), |one_of, item| one_of.item(item))
+                    .nullable(nullable(&schema))
+                    .description(description(&schema.metadata))
+                    .into();
+            }
+            if let Some(items) = subschemas.any_of.clone() {
+                return items
+                    .into_iter()
+                    .fold(AnyOfBuilder::new(), |any_of, item| any_of.item(item))
+                    .nullable(nullable(&schema))
+                    .description(description(&schema.metadata))
+                    .into();
+            }
+            if let Some(items) = subschemas.all_of.clone() {
+                return all_of(items, &schema);
+            }
+        }
+
+        match instance_type(&schema) {
+            Some(InstanceType::Object) => object(schema),
+            Some(InstanceType::Array) => array(schema),
+            Some(InstanceType::String) => property(ComponentType::String, schema),
+            Some(InstanceType::Integer) => property(ComponentType::Integer, schema),
+            Some(InstanceType::Number) => property(ComponentType::Number, schema),
+            Some(InstanceType::Boolean) => property(ComponentType::Boolean, schema),
+            Some(InstanceType::Null) | None => {
+                if schema.enum_values.is_some() || schema.const_value.is_some() {
+                    property(ComponentType::String, schema)
+                } else {
+                    object(schema)
+                }
+            }
+        }
+    }
+}
+
+/// Schemars wraps references with single item _`allOf`_ to describe them e.g. as nullable. The
+/// single item is used as is unless the _`allOf`_ itself has nullable or description which are
+/// kept by keeping the [`AllOf`] as well.
+fn all_of(items: Vec<Schema>, schema: &SchemaObject) -> Component {
+    let nullable = nullable(schema);
+    let description = description(&schema.metadata);
+
+    if let [item] = items.as_slice() {
+        if nullable.is_none() && description.is_none() {
+            return item.clone().into();
+        }
+    }
+
+    items
+        .into_iter()
+        .fold(AllOfBuilder::new(), |all_of, item| all_of.item(item))
+        .nullable(nullable)
+        .description(description)
+        .into()
+}
+
+/// Get first non null instance type of schema if any.
+fn instance_type(schema: &SchemaObject) -> Option<InstanceType> {
+    match schema.instance_type.as_ref()? {
+        SingleOrVec::Single(instance_type) => Some(**instance_type),
+        SingleOrVec::Vec(instance_types) => instance_types
+            .iter()
+            .find(|instance_type| **instance_type != InstanceType::Null)
+            .or_else(|| instance_types.first())
+            .copied(),
+    }
+}
+
+/// Get whether schema accepts `null`. Schemas generated with OpenAPI settings mark nullable
+/// schemas with `nullable` extension, otherwise `null` is one of the instance types.
+fn nullable(schema: &SchemaObject) -> Option<bool> {
+    let nullable = schema.extensions.get("nullable") == Some(&Value::Bool(true))
+        || matches!(
+            schema.instance_type,
+            Some(SingleOrVec::Vec(ref instance_types)) if instance_types.contains(&InstanceType::Null)
+        );
+
+    nullable.then(|| true)
+}
+
+fn object(schema: SchemaObject) -> Component {
+    let nullable = nullable(&schema);
+    let ObjectValidation {
+        required,
+        properties,
+        additional_properties,
+        ..
+    } = schema.object.map(|object| *object).unwrap_or_default();
+
+    let additional_properties = additional_properties.map(|schema| match *schema {
+        Schema::Object(schema_object) => Component::from(schema_object).into(),
+        Schema::Bool(free_form) => AdditionalProperties::FreeForm(free_form),
+    });
+
+    let object = properties.into_iter().fold(
+        ObjectBuilder::new()
+            .nullable(nullable)
+            .description(description(&schema.metadata))
+            .deprecated(deprecated(&schema.metadata))
+            .example(example(&schema.metadata, &schema.extensions))
+            .additional_properties(additional_properties),
+        |object, (name, property)| object.property(name, property),
+    );
+
+    required
+        .into_iter()
+        .fold(object, |object, name| object.required(name))
+        .into()
+}
+
+fn array(schema: SchemaObject) -> Component {
+    let nullable = nullable(&schema);
+    let ArrayValidation {
+        items,
+        max_items,
+        min_items,
+        unique_items,
+        ..
+    } = schema.array.map(|array| *array).unwrap_or_default();
+
+    let items: Component = match items {
+        Some(SingleOrVec::Single(item)) => (*item).into(),
+        Some(SingleOrVec::Vec(items)) => items
+            .into_iter()
+            .fold(OneOfBuilder::new(), |one_of, item| one_of.item(item))
+            .into(),
+        None => ObjectBuilder::new().into(),
+    };
+
+    Array::new(items)
+        .to_builder()
+        .nullable(nullable)
+        .max_items(max_items.map(|max_items| max_items as usize))
+        .min_items(min_items.map(|min_items| min_items as usize))
+        .unique_items(unique_items)
+        .into()
+}
+
+fn property(component_type: ComponentType, schema: SchemaObject) -> Component {
+    let nullable = nullable(&schema);
+    let enum_values = schema
+        .enum_values
+        .or_else(|| schema.const_value.map(|value| vec![value]));
+
+    let mut property = PropertyBuilder::new()
+        .component_type(component_type)
+        .nullable(nullable)
+        .format(schema.format.map(ComponentFormat::from))
+        .description(description(&schema.metadata))
+        .deprecated(deprecated(&schema.metadata))
+        .example(example(&schema.metadata, &schema.extensions))
+        .default(
+            schema
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.default.clone()),
+        )
+        .enum_values(enum_values);
+
+    if let Some(ref metadata) = schema.metadata {
+        property = property
+            .read_only(metadata.read_only.then(|| true))
+            .write_only(metadata.write_only.then(|| true));
+    }
+
+    if let Some(number) = schema.number {
+        property = property
+            .minimum(number.minimum.or(number.exclusive_minimum))
+            .exclusive_minimum(
+                (number.minimum.is_none() && number.exclusive_minimum.is_some()).then(|| true),
+            )
+            .maximum(number.maximum.or(number.exclusive_maximum))
+            .exclusive_maximum(
+                (number.maximum.is_none() && number.exclusive_maximum.is_some()).then(|| true),
+            );
+    }
+
+    if let Some(string) = schema.string {
+        property = property
+            .max_length(string.max_length.map(|max_length| max_length as usize))
+            .min_length(string.min_length.map(|min_length| min_length as usize))
+            .pattern(string.pattern);
+    }
+
+    property.into()
+}
+
+/// Get name of the referenced component from reference location e.g. `Pet` from
+/// `#/definitions/Pet` or `#/components/schemas/Pet`.
+fn component_name(reference: &str) -> &str {
+    reference
+        .rsplit_once('/')
+        .map(|(_, name)| name)
+        .unwrap_or(reference)
+}
+
+fn description(metadata: &Option<Box<Metadata>>) -> Option<String> {
+    metadata
+        .as_ref()
+        .and_then(|metadata| metadata.description.clone())
+}
+
+fn deprecated(metadata: &Option<Box<Metadata>>) -> Option<Deprecated> {
+    metadata
+        .as_ref()
+        .and_then(|metadata| metadata.deprecated.then(|| Deprecated::True))
+}
+
+/// Get example of the schema. Schemas generated with OpenAPI settings have example already
+/// moved to `example` extension, otherwise first example of `examples` is used.
+fn example(
+    metadata: &Option<Box<Metadata>>,
+    extensions: &::schemars::Map<String, Value>,
+) -> Option<Value> {
+    extensions.get("example").cloned().or_else(|| {
+        metadata
+            .as_ref()
+            .and_then(|metadata| metadata.examples.first().cloned())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ::schemars::{schema_for, JsonSchema};
+    use serde_json::json;
+
+    use super::*;
+
+    #[allow(unused)]
+    #[derive(JsonSchema)]
+    struct Pet {
+        /// Id of pet
+        id: u64,
+        name: String,
+        tags: Option<Vec<String>>,
+        owner: Owner,
+    }
+
+    #[allow(unused)]
+    #[derive(JsonSchema)]
+    struct Owner {
+        #[schemars(length(min = 1, max = 10))]
+        name: String,
+        #[schemars(range(min = 18))]
+        age: i32,
+    }
+
+    #[test]
+    fn convert_root_schema_to_components() {
+        let components = Components::from(schema_for!(Pet));
+        let components = serde_json::to_value(components).unwrap();
+
+        assert_eq!(
+            components,
+            json!({
+                "schemas": {
+                    "Pet": {
+                        "type": "object",
+                        "required": ["id", "name", "owner"],
+                        "properties": {
+                            "id": {
+                                "type": "integer",
+                                "format": "uint64",
+                                "description": "Id of pet",
+                                "minimum": 0.0
+                            },
+                            "name": {
+                                "type": "string"
+                            },
+                            "tags": {
+                                "type": "array",
+                                "nullable": true,
+                                "items": {
+                                    "type": "string"
+                                }
+                            },
+                            "owner": {
+                                "$ref": "#/components/schemas/Owner"
+                            }
+                        }
+                    },
+                    "Owner": {
+                        "type": "object",
+                        "required": ["age", "name"],
+                        "properties": {
+                            "name": {
+                                "type": "string",
+                                "maxLength": 10,
+                                "minLength": 1
+                            },
+                            "age": {
+                                "type": "integer",
+                                "format": "int32",
+                                "minimum": 18.0
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn convert_component_with_definitions() {
+        let pet = serde_json::to_value(component_for::<Pet>()).unwrap();
+        let definitions = definitions_for::<Pet>()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(pet["type"], json!("object"));
+        assert_eq!(
+            pet["properties"]["owner"]["$ref"],
+            json!("#/components/schemas/Owner")
+        );
+        assert_eq!(definitions, vec!["Owner".to_string()]);
+    }
+
+    #[test]
+    fn convert_nullable_schemas() {
+        #[allow(unused)]
+        #[derive(JsonSchema)]
+        struct Visit {
+            note: Option<String>,
+            rooms: Option<Vec<u32>>,
+            owner: Option<Owner>,
+        }
+
+        let visit = serde_json::to_value(component_for::<Visit>()).unwrap();
+
+        assert_eq!(visit["properties"]["note"]["nullable"], json!(true));
+        assert_eq!(visit["properties"]["rooms"]["nullable"], json!(true));
+        assert_eq!(
+            visit["properties"]["owner"],
+            json!({
+                "allOf": [{ "$ref": "#/components/schemas/Owner" }],
+                "nullable": true
+            })
+        );
+    }
+
+    #[test]
+    fn convert_all_of_schemas() {
+        let schema: Schema = serde_json::from_value(json!({
+            "allOf": [
+                { "$ref": "#/definitions/Pet" },
+                { "type": "object", "properties": { "name": { "type": "string" } } }
+            ],
+            "description": "Pet with name"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(Component::from(schema)).unwrap(),
+            json!({
+                "allOf": [
+                    { "$ref": "#/components/schemas/Pet" },
+                    { "type": "object", "properties": { "name": { "type": "string" } } }
+                ],
+                "description": "Pet with name"
+            })
+        );
+    }
+
+    #[test]
+    fn convert_single_all_of_without_nullable_or_description_to_item() {
+        let schema: Schema =
+            serde_json::from_value(json!({ "allOf": [{ "$ref": "#/definitions/Pet" }] })).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(Component::from(schema)).unwrap(),
+            json!({ "$ref": "#/components/schemas/Pet" })
+        );
+    }
+}
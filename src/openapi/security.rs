@@ -3,11 +3,18 @@
 //! Refer to [`SecurityScheme`] for usage and more details.
 //!
 //! [security]: https://spec.openapis.org/oas/latest.html#security-scheme-object
-use std::{collections::HashMap, iter};
+use std::{collections::HashMap, fmt, iter};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, IgnoredAny, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
-use super::{build_fn, builder, from, new};
+use super::{
+    build_fn, builder,
+    extensions::{self, Extensions},
+    from, new, set_value,
+};
 
 /// OpenAPI [security requirment][security] object.
 ///
@@ -24,10 +31,10 @@ use super::{build_fn, builder, from, new};
 /// [path]: ../../attr.path.html
 /// [openapi]: ../../derive.OpenApi.html
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
 pub struct SecurityRequirement {
     #[serde(flatten)]
-    value: HashMap<String, Vec<String>>,
+    pub(crate) value: HashMap<String, Vec<String>>,
 }
 
 impl SecurityRequirement {
@@ -97,7 +104,7 @@ impl SecurityRequirement {
 ///     HttpBuilder::new().scheme(HttpAuthScheme::Bearer).bearer_format("JWT").build()
 /// );
 /// ```
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(tag = "type", rename_all = "camelCase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum SecurityScheme {
@@ -117,11 +124,47 @@ pub enum SecurityScheme {
     MutualTls {
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+
+        /// Optional extensions `x-something` of the [`SecurityScheme::MutualTls`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        extensions: Option<Extensions>,
     },
 }
 
+impl SecurityScheme {
+    /// Get extensions of the [`SecurityScheme`] regardless of its type.
+    pub(crate) fn extensions(&self) -> Option<&Extensions> {
+        match self {
+            Self::OAuth2(oauth2) => oauth2.extensions.as_ref(),
+            Self::ApiKey(ApiKey::Header(value) | ApiKey::Query(value) | ApiKey::Cookie(value)) => {
+                value.extensions.as_ref()
+            }
+            Self::Http(http) => http.extensions.as_ref(),
+            Self::OpenIdConnect(open_id_connect) => open_id_connect.extensions.as_ref(),
+            Self::MutualTls { extensions, .. } => extensions.as_ref(),
+        }
+    }
+
+    /// Get mutable extensions of the [`SecurityScheme`] regardless of its type.
+    pub(crate) fn extensions_mut(&mut self) -> Option<&mut Extensions> {
+        match self {
+            Self::OAuth2(oauth2) => oauth2.extensions.as_mut(),
+            Self::ApiKey(ApiKey::Header(value) | ApiKey::Query(value) | ApiKey::Cookie(value)) => {
+                value.extensions.as_mut()
+            }
+            Self::Http(http) => http.extensions.as_mut(),
+            Self::OpenIdConnect(open_id_connect) => open_id_connect.extensions.as_mut(),
+            Self::MutualTls { extensions, .. } => extensions.as_mut(),
+        }
+    }
+}
+
 /// Api key authentication [`SecurityScheme`].
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(tag = "in", rename_all = "lowercase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum ApiKey {
@@ -135,7 +178,7 @@ pub enum ApiKey {
 
 /// Value object for [`ApiKey`].
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ApiKeyValue {
     /// Name of the [`ApiKey`] parameter.
@@ -144,6 +187,14 @@ pub struct ApiKeyValue {
     /// Description of the the [`ApiKey`] [`SecurityScheme`]. Supports markdown syntax.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Optional extensions `x-something` of the [`ApiKeyValue`].
+    #[serde(
+        flatten,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl ApiKeyValue {
@@ -160,6 +211,7 @@ impl ApiKeyValue {
         Self {
             name: name.into(),
             description: None,
+            extensions: None,
         }
     }
 
@@ -176,6 +228,7 @@ impl ApiKeyValue {
         Self {
             name: name.into(),
             description: Some(description.into()),
+            extensions: None,
         }
     }
 }
@@ -187,7 +240,7 @@ builder! {
     ///
     /// Methods can be chained to configure _bearer_format_ or to add _description_.
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Clone)]
+    #[derive(Serialize, Deserialize, Clone, PartialEq)]
     #[serde(rename_all = "camelCase")]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct Http {
@@ -201,6 +254,14 @@ builder! {
         /// Optional description of [`Http`] [`SecurityScheme`] supporting markdown syntax.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
+
+        /// Optional extensions `x-something` of the [`Http`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -221,6 +282,7 @@ impl Http {
             scheme,
             bearer_format: None,
             description: None,
+            extensions: None,
         }
     }
 }
@@ -265,6 +327,11 @@ impl HttpBuilder {
 
         self
     }
+
+    /// Add or change optional extensions `x-something` of the [`Http`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
 
 /// Implements types according [RFC7235](https://datatracker.ietf.org/doc/html/rfc7235#section-5.1).
@@ -296,7 +363,7 @@ impl Default for HttpAuthScheme {
 
 /// Open id connect [`SecurityScheme`]
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct OpenIdConnect {
@@ -306,6 +373,14 @@ pub struct OpenIdConnect {
     /// Description of [`OpenIdConnect`] [`SecurityScheme`] supporting markdown syntax.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Optional extensions `x-something` of the [`OpenIdConnect`].
+    #[serde(
+        flatten,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl OpenIdConnect {
@@ -321,6 +396,7 @@ impl OpenIdConnect {
         Self {
             open_id_connect_url: open_id_connect_url.into(),
             description: None,
+            extensions: None,
         }
     }
 
@@ -337,21 +413,31 @@ impl OpenIdConnect {
         Self {
             open_id_connect_url: open_id_connect_url.into(),
             description: Some(description.into()),
+            extensions: None,
         }
     }
 }
 
 /// OAuth2 [`Flow`] configuration for [`SecurityScheme`].
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct OAuth2 {
     /// Map of supported OAuth2 flows.
+    #[serde(deserialize_with = "deserialize_flows")]
     pub flows: HashMap<String, Flow>,
 
     /// Optional description for the [`OAuth2`] [`Flow`] [`SecurityScheme`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Optional extensions `x-something` of the [`OAuth2`].
+    #[serde(
+        flatten,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "extensions::deserialize_optional"
+    )]
+    pub extensions: Option<Extensions>,
 }
 
 impl OAuth2 {
@@ -393,6 +479,7 @@ impl OAuth2 {
                     .map(|auth_flow| (String::from(auth_flow.get_type_as_str()), auth_flow)),
             ),
             description: None,
+            extensions: None,
         }
     }
 
@@ -436,6 +523,7 @@ impl OAuth2 {
                     .map(|auth_flow| (String::from(auth_flow.get_type_as_str()), auth_flow)),
             ),
             description: Some(description.into()),
+            extensions: None,
         }
     }
 }
@@ -444,7 +532,7 @@ impl OAuth2 {
 ///
 ///
 /// See more details at <https://spec.openapis.org/oas/latest.html#oauth-flows-object>.
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(untagged)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub enum Flow {
@@ -471,9 +559,58 @@ impl Flow {
     }
 }
 
+/// Deserialize [`OAuth2`] flows by the name of the flow. Flows cannot be told apart by their
+/// content since e.g. [`AuthorizationCode`] has all the fields of [`Implicit`].
+fn deserialize_flows<'de, D>(deserializer: D) -> Result<HashMap<String, Flow>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct FlowsVisitor;
+
+    impl<'de> Visitor<'de> for FlowsVisitor {
+        type Value = HashMap<String, Flow>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("map of OAuth2 flows")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            const FLOWS: &[&str] = &[
+                "implicit",
+                "password",
+                "clientCredentials",
+                "authorizationCode",
+            ];
+            let mut flows = HashMap::new();
+
+            while let Some(name) = map.next_key::<String>()? {
+                let flow = match name.as_str() {
+                    "implicit" => Flow::Implicit(map.next_value()?),
+                    "password" => Flow::Password(map.next_value()?),
+                    "clientCredentials" => Flow::ClientCredentials(map.next_value()?),
+                    "authorizationCode" => Flow::AuthorizationCode(map.next_value()?),
+                    _ if name.starts_with("x-") => {
+                        map.next_value::<IgnoredAny>()?;
+                        continue;
+                    }
+                    _ => return Err(de::Error::unknown_variant(&name, FLOWS)),
+                };
+                flows.insert(name, flow);
+            }
+
+            Ok(flows)
+        }
+    }
+
+    deserializer.deserialize_map(FlowsVisitor)
+}
+
 /// Implicit [`Flow`] configuration for [`OAuth2`].
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Implicit {
@@ -556,7 +693,7 @@ impl Implicit {
 
 /// Authorization code [`Flow`] configuration for [`OAuth2`].
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct AuthorizationCode {
@@ -651,7 +788,7 @@ impl AuthorizationCode {
 
 /// Password [`Flow`] configuration for [`OAuth2`].
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Password {
@@ -733,7 +870,7 @@ impl Password {
 
 /// Client credentials [`Flow`] configuration for [`OAuth2`].
 #[non_exhaustive]
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ClientCredentials {
@@ -846,7 +983,7 @@ impl ClientCredentials {
 ///     ("read:items", "read my items")
 /// ]);
 /// ```
-#[derive(Default, Serialize, Deserialize, Clone)]
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Scopes {
     scopes: HashMap<String, String>,
@@ -1197,11 +1334,31 @@ mod tests {
     test_fn! {
         security_schema_correct_mutual_tls:
         SecurityScheme::MutualTls {
-            description: Some(String::from("authorizaion is performed with client side certificate"))
+            description: Some(String::from("authorizaion is performed with client side certificate")),
+            extensions: None,
         };
         r###"{
   "type": "mutualTLS",
   "description": "authorizaion is performed with client side certificate"
+}"###
+    }
+
+    test_fn! {
+        security_schema_correct_http_with_extensions:
+        SecurityScheme::Http(
+            HttpBuilder::new()
+                .scheme(HttpAuthScheme::Bearer)
+                .extensions(Some(
+                    crate::openapi::extensions::ExtensionsBuilder::new()
+                        .add("x-token-lifetime", "1h")
+                        .build(),
+                ))
+                .build(),
+        );
+        r###"{
+  "type": "http",
+  "scheme": "bearer",
+  "x-token-lifetime": "1h"
 }"###
     }
 }
@@ -3,7 +3,12 @@
 //! [tag]: https://spec.openapis.org/oas/latest.html#tag-object
 use serde::{Deserialize, Serialize};
 
-use super::{build_fn, builder, external_docs::ExternalDocs, from, new, set_value};
+use super::{
+    build_fn, builder,
+    extensions::{self, Extensions},
+    external_docs::ExternalDocs,
+    from, new, set_value,
+};
 
 builder! {
     TagBuilder;
@@ -14,7 +19,7 @@ builder! {
     ///
     /// [tag]: https://spec.openapis.org/oas/latest.html#tag-object
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     #[serde(rename_all = "camelCase")]
     pub struct Tag {
@@ -28,6 +33,14 @@ builder! {
         /// Additional external documentation for the tag.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub external_docs: Option<ExternalDocs>,
+
+        /// Optional extensions `x-something` of the [`Tag`].
+        #[serde(
+            flatten,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "extensions::deserialize_optional"
+        )]
+        pub extensions: Option<Extensions>,
     }
 }
 
@@ -56,4 +69,9 @@ impl TagBuilder {
     pub fn external_docs(mut self, external_docs: Option<ExternalDocs>) -> Self {
         set_value!(self external_docs external_docs)
     }
+
+    /// Add or change optional extensions `x-something` of the [`Tag`].
+    pub fn extensions(mut self, extensions: Option<Extensions>) -> Self {
+        set_value!(self extensions extensions)
+    }
 }
@@ -0,0 +1,781 @@
+//! Implements consistency validation of [`OpenApi`] documents. See [`OpenApi::validate`] for
+//! more details.
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+};
+
+use super::{
+    callback::Callback,
+    content::Content,
+    header::Header,
+    path::{Operation, Parameter, ParameterIn},
+    pointer_child,
+    request_body::RequestBody,
+    schema::AdditionalProperties,
+    Component, Components, Example, Link, OpenApi, PathItem, Ref, RefOr, Response,
+    SecurityRequirement,
+};
+
+/// Severity of the [`ValidationError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Document is usable but likely contains a mistake e.g. operation without responses.
+    Warning,
+    /// Document violates the OpenAPI specification or has references which do not resolve.
+    /// Clients generated from the document are likely to break.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => f.write_str("warning"),
+            Self::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// Problem found in [`OpenApi`] document by [`OpenApi::validate`].
+///
+/// Location of the problem is given as [JSON pointer][json_pointer] within the serialized
+/// document e.g. _`/paths/~1pets~1{id}/get/parameters/0`_.
+///
+/// [json_pointer]: https://www.rfc-editor.org/rfc/rfc6901
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    pointer: String,
+    severity: Severity,
+    message: String,
+}
+
+impl ValidationError {
+    fn new(pointer: String, severity: Severity, message: String) -> Self {
+        Self {
+            pointer,
+            severity,
+            message,
+        }
+    }
+
+    /// JSON pointer to the location of the problem within the document.
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    /// Severity of the problem.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Message describing the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}: {}", self.severity, self.pointer, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validate the [`OpenApi`] document returning all found problems. Problems are ordered by their
+/// location in the document where entries of maps e.g. components are ordered by their name.
+pub(super) fn validate(openapi: &OpenApi) -> Vec<ValidationError> {
+    let mut validator = Validator {
+        components: openapi.components.as_ref(),
+        operation_ids: BTreeSet::new(),
+        errors: Vec::new(),
+    };
+
+    validator.collect_operation_ids(openapi);
+
+    for (path, path_item) in &openapi.paths.paths {
+        validator.path_item(&pointer_child("/paths", path), path_item, Some(path));
+    }
+    for (name, path_item) in &openapi.webhooks {
+        validator.path_item(&pointer_child("/webhooks", name), path_item, None);
+    }
+    if let Some(components) = &openapi.components {
+        validator.components("/components", components);
+    }
+    if let Some(security) = &openapi.security {
+        validator.security("/security", security);
+    }
+
+    let mut tags = BTreeSet::new();
+    for (index, tag) in openapi.tags.iter().flatten().enumerate() {
+        if !tags.insert(&*tag.name) {
+            validator.error(
+                pointer_child("/tags", index),
+                format!("duplicate tag `{}`", tag.name),
+            );
+        }
+    }
+
+    validator.errors
+}
+
+/// Get entries of the map ordered by name so that problems are reported in stable order.
+fn by_name<V>(map: &HashMap<String, V>) -> Vec<(&String, &V)> {
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+}
+
+/// Get names of the template expressions e.g. _`id`_ of _`/pets/{id}`_ in the path.
+fn path_template_names(path: &str) -> BTreeSet<&str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+struct Validator<'a> {
+    components: Option<&'a Components>,
+    operation_ids: BTreeSet<&'a str>,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    fn error(&mut self, pointer: String, message: String) {
+        self.errors
+            .push(ValidationError::new(pointer, Severity::Error, message));
+    }
+
+    fn warning(&mut self, pointer: String, message: String) {
+        self.errors
+            .push(ValidationError::new(pointer, Severity::Warning, message));
+    }
+
+    fn collect_operation_ids(&mut self, openapi: &'a OpenApi) {
+        let paths = openapi
+            .paths
+            .paths
+            .iter()
+            .map(|(path, path_item)| (pointer_child("/paths", path), path_item));
+        let webhooks = openapi
+            .webhooks
+            .iter()
+            .map(|(name, path_item)| (pointer_child("/webhooks", name), path_item));
+
+        let mut operation_ids = HashMap::<&str, String>::new();
+        for (pointer, path_item) in paths.chain(webhooks) {
+            for (path_item_type, operation) in &path_item.operations {
+                let operation_id = match operation.operation_id.as_deref() {
+                    Some(operation_id) => operation_id,
+                    None => continue,
+                };
+                let pointer = pointer_child(&pointer, path_item_type.as_str());
+
+                match operation_ids.get(operation_id) {
+                    Some(first) => {
+                        let message = format!(
+                            "duplicate operation id `{}`, first defined at {}",
+                            operation_id, first
+                        );
+                        self.error(pointer_child(&pointer, "operationId"), message);
+                    }
+                    None => {
+                        operation_ids.insert(operation_id, pointer);
+                    }
+                }
+            }
+        }
+
+        self.operation_ids = operation_ids.into_keys().collect();
+    }
+
+    /// Check that local _`#/components/{kind}/{name}`_ reference resolves to a component of
+    /// the expected kind. Other references are not validated.
+    fn reference(&mut self, pointer: String, reference: &Ref, kind: &str) {
+        let location = &*reference.ref_location;
+        let (ref_kind, name) = match location
+            .strip_prefix("#/components/")
+            .and_then(|path| path.split_once('/'))
+        {
+            Some((ref_kind, name)) => (ref_kind, name.split('/').next().unwrap_or(name)),
+            None => return,
+        };
+        let name = name.replace("~1", "/").replace("~0", "~");
+
+        if ref_kind != kind {
+            return self.error(
+                pointer,
+                format!(
+                    "reference `{}` must reference components of type `{}`",
+                    location, kind
+                ),
+            );
+        }
+
+        let exists = self.components.map_or(false, |components| match kind {
+            "schemas" => components.schemas.contains_key(&name),
+            "responses" => components.responses.contains_key(&name),
+            "parameters" => components.parameters.contains_key(&name),
+            "examples" => components.examples.contains_key(&name),
+            "requestBodies" => components.request_bodies.contains_key(&name),
+            "headers" => components.headers.contains_key(&name),
+            "securitySchemes" => components.security_schemes.contains_key(&name),
+            "links" => components.links.contains_key(&name),
+            "callbacks" => components.callbacks.contains_key(&name),
+            _ => false,
+        });
+        if !exists {
+            self.error(
+                pointer,
+                format!("reference `{}` does not resolve to a component", location),
+            );
+        }
+    }
+
+    fn ref_or<T>(
+        &mut self,
+        pointer: String,
+        value: &'a RefOr<T>,
+        kind: &str,
+        validate: impl FnOnce(&mut Self, String, &'a T),
+    ) {
+        match value {
+            RefOr::Ref(reference) => self.reference(pointer, reference, kind),
+            RefOr::T(value) => validate(self, pointer, value),
+        }
+    }
+
+    /// Resolve [`Parameter`] either defined inline or referenced from components.
+    fn resolve_parameter(&self, parameter: &'a RefOr<Parameter>) -> Option<&'a Parameter> {
+        match parameter {
+            RefOr::T(parameter) => Some(parameter),
+            RefOr::Ref(reference) => {
+                let name = reference.component_name("parameters")?;
+                match self.components?.parameters.get(&name)? {
+                    RefOr::T(parameter) => Some(parameter),
+                    RefOr::Ref(_) => None,
+                }
+            }
+        }
+    }
+
+    fn components(&mut self, pointer: &str, components: &'a Components) {
+        let pointer = |kind: &str, name: &str| pointer_child(&pointer_child(pointer, kind), name);
+
+        for (name, schema) in by_name(&components.schemas) {
+            self.schema(pointer("schemas", name), schema);
+        }
+        for (name, response) in by_name(&components.responses) {
+            self.ref_or(
+                pointer("responses", name),
+                response,
+                "responses",
+                Self::response,
+            );
+        }
+        for (name, parameter) in by_name(&components.parameters) {
+            self.ref_or(
+                pointer("parameters", name),
+                parameter,
+                "parameters",
+                Self::parameter,
+            );
+        }
+        for (name, example) in by_name(&components.examples) {
+            self.example(pointer("examples", name), example);
+        }
+        for (name, request_body) in by_name(&components.request_bodies) {
+            self.ref_or(
+                pointer("requestBodies", name),
+                request_body,
+                "requestBodies",
+                Self::request_body,
+            );
+        }
+        for (name, header) in by_name(&components.headers) {
+            self.ref_or(pointer("headers", name), header, "headers", Self::header);
+        }
+        for (name, link) in by_name(&components.links) {
+            self.ref_or(pointer("links", name), link, "links", Self::link);
+        }
+        for (name, callback) in by_name(&components.callbacks) {
+            self.ref_or(
+                pointer("callbacks", name),
+                callback,
+                "callbacks",
+                Self::callback,
+            );
+        }
+    }
+
+    fn path_item(&mut self, pointer: &str, path_item: &'a PathItem, path: Option<&str>) {
+        let template_names = path.map(path_template_names);
+
+        let path_parameters = self.parameters(
+            &pointer_child(pointer, "parameters"),
+            path_item.parameters.as_deref().unwrap_or_default(),
+            template_names.as_ref(),
+        );
+
+        for (path_item_type, operation) in &path_item.operations {
+            let pointer = pointer_child(pointer, path_item_type.as_str());
+            let operation_parameters = self.parameters(
+                &pointer_child(&pointer, "parameters"),
+                operation.parameters.as_deref().unwrap_or_default(),
+                template_names.as_ref(),
+            );
+
+            for name in template_names.iter().flatten() {
+                if !path_parameters.contains(name) && !operation_parameters.contains(name) {
+                    self.error(
+                        pointer.clone(),
+                        format!(
+                            "path template parameter `{}` of `{}` is not defined as path parameter",
+                            name,
+                            path.unwrap_or_default()
+                        ),
+                    );
+                }
+            }
+
+            self.operation(pointer, operation);
+        }
+    }
+
+    /// Validate list of parameters returning names of the path parameters.
+    fn parameters(
+        &mut self,
+        pointer: &str,
+        parameters: &'a [RefOr<Parameter>],
+        template_names: Option<&BTreeSet<&str>>,
+    ) -> BTreeSet<&'a str> {
+        let mut defined = Vec::<(&str, &ParameterIn)>::new();
+        let mut path_parameters = BTreeSet::new();
+
+        for (index, parameter) in parameters.iter().enumerate() {
+            let pointer = pointer_child(pointer, index);
+            self.ref_or(pointer.clone(), parameter, "parameters", Self::parameter);
+
+            let parameter = match self.resolve_parameter(parameter) {
+                Some(parameter) => parameter,
+                None => continue,
+            };
+            let key = (&*parameter.name, &parameter.parameter_in);
+            if defined.contains(&key) {
+                self.error(
+                    pointer.clone(),
+                    format!("duplicate parameter `{}`", parameter.name),
+                );
+            }
+            defined.push(key);
+
+            if parameter.parameter_in == ParameterIn::Path {
+                path_parameters.insert(&*parameter.name);
+                if let Some(template_names) = template_names {
+                    if !template_names.contains(&*parameter.name) {
+                        self.error(
+                            pointer,
+                            format!(
+                                "path parameter `{}` is not part of the path template",
+                                parameter.name
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        path_parameters
+    }
+
+    fn operation(&mut self, pointer: String, operation: &'a Operation) {
+        if let Some(request_body) = &operation.request_body {
+            self.request_body(pointer_child(&pointer, "requestBody"), request_body);
+        }
+
+        let responses_pointer = pointer_child(&pointer, "responses");
+        if operation.responses.responses.is_empty() {
+            self.warning(
+                responses_pointer.clone(),
+                "operation does not define any responses".to_string(),
+            );
+        }
+        for (status, response) in &operation.responses.responses {
+            self.ref_or(
+                pointer_child(&responses_pointer, status),
+                response,
+                "responses",
+                Self::response,
+            );
+        }
+
+        for (name, callback) in operation.callbacks.iter().flatten() {
+            self.ref_or(
+                pointer_child(&pointer_child(&pointer, "callbacks"), name),
+                callback,
+                "callbacks",
+                Self::callback,
+            );
+        }
+
+        if let Some(security) = &operation.security {
+            self.security(&pointer_child(&pointer, "security"), security);
+        }
+    }
+
+    fn parameter(&mut self, pointer: String, parameter: &'a Parameter) {
+        if let Some(schema) = &parameter.schema {
+            self.schema(pointer_child(&pointer, "schema"), schema);
+        }
+        for (name, example) in &parameter.examples {
+            self.example(
+                pointer_child(&pointer_child(&pointer, "examples"), name),
+                example,
+            );
+        }
+    }
+
+    fn request_body(&mut self, pointer: String, request_body: &'a RequestBody) {
+        for (content_type, content) in by_name(&request_body.content) {
+            self.content(
+                pointer_child(&pointer_child(&pointer, "content"), content_type),
+                content,
+            );
+        }
+    }
+
+    fn response(&mut self, pointer: String, response: &'a Response) {
+        for (name, header) in by_name(&response.headers) {
+            self.header(
+                pointer_child(&pointer_child(&pointer, "headers"), name),
+                header,
+            );
+        }
+        for (content_type, content) in by_name(&response.content) {
+            self.content(
+                pointer_child(&pointer_child(&pointer, "content"), content_type),
+                content,
+            );
+        }
+        for (name, link) in by_name(&response.links) {
+            self.ref_or(
+                pointer_child(&pointer_child(&pointer, "links"), name),
+                link,
+                "links",
+                Self::link,
+            );
+        }
+    }
+
+    fn content(&mut self, pointer: String, content: &'a Content) {
+        self.schema(pointer_child(&pointer, "schema"), &content.schema);
+        for (name, example) in &content.examples {
+            self.example(
+                pointer_child(&pointer_child(&pointer, "examples"), name),
+                example,
+            );
+        }
+    }
+
+    fn header(&mut self, pointer: String, header: &'a Header) {
+        self.schema(pointer_child(&pointer, "schema"), &header.schema);
+        for (name, example) in &header.examples {
+            self.example(
+                pointer_child(&pointer_child(&pointer, "examples"), name),
+                example,
+            );
+        }
+    }
+
+    fn example(&mut self, pointer: String, example: &'a RefOr<Example>) {
+        self.ref_or(pointer, example, "examples", |_, _, _| ());
+    }
+
+    fn link(&mut self, pointer: String, link: &'a Link) {
+        if let Some(operation_id) = link.operation_id.as_deref() {
+            if !self.operation_ids.contains(operation_id) {
+                self.error(
+                    pointer_child(&pointer, "operationId"),
+                    format!(
+                        "link references operation id `{}` which does not exist",
+                        operation_id
+                    ),
+                );
+            }
+        }
+    }
+
+    fn callback(&mut self, pointer: String, callback: &'a Callback) {
+        for (expression, path_item) in &callback.callbacks {
+            self.path_item(&pointer_child(&pointer, expression), path_item, None);
+        }
+    }
+
+    fn security(&mut self, pointer: &str, security: &[SecurityRequirement]) {
+        for (index, requirement) in security.iter().enumerate() {
+            for name in requirement.value.keys() {
+                let is_defined = self.components.map_or(false, |components| {
+                    components.security_schemes.contains_key(name)
+                });
+                if !is_defined {
+                    self.error(
+                        pointer_child(&pointer_child(pointer, index), name),
+                        format!("security scheme `{}` is not defined in components", name),
+                    );
+                }
+            }
+        }
+    }
+
+    fn schema(&mut self, pointer: String, schema: &'a Component) {
+        match schema {
+            Component::Ref(reference) => self.reference(pointer, reference, "schemas"),
+            Component::Object(object) => {
+                for (name, property) in by_name(&object.properties) {
+                    self.schema(
+                        pointer_child(&pointer_child(&pointer, "properties"), name),
+                        property,
+                    );
+                }
+                if let Some(AdditionalProperties::Component(additional_properties)) =
+                    object.additional_properties.as_deref()
+                {
+                    self.schema(
+                        pointer_child(&pointer, "additionalProperties"),
+                        additional_properties,
+                    );
+                }
+                for (name, definition) in by_name(&object.defs) {
+                    self.schema(
+                        pointer_child(&pointer_child(&pointer, "$defs"), name),
+                        definition,
+                    );
+                }
+            }
+            Component::Array(array) => self.schema(pointer_child(&pointer, "items"), &array.items),
+            Component::OneOf(one_of) => self.schema_items(&pointer, "oneOf", &one_of.items),
+            Component::AllOf(all_of) => self.schema_items(&pointer, "allOf", &all_of.items),
+            Component::AnyOf(any_of) => self.schema_items(&pointer, "anyOf", &any_of.items),
+            Component::Property(_) => (),
+        }
+    }
+
+    fn schema_items(&mut self, pointer: &str, keyword: &str, items: &'a [Component]) {
+        for (index, item) in items.iter().enumerate() {
+            self.schema(pointer_child(&pointer_child(pointer, keyword), index), item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::{
+        path::{OperationBuilder, ParameterBuilder, ParameterIn, PathItemBuilder},
+        response::ResponseBuilder,
+        security::{ApiKey, ApiKeyValue, SecurityScheme},
+        ComponentType, ComponentsBuilder, ContentBuilder, Info, ObjectBuilder, OpenApiBuilder,
+        PathItemType, PathsBuilder, Property, Tag,
+    };
+
+    use super::*;
+
+    fn get_operation(operation_id: &str) -> OperationBuilder {
+        OperationBuilder::new()
+            .operation_id(Some(operation_id))
+            .response("200", ResponseBuilder::new().description("success"))
+    }
+
+    fn path_parameter(name: &str) -> Parameter {
+        ParameterBuilder::new()
+            .name(name)
+            .parameter_in(ParameterIn::Path)
+            .build()
+    }
+
+    fn pointers(openapi: &OpenApi) -> Vec<(Severity, String)> {
+        match openapi.validate() {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors
+                .into_iter()
+                .map(|error| (error.severity(), error.pointer().to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn validate_valid_openapi() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(
+                PathsBuilder::new().path(
+                    "/pets/{id}",
+                    PathItem::new(
+                        PathItemType::Get,
+                        get_operation("getPet")
+                            .parameter(path_parameter("id"))
+                            .response(
+                                "404",
+                                ResponseBuilder::new().description("not found").content(
+                                    "application/json",
+                                    ContentBuilder::new()
+                                        .schema(Ref::from_component_name("Error"))
+                                        .build(),
+                                ),
+                            )
+                            .security(SecurityRequirement::new("api_key", [] as [&str; 0])),
+                    ),
+                ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .component("Error", Property::new(ComponentType::String))
+                    .security_scheme(
+                        "api_key",
+                        SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("api_key"))),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(pointers(&openapi), []);
+    }
+
+    #[test]
+    fn validate_reports_broken_openapi() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/pets/{id}",
+                        PathItemBuilder::new()
+                            .parameters(Some([path_parameter("petId")]))
+                            .operation(PathItemType::Get, get_operation("getPet"))
+                            .operation(
+                                PathItemType::Delete,
+                                OperationBuilder::new()
+                                    .operation_id(Some("getPet"))
+                                    .parameter(path_parameter("id")),
+                            )
+                            .build(),
+                    )
+                    .path(
+                        "/pets",
+                        PathItem::new(
+                            PathItemType::Post,
+                            get_operation("addPet")
+                                .response(
+                                    "400",
+                                    ResponseBuilder::new().description("bad request").content(
+                                        "application/json",
+                                        ContentBuilder::new()
+                                            .schema(ObjectBuilder::new().property(
+                                                "error",
+                                                Ref::from_component_name("Error"),
+                                            ))
+                                            .build(),
+                                    ),
+                                )
+                                .security(SecurityRequirement::new("oauth", ["read"])),
+                        ),
+                    ),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .component("Pet", Ref::new("#/components/responses/Pet"))
+                    .build(),
+            ))
+            .tags(Some([Tag::new("pet"), Tag::new("pet")]))
+            .build();
+
+        assert_eq!(
+            pointers(&openapi),
+            [
+                (
+                    Severity::Error,
+                    "/paths/~1pets~1{id}/delete/operationId".to_string()
+                ),
+                (
+                    Severity::Error,
+                    "/paths/~1pets/post/responses/400/content/application~1json/schema/properties/error"
+                        .to_string()
+                ),
+                (
+                    Severity::Error,
+                    "/paths/~1pets/post/security/0/oauth".to_string()
+                ),
+                (
+                    Severity::Error,
+                    "/paths/~1pets~1{id}/parameters/0".to_string()
+                ),
+                (Severity::Error, "/paths/~1pets~1{id}/get".to_string()),
+                (Severity::Warning, "/paths/~1pets~1{id}/delete/responses".to_string()),
+                (Severity::Error, "/components/schemas/Pet".to_string()),
+                (Severity::Error, "/tags/1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_reports_component_problems_ordered_by_name() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .component("Pet", Ref::new("#/components/responses/Pet"))
+                    .component("Owner", Ref::from_component_name("Person"))
+                    .component("Category", Ref::from_component_name("Group"))
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(
+            pointers(&openapi),
+            [
+                (Severity::Error, "/components/schemas/Category".to_string()),
+                (Severity::Error, "/components/schemas/Owner".to_string()),
+                (Severity::Error, "/components/schemas/Pet".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_resolves_escaped_parameter_reference() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(PathsBuilder::new().path(
+                "/pets/{id}",
+                PathItem::new(
+                    PathItemType::Get,
+                    get_operation("getPet").parameter(Ref::new("#/components/parameters/pet~1id")),
+                ),
+            ))
+            .components(Some(
+                ComponentsBuilder::new()
+                    .parameter("pet/id", path_parameter("id"))
+                    .build(),
+            ))
+            .build();
+
+        assert_eq!(pointers(&openapi), []);
+    }
+
+    #[test]
+    fn validation_error_display() {
+        let openapi = OpenApiBuilder::new()
+            .info(Info::new("pet api", "1.0.0"))
+            .paths(PathsBuilder::new().path(
+                "/pets/{id}",
+                PathItem::new(PathItemType::Get, get_operation("getPet")),
+            ))
+            .build();
+
+        let errors = openapi.validate().unwrap_err();
+
+        assert_eq!(
+            errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["error at /paths/~1pets~1{id}/get: path template parameter `id` of `/pets/{id}` is not defined as path parameter"]
+        );
+    }
+}
@@ -0,0 +1,360 @@
+//! Implements serialization of [`OpenApi`] documents according to the [`OpenApiVersion`][version]
+//! of the document.
+//!
+//! * OpenAPI 3.0 documents are serialized from a copy of the document where the fields only
+//!   available in OpenAPI 3.1 are cleared, see [`to_openapi_30`].
+//! * OpenAPI 3.1 documents are first serialized to [`Value`] and OpenAPI 3.0 keywords of the
+//!   schemas within are then translated to JSON Schema keywords, see [`translate_to_openapi_31`].
+//!
+//! [version]: super::OpenApiVersion
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+
+#[cfg(feature = "serde_json")]
+use super::json_schema::{for_each_subschema_mut, translate_keywords};
+use super::{
+    visit_mut::{self, VisitMut},
+    Info, License, Object, OpenApi, Property,
+};
+
+/// Get copy of the [`OpenApi`] document without the fields only available in OpenAPI 3.1.
+pub(super) fn to_openapi_30(openapi: &OpenApi) -> OpenApi {
+    let mut openapi = openapi.clone();
+    OpenApi30.visit_openapi_mut(&mut openapi);
+
+    openapi
+}
+
+/// Clears fields only available in OpenAPI 3.1 from visited objects.
+struct OpenApi30;
+
+impl VisitMut for OpenApi30 {
+    fn visit_openapi_mut(&mut self, openapi: &mut OpenApi) {
+        openapi.json_schema_dialect = None;
+        openapi.webhooks.clear();
+        visit_mut::visit_openapi_mut(self, openapi)
+    }
+
+    fn visit_info_mut(&mut self, info: &mut Info) {
+        info.summary = None;
+        visit_mut::visit_info_mut(self, info)
+    }
+
+    fn visit_license_mut(&mut self, license: &mut License) {
+        license.identifier = None;
+        visit_mut::visit_license_mut(self, license)
+    }
+
+    fn visit_property_mut(&mut self, property: &mut Property) {
+        property.const_value = None;
+        visit_mut::visit_property_mut(self, property)
+    }
+
+    fn visit_object_mut(&mut self, object: &mut Object) {
+        object.defs.clear();
+        visit_mut::visit_object_mut(self, object)
+    }
+}
+
+/// Translate OpenAPI 3.0 keywords of all schemas within serialized [`OpenApi`] document to
+/// JSON Schema keywords used by OpenAPI 3.1. Only values known to be schemas are translated so
+/// that e.g. examples and extensions are left as is.
+#[cfg(feature = "serde_json")]
+pub(super) fn translate_to_openapi_31(document: &mut Value) {
+    entries(document, "paths").for_each(translate_path_item);
+    entries(document, "webhooks").for_each(translate_path_item);
+
+    if let Some(components) = document.get_mut("components") {
+        entries(components, "schemas").for_each(translate_schema);
+        entries(components, "responses").for_each(translate_response);
+        entries(components, "parameters").for_each(translate_schema_field);
+        entries(components, "requestBodies").for_each(translate_content);
+        entries(components, "headers").for_each(translate_schema_field);
+        entries(components, "callbacks").for_each(translate_callback);
+    }
+}
+
+/// Get values of the object or items of the array of the given field of the `value`.
+#[cfg(feature = "serde_json")]
+fn entries<'a>(value: &'a mut Value, field: &str) -> Box<dyn Iterator<Item = &'a mut Value> + 'a> {
+    match value.get_mut(field) {
+        Some(Value::Object(entries)) => Box::new(entries.values_mut()),
+        Some(Value::Array(entries)) => Box::new(entries.iter_mut()),
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+#[cfg(feature = "serde_json")]
+fn translate_path_item(path_item: &mut Value) {
+    entries(path_item, "parameters").for_each(translate_schema_field);
+
+    for method in [
+        "get", "post", "put", "delete", "options", "head", "patch", "trace", "connect",
+    ] {
+        if let Some(operation) = path_item.get_mut(method) {
+            translate_operation(operation);
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+fn translate_operation(operation: &mut Value) {
+    entries(operation, "parameters").for_each(translate_schema_field);
+    if let Some(request_body) = operation.get_mut("requestBody") {
+        translate_content(request_body);
+    }
+    entries(operation, "responses").for_each(translate_response);
+    entries(operation, "callbacks").for_each(translate_callback);
+}
+
+#[cfg(feature = "serde_json")]
+fn translate_callback(callback: &mut Value) {
+    if let Some(path_items) = callback.as_object_mut() {
+        path_items.values_mut().for_each(translate_path_item);
+    }
+}
+
+#[cfg(feature = "serde_json")]
+fn translate_response(response: &mut Value) {
+    entries(response, "headers").for_each(translate_schema_field);
+    translate_content(response);
+}
+
+/// Translate schemas of the media types of the _`content`_ field of the `value`.
+#[cfg(feature = "serde_json")]
+fn translate_content(value: &mut Value) {
+    entries(value, "content").for_each(translate_schema_field);
+}
+
+/// Translate schema of the _`schema`_ field of the `value`. This is shared by parameters,
+/// headers and media types which all define their schema with _`schema`_ field.
+#[cfg(feature = "serde_json")]
+fn translate_schema_field(value: &mut Value) {
+    if let Some(schema) = value.get_mut("schema") {
+        translate_schema(schema);
+    }
+    translate_content(value);
+}
+
+#[cfg(feature = "serde_json")]
+fn translate_schema(schema: &mut Value) {
+    for_each_subschema_mut(schema, &mut translate_keywords);
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::openapi::{
+        path::OperationBuilder, Component, ComponentType, ComponentsBuilder, InfoBuilder,
+        LicenseBuilder, ObjectBuilder, OpenApiBuilder, PathItem, PathItemType, PropertyBuilder,
+    };
+
+    #[test]
+    fn to_openapi_30_clears_openapi_31_fields() {
+        let openapi = OpenApiBuilder::new()
+            .json_schema_dialect(Some("https://spec.openapis.org/oas/3.1/dialect/base"))
+            .webhook(
+                "petCreated",
+                PathItem::new(PathItemType::Post, OperationBuilder::new()),
+            )
+            .info(
+                InfoBuilder::new()
+                    .summary(Some("Api for pets"))
+                    .license(Some(LicenseBuilder::new().identifier(Some("MIT")).build()))
+                    .build(),
+            )
+            .components(Some(
+                ComponentsBuilder::new()
+                    .component(
+                        "Pet",
+                        ObjectBuilder::new()
+                            .property(
+                                "kind",
+                                PropertyBuilder::new()
+                                    .component_type(ComponentType::String)
+                                    .const_value(Some(json!("pet"))),
+                            )
+                            .def("Owner", ObjectBuilder::new()),
+                    )
+                    .build(),
+            ))
+            .build();
+
+        let openapi = to_openapi_30(&openapi);
+
+        assert_eq!(openapi.json_schema_dialect, None);
+        assert!(openapi.webhooks.is_empty());
+        assert_eq!(openapi.info.summary, None);
+        assert_eq!(openapi.info.license.unwrap().identifier, None);
+        let pet = match &openapi.components.unwrap().schemas["Pet"] {
+            Component::Object(pet) => pet.clone(),
+            _ => panic!("Pet is not an object"),
+        };
+        assert!(pet.defs.is_empty());
+        match &pet.properties["kind"] {
+            Component::Property(kind) => assert_eq!(kind.const_value, None),
+            _ => panic!("kind is not a property"),
+        }
+    }
+
+    fn translate_schema_of(schema: Value) -> Value {
+        let mut document = json!({ "components": { "schemas": { "Pet": schema } } });
+        translate_to_openapi_31(&mut document);
+
+        document["components"]["schemas"]["Pet"].take()
+    }
+
+    #[test]
+    fn translate_nullable_type_to_type_array() {
+        assert_eq!(
+            translate_schema_of(json!({ "type": "string", "nullable": true })),
+            json!({ "type": ["string", "null"] })
+        );
+    }
+
+    #[test]
+    fn translate_nullable_without_type_to_any_of_null() {
+        assert_eq!(
+            translate_schema_of(json!({ "$ref": "#/components/schemas/Owner", "nullable": true })),
+            json!({ "anyOf": [{ "$ref": "#/components/schemas/Owner" }, { "type": "null" }] })
+        );
+    }
+
+    #[test]
+    fn translate_not_nullable_removes_nullable() {
+        assert_eq!(
+            translate_schema_of(json!({ "type": "string", "nullable": false })),
+            json!({ "type": "string" })
+        );
+    }
+
+    #[test]
+    fn translate_example_to_examples() {
+        assert_eq!(
+            translate_schema_of(json!({ "type": "string", "example": "bark" })),
+            json!({ "type": "string", "examples": ["bark"] })
+        );
+    }
+
+    #[test]
+    fn translate_exclusive_minimum_to_numeric_limit() {
+        assert_eq!(
+            translate_schema_of(
+                json!({ "type": "integer", "minimum": 1, "exclusiveMinimum": true })
+            ),
+            json!({ "type": "integer", "exclusiveMinimum": 1 })
+        );
+        assert_eq!(
+            translate_schema_of(
+                json!({ "type": "integer", "minimum": 1, "exclusiveMinimum": false })
+            ),
+            json!({ "type": "integer", "minimum": 1 })
+        );
+    }
+
+    #[test]
+    fn translate_exclusive_maximum_to_numeric_limit() {
+        assert_eq!(
+            translate_schema_of(
+                json!({ "type": "integer", "maximum": 9, "exclusiveMaximum": true })
+            ),
+            json!({ "type": "integer", "exclusiveMaximum": 9 })
+        );
+        assert_eq!(
+            translate_schema_of(
+                json!({ "type": "integer", "maximum": 9, "exclusiveMaximum": false })
+            ),
+            json!({ "type": "integer", "maximum": 9 })
+        );
+    }
+
+    #[test]
+    fn translate_subschemas() {
+        let nullable = || json!({ "type": "string", "nullable": true });
+        let translated = || json!({ "type": ["string", "null"] });
+
+        assert_eq!(
+            translate_schema_of(json!({
+                "type": "object",
+                "properties": { "name": nullable() },
+                "additionalProperties": nullable(),
+                "$defs": { "Name": nullable() },
+                "oneOf": [{ "type": "array", "items": nullable() }],
+            })),
+            json!({
+                "type": "object",
+                "properties": { "name": translated() },
+                "additionalProperties": translated(),
+                "$defs": { "Name": translated() },
+                "oneOf": [{ "type": "array", "items": translated() }],
+            })
+        );
+    }
+
+    #[test]
+    fn translate_schemas_of_paths_webhooks_and_components() {
+        let nullable = || json!({ "schema": { "type": "string", "nullable": true } });
+        let translated = || json!({ "schema": { "type": ["string", "null"] } });
+        let path_item = |media_type: Value| {
+            json!({
+                "parameters": [media_type],
+                "post": {
+                    "parameters": [media_type],
+                    "requestBody": { "content": { "application/json": media_type } },
+                    "responses": {
+                        "200": {
+                            "headers": { "x-rate-limit": media_type },
+                            "content": { "application/json": media_type }
+                        }
+                    },
+                    "callbacks": { "onEvent": { "{$request.body#/url}": {
+                        "post": { "parameters": [media_type] }
+                    } } }
+                }
+            })
+        };
+        let document = |media_type: Value| {
+            json!({
+                "paths": { "/pets": path_item(media_type.clone()) },
+                "webhooks": { "newPet": path_item(media_type.clone()) },
+                "components": {
+                    "schemas": { "Name": media_type["schema"] },
+                    "responses": { "Pet": { "content": { "application/json": media_type } } },
+                    "parameters": { "name": media_type },
+                    "requestBodies": { "Pet": { "content": { "application/json": media_type } } },
+                    "headers": { "x-rate-limit": media_type },
+                    "callbacks": { "onEvent": { "{$request.body#/url}": path_item(media_type.clone()) } }
+                }
+            })
+        };
+
+        let mut openapi = document(nullable());
+        translate_to_openapi_31(&mut openapi);
+
+        assert_eq!(openapi, document(translated()));
+    }
+
+    #[test]
+    fn translate_leaves_examples_and_extensions_as_is() {
+        let media_type = json!({
+            "schema": { "type": "string" },
+            "example": { "nullable": true, "example": "bark" },
+            "examples": { "Bark": { "value": { "nullable": true } } },
+            "x-schema": { "nullable": true }
+        });
+        let mut openapi = json!({
+            "paths": { "/pets": { "get": { "responses": { "200": {
+                "content": { "application/json": media_type }
+            } } } } }
+        });
+        translate_to_openapi_31(&mut openapi);
+
+        assert_eq!(
+            openapi["paths"]["/pets"]["get"]["responses"]["200"]["content"]["application/json"],
+            media_type
+        );
+    }
+}
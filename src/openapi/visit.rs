@@ -0,0 +1,747 @@
+//! Implements [`Visit`] trait for traversing [`OpenApi`] document by shared reference.
+//!
+//! Each method of the [`Visit`] trait has a default implementation calling the free function of
+//! the same name in this module which visits the children of the type recursively. Implementors
+//! only override methods of the types they are interested in and call the free function from the
+//! override to continue the traversal to the children.
+//!
+//! See [`visit_mut`][super::visit_mut] for traversing the document by mutable reference.
+//!
+//! # Examples
+//!
+//! Collect operation ids of all _`GET`_ operations including operations of webhooks and callbacks.
+//! ```rust
+//! # use utoipa::openapi::{
+//! #     path::{Operation, OperationBuilder, PathItemBuilder, PathItemType},
+//! #     visit::{self, Visit},
+//! #     OpenApiBuilder, PathsBuilder,
+//! # };
+//! #[derive(Default)]
+//! struct OperationIds<'a>(Vec<&'a str>);
+//!
+//! impl<'a> Visit<'a> for OperationIds<'a> {
+//!     fn visit_operation(&mut self, path_item_type: &'a PathItemType, operation: &'a Operation) {
+//!         if *path_item_type == PathItemType::Get {
+//!             self.0.extend(operation.operation_id.as_deref());
+//!         }
+//!         visit::visit_operation(self, path_item_type, operation);
+//!     }
+//! }
+//!
+//! let openapi = OpenApiBuilder::new()
+//!     .paths(PathsBuilder::new().path(
+//!         "/pets",
+//!         PathItemBuilder::new()
+//!             .operation(PathItemType::Get, OperationBuilder::new().operation_id(Some("list_pets")).build())
+//!             .operation(PathItemType::Post, OperationBuilder::new().operation_id(Some("add_pet")).build())
+//!             .build(),
+//!     ))
+//!     .build();
+//!
+//! let mut operation_ids = OperationIds::default();
+//! operation_ids.visit_openapi(&openapi);
+//!
+//! assert_eq!(operation_ids.0, ["list_pets"]);
+//! ```
+use super::{
+    extensions::Extensions,
+    info::{Contact, License},
+    path::{Operation, Parameter, PathItem, PathItemType},
+    request_body::RequestBody,
+    response::{Response, Responses},
+    schema::{
+        AdditionalProperties, AllOf, AnyOf, Array, Component, Components, Object, OneOf, Property,
+        Ref,
+    },
+    security::{Flow, SecurityRequirement, SecurityScheme},
+    server::ServerVariable,
+    xml::Xml,
+    Callback, Content, Example, ExternalDocs, Header, Info, Link, OpenApi, Paths, RefOr, Server,
+    Tag,
+};
+
+/// Visitor of [`OpenApi`] document and its children by shared reference.
+///
+/// See [module level][self] documentation for more details.
+pub trait Visit<'a> {
+    fn visit_openapi(&mut self, openapi: &'a OpenApi) {
+        visit_openapi(self, openapi)
+    }
+
+    fn visit_info(&mut self, info: &'a Info) {
+        visit_info(self, info)
+    }
+
+    fn visit_contact(&mut self, contact: &'a Contact) {
+        visit_contact(self, contact)
+    }
+
+    fn visit_license(&mut self, license: &'a License) {
+        visit_license(self, license)
+    }
+
+    fn visit_server(&mut self, server: &'a Server) {
+        visit_server(self, server)
+    }
+
+    fn visit_server_variable(&mut self, server_variable: &'a ServerVariable) {
+        visit_server_variable(self, server_variable)
+    }
+
+    fn visit_paths(&mut self, paths: &'a Paths) {
+        visit_paths(self, paths)
+    }
+
+    fn visit_path_item(&mut self, path: &'a str, path_item: &'a PathItem) {
+        visit_path_item(self, path, path_item)
+    }
+
+    fn visit_operation(&mut self, path_item_type: &'a PathItemType, operation: &'a Operation) {
+        visit_operation(self, path_item_type, operation)
+    }
+
+    fn visit_parameter(&mut self, parameter: &'a Parameter) {
+        visit_parameter(self, parameter)
+    }
+
+    fn visit_request_body(&mut self, request_body: &'a RequestBody) {
+        visit_request_body(self, request_body)
+    }
+
+    fn visit_responses(&mut self, responses: &'a Responses) {
+        visit_responses(self, responses)
+    }
+
+    fn visit_response(&mut self, response: &'a Response) {
+        visit_response(self, response)
+    }
+
+    fn visit_header(&mut self, header: &'a Header) {
+        visit_header(self, header)
+    }
+
+    fn visit_content(&mut self, content: &'a Content) {
+        visit_content(self, content)
+    }
+
+    fn visit_example(&mut self, example: &'a Example) {
+        visit_example(self, example)
+    }
+
+    fn visit_link(&mut self, link: &'a Link) {
+        visit_link(self, link)
+    }
+
+    fn visit_callback(&mut self, callback: &'a Callback) {
+        visit_callback(self, callback)
+    }
+
+    fn visit_components(&mut self, components: &'a Components) {
+        visit_components(self, components)
+    }
+
+    fn visit_schema(&mut self, name: &'a str, schema: &'a Component) {
+        visit_schema(self, name, schema)
+    }
+
+    fn visit_component(&mut self, component: &'a Component) {
+        visit_component(self, component)
+    }
+
+    fn visit_property(&mut self, property: &'a Property) {
+        visit_property(self, property)
+    }
+
+    fn visit_object(&mut self, object: &'a Object) {
+        visit_object(self, object)
+    }
+
+    fn visit_array(&mut self, array: &'a Array) {
+        visit_array(self, array)
+    }
+
+    fn visit_one_of(&mut self, one_of: &'a OneOf) {
+        visit_one_of(self, one_of)
+    }
+
+    fn visit_all_of(&mut self, all_of: &'a AllOf) {
+        visit_all_of(self, all_of)
+    }
+
+    fn visit_any_of(&mut self, any_of: &'a AnyOf) {
+        visit_any_of(self, any_of)
+    }
+
+    fn visit_ref(&mut self, reference: &'a Ref) {
+        visit_ref(self, reference)
+    }
+
+    fn visit_xml(&mut self, xml: &'a Xml) {
+        visit_xml(self, xml)
+    }
+
+    fn visit_security_scheme(&mut self, name: &'a str, security_scheme: &'a SecurityScheme) {
+        visit_security_scheme(self, name, security_scheme)
+    }
+
+    fn visit_flow(&mut self, flow: &'a Flow) {
+        visit_flow(self, flow)
+    }
+
+    fn visit_security_requirement(&mut self, security_requirement: &'a SecurityRequirement) {
+        visit_security_requirement(self, security_requirement)
+    }
+
+    fn visit_tag(&mut self, tag: &'a Tag) {
+        visit_tag(self, tag)
+    }
+
+    fn visit_external_docs(&mut self, external_docs: &'a ExternalDocs) {
+        visit_external_docs(self, external_docs)
+    }
+
+    fn visit_extensions(&mut self, extensions: &'a Extensions) {
+        visit_extensions(self, extensions)
+    }
+}
+
+/// Visit either the [`Ref`] or the value of [`RefOr`] with given `visit` function.
+fn visit_ref_or<'a, V, T>(visitor: &mut V, ref_or: &'a RefOr<T>, visit: fn(&mut V, &'a T))
+where
+    V: Visit<'a> + ?Sized,
+{
+    match ref_or {
+        RefOr::Ref(reference) => visitor.visit_ref(reference),
+        RefOr::T(value) => visit(visitor, value),
+    }
+}
+
+pub fn visit_openapi<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, openapi: &'a OpenApi) {
+    visitor.visit_info(&openapi.info);
+    openapi
+        .servers
+        .iter()
+        .flatten()
+        .for_each(|server| visitor.visit_server(server));
+    visitor.visit_paths(&openapi.paths);
+    openapi
+        .webhooks
+        .iter()
+        .for_each(|(name, path_item)| visitor.visit_path_item(name, path_item));
+    if let Some(components) = &openapi.components {
+        visitor.visit_components(components);
+    }
+    openapi
+        .security
+        .iter()
+        .flatten()
+        .for_each(|requirement| visitor.visit_security_requirement(requirement));
+    openapi
+        .tags
+        .iter()
+        .flatten()
+        .for_each(|tag| visitor.visit_tag(tag));
+    if let Some(external_docs) = &openapi.external_docs {
+        visitor.visit_external_docs(external_docs);
+    }
+    if let Some(extensions) = &openapi.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_info<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, info: &'a Info) {
+    if let Some(contact) = &info.contact {
+        visitor.visit_contact(contact);
+    }
+    if let Some(license) = &info.license {
+        visitor.visit_license(license);
+    }
+    if let Some(extensions) = &info.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_contact<'a, V: Visit<'a> + ?Sized>(_visitor: &mut V, _contact: &'a Contact) {}
+
+pub fn visit_license<'a, V: Visit<'a> + ?Sized>(_visitor: &mut V, _license: &'a License) {}
+
+pub fn visit_server<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, server: &'a Server) {
+    server
+        .variables
+        .iter()
+        .flat_map(|variables| variables.values())
+        .for_each(|variable| visitor.visit_server_variable(variable));
+}
+
+pub fn visit_server_variable<'a, V: Visit<'a> + ?Sized>(
+    _visitor: &mut V,
+    _server_variable: &'a ServerVariable,
+) {
+}
+
+pub fn visit_paths<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, paths: &'a Paths) {
+    paths
+        .paths
+        .iter()
+        .for_each(|(path, path_item)| visitor.visit_path_item(path, path_item));
+}
+
+pub fn visit_path_item<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    _path: &'a str,
+    path_item: &'a PathItem,
+) {
+    path_item
+        .servers
+        .iter()
+        .flatten()
+        .for_each(|server| visitor.visit_server(server));
+    path_item
+        .parameters
+        .iter()
+        .flatten()
+        .for_each(|parameter| visit_ref_or(visitor, parameter, V::visit_parameter));
+    path_item
+        .operations
+        .iter()
+        .for_each(|(path_item_type, operation)| visitor.visit_operation(path_item_type, operation));
+    if let Some(extensions) = &path_item.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_operation<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    _path_item_type: &'a PathItemType,
+    operation: &'a Operation,
+) {
+    if let Some(external_docs) = &operation.external_docs {
+        visitor.visit_external_docs(external_docs);
+    }
+    operation
+        .parameters
+        .iter()
+        .flatten()
+        .for_each(|parameter| visit_ref_or(visitor, parameter, V::visit_parameter));
+    if let Some(request_body) = &operation.request_body {
+        visitor.visit_request_body(request_body);
+    }
+    visitor.visit_responses(&operation.responses);
+    operation
+        .callbacks
+        .iter()
+        .flat_map(|callbacks| callbacks.values())
+        .for_each(|callback| visit_ref_or(visitor, callback, V::visit_callback));
+    operation
+        .security
+        .iter()
+        .flatten()
+        .for_each(|requirement| visitor.visit_security_requirement(requirement));
+    operation
+        .servers
+        .iter()
+        .flatten()
+        .for_each(|server| visitor.visit_server(server));
+    if let Some(extensions) = &operation.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_parameter<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, parameter: &'a Parameter) {
+    if let Some(schema) = &parameter.schema {
+        visitor.visit_component(schema);
+    }
+    parameter
+        .examples
+        .values()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example));
+    if let Some(extensions) = &parameter.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_request_body<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    request_body: &'a RequestBody,
+) {
+    request_body
+        .content
+        .values()
+        .for_each(|content| visitor.visit_content(content));
+}
+
+pub fn visit_responses<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, responses: &'a Responses) {
+    responses
+        .responses
+        .values()
+        .for_each(|response| visit_ref_or(visitor, response, V::visit_response));
+}
+
+pub fn visit_response<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, response: &'a Response) {
+    response
+        .headers
+        .values()
+        .for_each(|header| visitor.visit_header(header));
+    response
+        .content
+        .values()
+        .for_each(|content| visitor.visit_content(content));
+    response
+        .links
+        .values()
+        .for_each(|link| visit_ref_or(visitor, link, V::visit_link));
+    if let Some(extensions) = &response.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_header<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, header: &'a Header) {
+    visitor.visit_component(&header.schema);
+    header
+        .examples
+        .values()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example));
+}
+
+pub fn visit_content<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, content: &'a Content) {
+    visitor.visit_component(&content.schema);
+    content
+        .examples
+        .values()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example));
+}
+
+pub fn visit_example<'a, V: Visit<'a> + ?Sized>(_visitor: &mut V, _example: &'a Example) {}
+
+pub fn visit_link<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, link: &'a Link) {
+    if let Some(server) = &link.server {
+        visitor.visit_server(server);
+    }
+}
+
+pub fn visit_callback<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, callback: &'a Callback) {
+    callback
+        .callbacks
+        .iter()
+        .for_each(|(expression, path_item)| visitor.visit_path_item(expression, path_item));
+}
+
+pub fn visit_components<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, components: &'a Components) {
+    components
+        .schemas
+        .iter()
+        .for_each(|(name, schema)| visitor.visit_schema(name, schema));
+    components
+        .security_schemes
+        .iter()
+        .for_each(|(name, security_scheme)| visitor.visit_security_scheme(name, security_scheme));
+    components
+        .examples
+        .values()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example));
+    components
+        .responses
+        .values()
+        .for_each(|response| visit_ref_or(visitor, response, V::visit_response));
+    components
+        .parameters
+        .values()
+        .for_each(|parameter| visit_ref_or(visitor, parameter, V::visit_parameter));
+    components
+        .request_bodies
+        .values()
+        .for_each(|request_body| visit_ref_or(visitor, request_body, V::visit_request_body));
+    components
+        .headers
+        .values()
+        .for_each(|header| visit_ref_or(visitor, header, V::visit_header));
+    components
+        .links
+        .values()
+        .for_each(|link| visit_ref_or(visitor, link, V::visit_link));
+    components
+        .callbacks
+        .values()
+        .for_each(|callback| visit_ref_or(visitor, callback, V::visit_callback));
+}
+
+pub fn visit_schema<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    _name: &'a str,
+    schema: &'a Component,
+) {
+    visitor.visit_component(schema);
+}
+
+pub fn visit_component<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, component: &'a Component) {
+    match component {
+        Component::Property(property) => visitor.visit_property(property),
+        Component::Ref(reference) => visitor.visit_ref(reference),
+        Component::Object(object) => visitor.visit_object(object),
+        Component::Array(array) => visitor.visit_array(array),
+        Component::OneOf(one_of) => visitor.visit_one_of(one_of),
+        Component::AllOf(all_of) => visitor.visit_all_of(all_of),
+        Component::AnyOf(any_of) => visitor.visit_any_of(any_of),
+    }
+}
+
+pub fn visit_property<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, property: &'a Property) {
+    if let Some(xml) = &property.xml {
+        visitor.visit_xml(xml);
+    }
+    if let Some(extensions) = &property.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_object<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, object: &'a Object) {
+    object
+        .properties
+        .values()
+        .for_each(|property| visitor.visit_component(property));
+    if let Some(xml) = &object.xml {
+        visitor.visit_xml(xml);
+    }
+    if let Some(AdditionalProperties::Component(additional_properties)) =
+        object.additional_properties.as_deref()
+    {
+        visitor.visit_component(additional_properties);
+    }
+    object
+        .defs
+        .values()
+        .for_each(|def| visitor.visit_component(def));
+    if let Some(extensions) = &object.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_array<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, array: &'a Array) {
+    visitor.visit_component(&array.items);
+    if let Some(xml) = &array.xml {
+        visitor.visit_xml(xml);
+    }
+    if let Some(extensions) = &array.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_one_of<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, one_of: &'a OneOf) {
+    one_of
+        .items
+        .iter()
+        .for_each(|item| visitor.visit_component(item));
+    if let Some(extensions) = &one_of.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_all_of<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, all_of: &'a AllOf) {
+    all_of
+        .items
+        .iter()
+        .for_each(|item| visitor.visit_component(item));
+    if let Some(extensions) = &all_of.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_any_of<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, any_of: &'a AnyOf) {
+    any_of
+        .items
+        .iter()
+        .for_each(|item| visitor.visit_component(item));
+    if let Some(extensions) = &any_of.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_ref<'a, V: Visit<'a> + ?Sized>(_visitor: &mut V, _reference: &'a Ref) {}
+
+pub fn visit_xml<'a, V: Visit<'a> + ?Sized>(_visitor: &mut V, _xml: &'a Xml) {}
+
+pub fn visit_security_scheme<'a, V: Visit<'a> + ?Sized>(
+    visitor: &mut V,
+    _name: &'a str,
+    security_scheme: &'a SecurityScheme,
+) {
+    if let SecurityScheme::OAuth2(oauth2) = security_scheme {
+        oauth2
+            .flows
+            .values()
+            .for_each(|flow| visitor.visit_flow(flow));
+    }
+    if let Some(extensions) = security_scheme.extensions() {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_flow<'a, V: Visit<'a> + ?Sized>(_visitor: &mut V, _flow: &'a Flow) {}
+
+pub fn visit_security_requirement<'a, V: Visit<'a> + ?Sized>(
+    _visitor: &mut V,
+    _security_requirement: &'a SecurityRequirement,
+) {
+}
+
+pub fn visit_tag<'a, V: Visit<'a> + ?Sized>(visitor: &mut V, tag: &'a Tag) {
+    if let Some(external_docs) = &tag.external_docs {
+        visitor.visit_external_docs(external_docs);
+    }
+    if let Some(extensions) = &tag.extensions {
+        visitor.visit_extensions(extensions);
+    }
+}
+
+pub fn visit_external_docs<'a, V: Visit<'a> + ?Sized>(
+    _visitor: &mut V,
+    _external_docs: &'a ExternalDocs,
+) {
+}
+
+pub fn visit_extensions<'a, V: Visit<'a> + ?Sized>(_visitor: &mut V, _extensions: &'a Extensions) {}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct References<'a>(BTreeMap<&'a str, usize>);
+
+    impl<'a> Visit<'a> for References<'a> {
+        fn visit_ref(&mut self, reference: &'a Ref) {
+            *self.0.entry(&reference.ref_location).or_default() += 1;
+        }
+    }
+
+    /// Records the context passed to the visitor by the default traversal.
+    #[derive(Default)]
+    struct Context<'a> {
+        path: &'a str,
+        operations: Vec<String>,
+        schemas: Vec<&'a str>,
+        security_schemes: Vec<&'a str>,
+        flows: Vec<&'a str>,
+    }
+
+    impl<'a> Visit<'a> for Context<'a> {
+        fn visit_path_item(&mut self, path: &'a str, path_item: &'a PathItem) {
+            self.path = path;
+            visit_path_item(self, path, path_item);
+        }
+
+        fn visit_operation(&mut self, path_item_type: &'a PathItemType, operation: &'a Operation) {
+            self.operations
+                .push(format!("{} {}", path_item_type.as_str(), self.path));
+            visit_operation(self, path_item_type, operation);
+        }
+
+        fn visit_schema(&mut self, name: &'a str, schema: &'a Component) {
+            self.schemas.push(name);
+            visit_schema(self, name, schema);
+        }
+
+        fn visit_security_scheme(&mut self, name: &'a str, security_scheme: &'a SecurityScheme) {
+            self.security_schemes.push(name);
+            visit_security_scheme(self, name, security_scheme);
+        }
+
+        fn visit_flow(&mut self, flow: &'a Flow) {
+            self.flows.push(match flow {
+                Flow::Implicit(_) => "implicit",
+                Flow::Password(_) => "password",
+                Flow::ClientCredentials(_) => "clientCredentials",
+                Flow::AuthorizationCode(_) => "authorizationCode",
+            });
+            visit_flow(self, flow);
+        }
+    }
+
+    fn visit_context(openapi: &OpenApi) -> Context<'_> {
+        let mut context = Context::default();
+        context.visit_openapi(openapi);
+        context.operations.sort();
+        context.schemas.sort();
+        context.security_schemes.sort();
+        context.flows.sort();
+
+        context
+    }
+
+    #[test]
+    fn visit_path_item_and_operation_with_path_and_type() {
+        let openapi = OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+
+        assert_eq!(
+            visit_context(&openapi).operations,
+            [
+                "delete /pets/{id}",
+                "get /pets",
+                "get /pets/{id}",
+                "post /pets",
+                "post {$request.body#/callbackUrl}",
+            ]
+        );
+    }
+
+    #[test]
+    fn visit_webhook_path_item_with_name() {
+        let openapi =
+            OpenApi::from_json(include_str!("testdata/petstore-features-3.1.json")).unwrap();
+
+        assert_eq!(
+            visit_context(&openapi).operations,
+            ["get /pets/{id}", "post newPet"]
+        );
+    }
+
+    #[test]
+    fn visit_schema_with_name() {
+        let openapi = OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+
+        assert_eq!(
+            visit_context(&openapi).schemas,
+            ["Cat", "Dog", "Error", "ListedPet", "NewPet", "Owner", "Pet"]
+        );
+    }
+
+    #[test]
+    fn visit_security_scheme_with_name_and_flows() {
+        let openapi = OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+        let context = visit_context(&openapi);
+
+        assert_eq!(
+            context.security_schemes,
+            ["api_key", "bearer", "openid", "petstore_auth"]
+        );
+        assert_eq!(
+            context.flows,
+            ["authorizationCode", "clientCredentials", "implicit"]
+        );
+    }
+
+    #[test]
+    fn visit_openapi_references() {
+        let openapi = OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+
+        let mut references = References::default();
+        references.visit_openapi(&openapi);
+
+        assert_eq!(
+            references.0.into_iter().collect::<Vec<_>>(),
+            [
+                ("#/components/examples/Internal", 1),
+                ("#/components/parameters/Limit", 1),
+                ("#/components/responses/Error", 4),
+                ("#/components/schemas/Cat", 1),
+                ("#/components/schemas/Dog", 1),
+                ("#/components/schemas/Error", 1),
+                ("#/components/schemas/NewPet", 1),
+                ("#/components/schemas/Owner", 2),
+                ("#/components/schemas/Pet", 6),
+            ]
+        );
+    }
+}
@@ -0,0 +1,747 @@
+//! Implements [`VisitMut`] trait for traversing [`OpenApi`] document by mutable reference.
+//!
+//! Each method of the [`VisitMut`] trait has a default implementation calling the free function of
+//! the same name in this module which visits the children of the type recursively. Implementors
+//! only override methods of the types they are interested in and call the free function from the
+//! override to continue the traversal to the children.
+//!
+//! See [`visit`][super::visit] for traversing the document by shared reference.
+//!
+//! # Examples
+//!
+//! Add _`x-request-id`_ header parameter to every operation of the [`OpenApi`] document.
+//! ```rust
+//! # use utoipa::openapi::{
+//! #     path::{Operation, OperationBuilder, ParameterBuilder, ParameterIn, PathItemBuilder, PathItemType},
+//! #     visit_mut::{self, VisitMut},
+//! #     OpenApiBuilder, PathsBuilder, RefOr,
+//! # };
+//! struct RequestIdHeader;
+//!
+//! impl VisitMut for RequestIdHeader {
+//!     fn visit_operation_mut(&mut self, path_item_type: &PathItemType, operation: &mut Operation) {
+//!         operation.parameters.get_or_insert_with(Vec::new).push(RefOr::T(
+//!             ParameterBuilder::new()
+//!                 .name("x-request-id")
+//!                 .parameter_in(ParameterIn::Header)
+//!                 .build(),
+//!         ));
+//!         visit_mut::visit_operation_mut(self, path_item_type, operation);
+//!     }
+//! }
+//!
+//! let mut openapi = OpenApiBuilder::new()
+//!     .paths(PathsBuilder::new().path(
+//!         "/pets",
+//!         PathItemBuilder::new()
+//!             .operation(PathItemType::Get, OperationBuilder::new().build())
+//!             .build(),
+//!     ))
+//!     .build();
+//!
+//! RequestIdHeader.visit_openapi_mut(&mut openapi);
+//!
+//! let operation = openapi.paths.get_path_operation("/pets", PathItemType::Get).unwrap();
+//! assert_eq!(operation.parameters.as_ref().map(Vec::len), Some(1));
+//! ```
+use super::{
+    extensions::Extensions,
+    info::{Contact, License},
+    path::{Operation, Parameter, PathItem, PathItemType},
+    request_body::RequestBody,
+    response::{Response, Responses},
+    schema::{
+        AdditionalProperties, AllOf, AnyOf, Array, Component, Components, Object, OneOf, Property,
+        Ref,
+    },
+    security::{Flow, SecurityRequirement, SecurityScheme},
+    server::ServerVariable,
+    xml::Xml,
+    Callback, Content, Example, ExternalDocs, Header, Info, Link, OpenApi, Paths, RefOr, Server,
+    Tag,
+};
+
+/// Visitor of [`OpenApi`] document and its children by mutable reference.
+///
+/// See [module level][self] documentation for more details.
+pub trait VisitMut {
+    fn visit_openapi_mut(&mut self, openapi: &mut OpenApi) {
+        visit_openapi_mut(self, openapi)
+    }
+
+    fn visit_info_mut(&mut self, info: &mut Info) {
+        visit_info_mut(self, info)
+    }
+
+    fn visit_contact_mut(&mut self, contact: &mut Contact) {
+        visit_contact_mut(self, contact)
+    }
+
+    fn visit_license_mut(&mut self, license: &mut License) {
+        visit_license_mut(self, license)
+    }
+
+    fn visit_server_mut(&mut self, server: &mut Server) {
+        visit_server_mut(self, server)
+    }
+
+    fn visit_server_variable_mut(&mut self, server_variable: &mut ServerVariable) {
+        visit_server_variable_mut(self, server_variable)
+    }
+
+    fn visit_paths_mut(&mut self, paths: &mut Paths) {
+        visit_paths_mut(self, paths)
+    }
+
+    fn visit_path_item_mut(&mut self, path: &str, path_item: &mut PathItem) {
+        visit_path_item_mut(self, path, path_item)
+    }
+
+    fn visit_operation_mut(&mut self, path_item_type: &PathItemType, operation: &mut Operation) {
+        visit_operation_mut(self, path_item_type, operation)
+    }
+
+    fn visit_parameter_mut(&mut self, parameter: &mut Parameter) {
+        visit_parameter_mut(self, parameter)
+    }
+
+    fn visit_request_body_mut(&mut self, request_body: &mut RequestBody) {
+        visit_request_body_mut(self, request_body)
+    }
+
+    fn visit_responses_mut(&mut self, responses: &mut Responses) {
+        visit_responses_mut(self, responses)
+    }
+
+    fn visit_response_mut(&mut self, response: &mut Response) {
+        visit_response_mut(self, response)
+    }
+
+    fn visit_header_mut(&mut self, header: &mut Header) {
+        visit_header_mut(self, header)
+    }
+
+    fn visit_content_mut(&mut self, content: &mut Content) {
+        visit_content_mut(self, content)
+    }
+
+    fn visit_example_mut(&mut self, example: &mut Example) {
+        visit_example_mut(self, example)
+    }
+
+    fn visit_link_mut(&mut self, link: &mut Link) {
+        visit_link_mut(self, link)
+    }
+
+    fn visit_callback_mut(&mut self, callback: &mut Callback) {
+        visit_callback_mut(self, callback)
+    }
+
+    fn visit_components_mut(&mut self, components: &mut Components) {
+        visit_components_mut(self, components)
+    }
+
+    fn visit_schema_mut(&mut self, name: &str, schema: &mut Component) {
+        visit_schema_mut(self, name, schema)
+    }
+
+    fn visit_component_mut(&mut self, component: &mut Component) {
+        visit_component_mut(self, component)
+    }
+
+    fn visit_property_mut(&mut self, property: &mut Property) {
+        visit_property_mut(self, property)
+    }
+
+    fn visit_object_mut(&mut self, object: &mut Object) {
+        visit_object_mut(self, object)
+    }
+
+    fn visit_array_mut(&mut self, array: &mut Array) {
+        visit_array_mut(self, array)
+    }
+
+    fn visit_one_of_mut(&mut self, one_of: &mut OneOf) {
+        visit_one_of_mut(self, one_of)
+    }
+
+    fn visit_all_of_mut(&mut self, all_of: &mut AllOf) {
+        visit_all_of_mut(self, all_of)
+    }
+
+    fn visit_any_of_mut(&mut self, any_of: &mut AnyOf) {
+        visit_any_of_mut(self, any_of)
+    }
+
+    fn visit_ref_mut(&mut self, reference: &mut Ref) {
+        visit_ref_mut(self, reference)
+    }
+
+    fn visit_xml_mut(&mut self, xml: &mut Xml) {
+        visit_xml_mut(self, xml)
+    }
+
+    fn visit_security_scheme_mut(&mut self, name: &str, security_scheme: &mut SecurityScheme) {
+        visit_security_scheme_mut(self, name, security_scheme)
+    }
+
+    fn visit_flow_mut(&mut self, flow: &mut Flow) {
+        visit_flow_mut(self, flow)
+    }
+
+    fn visit_security_requirement_mut(&mut self, security_requirement: &mut SecurityRequirement) {
+        visit_security_requirement_mut(self, security_requirement)
+    }
+
+    fn visit_tag_mut(&mut self, tag: &mut Tag) {
+        visit_tag_mut(self, tag)
+    }
+
+    fn visit_external_docs_mut(&mut self, external_docs: &mut ExternalDocs) {
+        visit_external_docs_mut(self, external_docs)
+    }
+
+    fn visit_extensions_mut(&mut self, extensions: &mut Extensions) {
+        visit_extensions_mut(self, extensions)
+    }
+}
+
+/// Visit either the [`Ref`] or the value of [`RefOr`] with given `visit` function.
+fn visit_ref_or<V, T>(visitor: &mut V, ref_or: &mut RefOr<T>, visit: fn(&mut V, &mut T))
+where
+    V: VisitMut + ?Sized,
+{
+    match ref_or {
+        RefOr::Ref(reference) => visitor.visit_ref_mut(reference),
+        RefOr::T(value) => visit(visitor, value),
+    }
+}
+
+pub fn visit_openapi_mut<V: VisitMut + ?Sized>(visitor: &mut V, openapi: &mut OpenApi) {
+    visitor.visit_info_mut(&mut openapi.info);
+    openapi
+        .servers
+        .iter_mut()
+        .flatten()
+        .for_each(|server| visitor.visit_server_mut(server));
+    visitor.visit_paths_mut(&mut openapi.paths);
+    openapi
+        .webhooks
+        .iter_mut()
+        .for_each(|(name, path_item)| visitor.visit_path_item_mut(name, path_item));
+    if let Some(components) = &mut openapi.components {
+        visitor.visit_components_mut(components);
+    }
+    openapi
+        .security
+        .iter_mut()
+        .flatten()
+        .for_each(|requirement| visitor.visit_security_requirement_mut(requirement));
+    openapi
+        .tags
+        .iter_mut()
+        .flatten()
+        .for_each(|tag| visitor.visit_tag_mut(tag));
+    if let Some(external_docs) = &mut openapi.external_docs {
+        visitor.visit_external_docs_mut(external_docs);
+    }
+    if let Some(extensions) = &mut openapi.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_info_mut<V: VisitMut + ?Sized>(visitor: &mut V, info: &mut Info) {
+    if let Some(contact) = &mut info.contact {
+        visitor.visit_contact_mut(contact);
+    }
+    if let Some(license) = &mut info.license {
+        visitor.visit_license_mut(license);
+    }
+    if let Some(extensions) = &mut info.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_contact_mut<V: VisitMut + ?Sized>(_visitor: &mut V, _contact: &mut Contact) {}
+
+pub fn visit_license_mut<V: VisitMut + ?Sized>(_visitor: &mut V, _license: &mut License) {}
+
+pub fn visit_server_mut<V: VisitMut + ?Sized>(visitor: &mut V, server: &mut Server) {
+    server
+        .variables
+        .iter_mut()
+        .flat_map(|variables| variables.values_mut())
+        .for_each(|variable| visitor.visit_server_variable_mut(variable));
+}
+
+pub fn visit_server_variable_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _server_variable: &mut ServerVariable,
+) {
+}
+
+pub fn visit_paths_mut<V: VisitMut + ?Sized>(visitor: &mut V, paths: &mut Paths) {
+    paths
+        .paths
+        .iter_mut()
+        .for_each(|(path, path_item)| visitor.visit_path_item_mut(path, path_item));
+}
+
+pub fn visit_path_item_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    _path: &str,
+    path_item: &mut PathItem,
+) {
+    path_item
+        .servers
+        .iter_mut()
+        .flatten()
+        .for_each(|server| visitor.visit_server_mut(server));
+    path_item
+        .parameters
+        .iter_mut()
+        .flatten()
+        .for_each(|parameter| visit_ref_or(visitor, parameter, V::visit_parameter_mut));
+    path_item
+        .operations
+        .iter_mut()
+        .for_each(|(path_item_type, operation)| {
+            visitor.visit_operation_mut(path_item_type, operation)
+        });
+    if let Some(extensions) = &mut path_item.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_operation_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    _path_item_type: &PathItemType,
+    operation: &mut Operation,
+) {
+    if let Some(external_docs) = &mut operation.external_docs {
+        visitor.visit_external_docs_mut(external_docs);
+    }
+    operation
+        .parameters
+        .iter_mut()
+        .flatten()
+        .for_each(|parameter| visit_ref_or(visitor, parameter, V::visit_parameter_mut));
+    if let Some(request_body) = &mut operation.request_body {
+        visitor.visit_request_body_mut(request_body);
+    }
+    visitor.visit_responses_mut(&mut operation.responses);
+    operation
+        .callbacks
+        .iter_mut()
+        .flat_map(|callbacks| callbacks.values_mut())
+        .for_each(|callback| visit_ref_or(visitor, callback, V::visit_callback_mut));
+    operation
+        .security
+        .iter_mut()
+        .flatten()
+        .for_each(|requirement| visitor.visit_security_requirement_mut(requirement));
+    operation
+        .servers
+        .iter_mut()
+        .flatten()
+        .for_each(|server| visitor.visit_server_mut(server));
+    if let Some(extensions) = &mut operation.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_parameter_mut<V: VisitMut + ?Sized>(visitor: &mut V, parameter: &mut Parameter) {
+    if let Some(schema) = &mut parameter.schema {
+        visitor.visit_component_mut(schema);
+    }
+    parameter
+        .examples
+        .values_mut()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example_mut));
+    if let Some(extensions) = &mut parameter.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_request_body_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    request_body: &mut RequestBody,
+) {
+    request_body
+        .content
+        .values_mut()
+        .for_each(|content| visitor.visit_content_mut(content));
+}
+
+pub fn visit_responses_mut<V: VisitMut + ?Sized>(visitor: &mut V, responses: &mut Responses) {
+    responses
+        .responses
+        .values_mut()
+        .for_each(|response| visit_ref_or(visitor, response, V::visit_response_mut));
+}
+
+pub fn visit_response_mut<V: VisitMut + ?Sized>(visitor: &mut V, response: &mut Response) {
+    response
+        .headers
+        .values_mut()
+        .for_each(|header| visitor.visit_header_mut(header));
+    response
+        .content
+        .values_mut()
+        .for_each(|content| visitor.visit_content_mut(content));
+    response
+        .links
+        .values_mut()
+        .for_each(|link| visit_ref_or(visitor, link, V::visit_link_mut));
+    if let Some(extensions) = &mut response.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_header_mut<V: VisitMut + ?Sized>(visitor: &mut V, header: &mut Header) {
+    visitor.visit_component_mut(&mut header.schema);
+    header
+        .examples
+        .values_mut()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example_mut));
+}
+
+pub fn visit_content_mut<V: VisitMut + ?Sized>(visitor: &mut V, content: &mut Content) {
+    visitor.visit_component_mut(&mut content.schema);
+    content
+        .examples
+        .values_mut()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example_mut));
+}
+
+pub fn visit_example_mut<V: VisitMut + ?Sized>(_visitor: &mut V, _example: &mut Example) {}
+
+pub fn visit_link_mut<V: VisitMut + ?Sized>(visitor: &mut V, link: &mut Link) {
+    if let Some(server) = &mut link.server {
+        visitor.visit_server_mut(server);
+    }
+}
+
+pub fn visit_callback_mut<V: VisitMut + ?Sized>(visitor: &mut V, callback: &mut Callback) {
+    callback
+        .callbacks
+        .iter_mut()
+        .for_each(|(expression, path_item)| visitor.visit_path_item_mut(expression, path_item));
+}
+
+pub fn visit_components_mut<V: VisitMut + ?Sized>(visitor: &mut V, components: &mut Components) {
+    components
+        .schemas
+        .iter_mut()
+        .for_each(|(name, schema)| visitor.visit_schema_mut(name, schema));
+    components
+        .security_schemes
+        .iter_mut()
+        .for_each(|(name, security_scheme)| {
+            visitor.visit_security_scheme_mut(name, security_scheme)
+        });
+    components
+        .examples
+        .values_mut()
+        .for_each(|example| visit_ref_or(visitor, example, V::visit_example_mut));
+    components
+        .responses
+        .values_mut()
+        .for_each(|response| visit_ref_or(visitor, response, V::visit_response_mut));
+    components
+        .parameters
+        .values_mut()
+        .for_each(|parameter| visit_ref_or(visitor, parameter, V::visit_parameter_mut));
+    components
+        .request_bodies
+        .values_mut()
+        .for_each(|request_body| visit_ref_or(visitor, request_body, V::visit_request_body_mut));
+    components
+        .headers
+        .values_mut()
+        .for_each(|header| visit_ref_or(visitor, header, V::visit_header_mut));
+    components
+        .links
+        .values_mut()
+        .for_each(|link| visit_ref_or(visitor, link, V::visit_link_mut));
+    components
+        .callbacks
+        .values_mut()
+        .for_each(|callback| visit_ref_or(visitor, callback, V::visit_callback_mut));
+}
+
+pub fn visit_schema_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    _name: &str,
+    schema: &mut Component,
+) {
+    visitor.visit_component_mut(schema);
+}
+
+pub fn visit_component_mut<V: VisitMut + ?Sized>(visitor: &mut V, component: &mut Component) {
+    match component {
+        Component::Property(property) => visitor.visit_property_mut(property),
+        Component::Ref(reference) => visitor.visit_ref_mut(reference),
+        Component::Object(object) => visitor.visit_object_mut(object),
+        Component::Array(array) => visitor.visit_array_mut(array),
+        Component::OneOf(one_of) => visitor.visit_one_of_mut(one_of),
+        Component::AllOf(all_of) => visitor.visit_all_of_mut(all_of),
+        Component::AnyOf(any_of) => visitor.visit_any_of_mut(any_of),
+    }
+}
+
+pub fn visit_property_mut<V: VisitMut + ?Sized>(visitor: &mut V, property: &mut Property) {
+    if let Some(xml) = &mut property.xml {
+        visitor.visit_xml_mut(xml);
+    }
+    if let Some(extensions) = &mut property.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_object_mut<V: VisitMut + ?Sized>(visitor: &mut V, object: &mut Object) {
+    object
+        .properties
+        .values_mut()
+        .for_each(|property| visitor.visit_component_mut(property));
+    if let Some(xml) = &mut object.xml {
+        visitor.visit_xml_mut(xml);
+    }
+    if let Some(AdditionalProperties::Component(additional_properties)) =
+        object.additional_properties.as_deref_mut()
+    {
+        visitor.visit_component_mut(additional_properties);
+    }
+    object
+        .defs
+        .values_mut()
+        .for_each(|def| visitor.visit_component_mut(def));
+    if let Some(extensions) = &mut object.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_array_mut<V: VisitMut + ?Sized>(visitor: &mut V, array: &mut Array) {
+    visitor.visit_component_mut(&mut array.items);
+    if let Some(xml) = &mut array.xml {
+        visitor.visit_xml_mut(xml);
+    }
+    if let Some(extensions) = &mut array.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_one_of_mut<V: VisitMut + ?Sized>(visitor: &mut V, one_of: &mut OneOf) {
+    one_of
+        .items
+        .iter_mut()
+        .for_each(|item| visitor.visit_component_mut(item));
+    if let Some(extensions) = &mut one_of.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_all_of_mut<V: VisitMut + ?Sized>(visitor: &mut V, all_of: &mut AllOf) {
+    all_of
+        .items
+        .iter_mut()
+        .for_each(|item| visitor.visit_component_mut(item));
+    if let Some(extensions) = &mut all_of.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_any_of_mut<V: VisitMut + ?Sized>(visitor: &mut V, any_of: &mut AnyOf) {
+    any_of
+        .items
+        .iter_mut()
+        .for_each(|item| visitor.visit_component_mut(item));
+    if let Some(extensions) = &mut any_of.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_ref_mut<V: VisitMut + ?Sized>(_visitor: &mut V, _reference: &mut Ref) {}
+
+pub fn visit_xml_mut<V: VisitMut + ?Sized>(_visitor: &mut V, _xml: &mut Xml) {}
+
+pub fn visit_security_scheme_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    _name: &str,
+    security_scheme: &mut SecurityScheme,
+) {
+    if let SecurityScheme::OAuth2(oauth2) = security_scheme {
+        oauth2
+            .flows
+            .values_mut()
+            .for_each(|flow| visitor.visit_flow_mut(flow));
+    }
+    if let Some(extensions) = security_scheme.extensions_mut() {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_flow_mut<V: VisitMut + ?Sized>(_visitor: &mut V, _flow: &mut Flow) {}
+
+pub fn visit_security_requirement_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _security_requirement: &mut SecurityRequirement,
+) {
+}
+
+pub fn visit_tag_mut<V: VisitMut + ?Sized>(visitor: &mut V, tag: &mut Tag) {
+    if let Some(external_docs) = &mut tag.external_docs {
+        visitor.visit_external_docs_mut(external_docs);
+    }
+    if let Some(extensions) = &mut tag.extensions {
+        visitor.visit_extensions_mut(extensions);
+    }
+}
+
+pub fn visit_external_docs_mut<V: VisitMut + ?Sized>(
+    _visitor: &mut V,
+    _external_docs: &mut ExternalDocs,
+) {
+}
+
+pub fn visit_extensions_mut<V: VisitMut + ?Sized>(_visitor: &mut V, _extensions: &mut Extensions) {}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use crate::openapi::visit::{self, Visit};
+
+    use super::*;
+
+    struct StripDescriptions;
+
+    impl VisitMut for StripDescriptions {
+        fn visit_operation_mut(
+            &mut self,
+            path_item_type: &PathItemType,
+            operation: &mut Operation,
+        ) {
+            operation.description = None;
+            visit_operation_mut(self, path_item_type, operation);
+        }
+
+        fn visit_property_mut(&mut self, property: &mut Property) {
+            property.description = None;
+            visit_property_mut(self, property);
+        }
+
+        fn visit_object_mut(&mut self, object: &mut Object) {
+            object.description = None;
+            visit_object_mut(self, object);
+        }
+    }
+
+    #[derive(Default)]
+    struct Descriptions(usize);
+
+    impl<'a> Visit<'a> for Descriptions {
+        fn visit_operation(&mut self, path_item_type: &'a PathItemType, operation: &'a Operation) {
+            self.0 += usize::from(operation.description.is_some());
+            visit::visit_operation(self, path_item_type, operation);
+        }
+
+        fn visit_property(&mut self, property: &'a Property) {
+            self.0 += usize::from(property.description.is_some());
+            visit::visit_property(self, property);
+        }
+
+        fn visit_object(&mut self, object: &'a Object) {
+            self.0 += usize::from(object.description.is_some());
+            visit::visit_object(self, object);
+        }
+    }
+
+    fn count_descriptions(openapi: &OpenApi) -> usize {
+        let mut descriptions = Descriptions::default();
+        descriptions.visit_openapi(openapi);
+
+        descriptions.0
+    }
+
+    #[test]
+    fn visit_openapi_mut_strip_descriptions() {
+        let mut openapi =
+            OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+        assert!(count_descriptions(&openapi) > 0);
+
+        StripDescriptions.visit_openapi_mut(&mut openapi);
+
+        assert_eq!(count_descriptions(&openapi), 0);
+    }
+
+    /// Renames references to the schema `from` within the schemas of components.
+    struct RenameSchemaReferences {
+        from: &'static str,
+        to: &'static str,
+    }
+
+    impl VisitMut for RenameSchemaReferences {
+        fn visit_schema_mut(&mut self, name: &str, schema: &mut Component) {
+            if name != self.from {
+                visit_schema_mut(self, name, schema);
+            }
+        }
+
+        fn visit_ref_mut(&mut self, reference: &mut Ref) {
+            if reference.ref_location == format!("#/components/schemas/{}", self.from) {
+                *reference = Ref::from_component_name(self.to);
+            }
+        }
+    }
+
+    #[test]
+    fn visit_openapi_mut_nested_components() {
+        let mut openapi =
+            OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+
+        RenameSchemaReferences {
+            from: "Owner",
+            to: "Person",
+        }
+        .visit_openapi_mut(&mut openapi);
+
+        let value = serde_json::to_value(&openapi).unwrap();
+        let pet = &value["components"]["schemas"]["Pet"]["properties"];
+        assert_eq!(pet["owner"]["$ref"], "#/components/schemas/Person");
+        assert_eq!(
+            pet["previousOwner"]["allOf"][0]["$ref"],
+            "#/components/schemas/Person"
+        );
+    }
+
+    struct RefreshUrl;
+
+    impl VisitMut for RefreshUrl {
+        fn visit_security_scheme_mut(&mut self, name: &str, security_scheme: &mut SecurityScheme) {
+            if name == "petstore_auth" {
+                visit_security_scheme_mut(self, name, security_scheme);
+            }
+        }
+
+        fn visit_flow_mut(&mut self, flow: &mut Flow) {
+            if let Flow::Implicit(implicit) = flow {
+                implicit.refresh_url =
+                    Some("https://petstore.swagger.io/oauth/refresh".to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn visit_openapi_mut_security_scheme_flows() {
+        let mut openapi =
+            OpenApi::from_json(include_str!("testdata/petstore-features.json")).unwrap();
+
+        RefreshUrl.visit_openapi_mut(&mut openapi);
+
+        let value = serde_json::to_value(&openapi).unwrap();
+        assert_eq!(
+            value["components"]["securitySchemes"]["petstore_auth"]["flows"]["implicit"]
+                ["refreshUrl"],
+            "https://petstore.swagger.io/oauth/refresh"
+        );
+    }
+}
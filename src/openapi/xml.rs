@@ -28,7 +28,7 @@ builder! {
     /// [schema_object]: https://spec.openapis.org/oas/latest.html#schema-object
     /// [schema]: ../schema/index.html
     #[non_exhaustive]
-    #[derive(Serialize, Deserialize, Default, Clone)]
+    #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
     pub struct Xml {
         /// Used to replace the name of attribute or type used in schema property.
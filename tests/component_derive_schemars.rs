@@ -0,0 +1,68 @@
+#![cfg(feature = "schemars")]
+
+use schemars::JsonSchema;
+use utoipa::{Component, OpenApi};
+
+use crate::common::get_json_path;
+
+mod common;
+
+#[allow(unused)]
+#[derive(JsonSchema)]
+struct Owner {
+    name: String,
+    age: Option<i32>,
+}
+
+/// Pet from schemars
+#[allow(unused)]
+#[derive(JsonSchema, Component)]
+#[component(schemars)]
+struct Pet {
+    id: u64,
+    owner: Owner,
+}
+
+#[allow(unused)]
+#[derive(Component)]
+struct Store {
+    pets: Vec<Pet>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/pets",
+    request_body = Pet,
+    responses(
+        (status = 200, description = "Pet created", body = Pet)
+    )
+)]
+#[allow(unused)]
+fn create_pet() {}
+
+#[test]
+fn derive_component_with_schemars() {
+    #[derive(OpenApi)]
+    #[openapi(handlers(create_pet), components(Pet, Store))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = get_json_path(&doc, "components.schemas");
+
+    assert_value! {schemas=>
+        "Pet.type" = r#""object""#, "Pet type"
+        "Pet.description" = r#""Pet from schemars""#, "Pet description"
+        "Pet.properties.id.type" = r#""integer""#, "Pet id type"
+        "Pet.properties.owner.$ref" = r###""#/components/schemas/Owner""###, "Pet owner ref"
+        "Owner.type" = r#""object""#, "Owner type"
+        "Owner.required" = r#"["name"]"#, "Owner required"
+        "Owner.properties.age.type" = r#""integer""#, "Owner age type"
+        "Store.properties.pets.items.$ref" = r###""#/components/schemas/Pet""###, "Store pets items ref"
+    }
+
+    let operation = get_json_path(&doc, "paths./pets.post");
+    assert_value! {operation=>
+        "requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/Pet""###, "Request body ref"
+        "responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/Pet""###, "Response body ref"
+    }
+}
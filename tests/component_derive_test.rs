@@ -1,5 +1,11 @@
 #![cfg(feature = "serde_json")]
-use std::{borrow::Cow, cell::RefCell, collections::HashMap, marker::PhantomData, vec};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+    vec,
+};
 
 #[cfg(any(feature = "chrono", feature = "chrono_with_format"))]
 use chrono::{Date, DateTime, Duration, Utc};
@@ -156,6 +162,102 @@ fn derive_struct_with_custom_properties_success() {
     };
 }
 
+#[test]
+fn derive_struct_with_example_method_reference() {
+    #[derive(Serialize)]
+    struct OwnerExample {
+        name: String,
+        pets: Vec<&'static str>,
+    }
+
+    fn owner_example() -> OwnerExample {
+        OwnerExample {
+            name: "bob".to_string(),
+            pets: vec!["cat", "dog"],
+        }
+    }
+
+    let owner = api_doc! {
+        #[component(example = owner_example)]
+        struct Owner {
+            name: String,
+            pets: Vec<String>
+        }
+    };
+
+    assert_value! {owner=>
+        "example" = r#"{"name":"bob","pets":["cat","dog"]}"#, "Owner example"
+    }
+}
+
+#[test]
+fn derive_struct_with_example_from_default() {
+    #[derive(Component, Serialize, Default)]
+    #[component(example_from_default)]
+    #[allow(unused)]
+    struct Book {
+        name: String,
+        pages: Option<u32>,
+    }
+
+    #[derive(Component, Serialize)]
+    #[component(example_from_default)]
+    struct Isbn(String);
+
+    impl Default for Isbn {
+        fn default() -> Self {
+            Self("978-3-16-148410-0".to_string())
+        }
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(Book, Isbn))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = get_json_path(&doc, "components.schemas");
+
+    assert_value! {schemas=>
+        "Book.example" = r#"{"name":"","pages":null}"#, "Book example"
+        "Isbn.example" = r#""978-3-16-148410-0""#, "Isbn example"
+    }
+}
+
+#[test]
+fn derive_struct_with_example_from_file() {
+    let pet = api_doc! {
+        #[component(example = file("tests/testdata/pet_example.json"))]
+        struct Pet {
+            id: u64,
+            #[component(example = file("tests/testdata/pet_name_example.json"))]
+            name: String,
+            tags: Vec<String>
+        }
+    };
+
+    assert_value! {pet=>
+        "example" = r#"{"id":1,"name":"bob the cat","tags":["cat","lazy"]}"#, "Pet example"
+        "properties.name.example" = r#""bob the cat""#, "Pet name example"
+    }
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn derive_struct_with_example_from_yaml_file() {
+    let pet = api_doc! {
+        #[component(example = file("tests/testdata/pet_example.yaml"))]
+        struct Pet {
+            id: u64,
+            name: String,
+            tags: Vec<String>
+        }
+    };
+
+    assert_value! {pet=>
+        "example" = r#"{"id":2,"name":"doggie","tags":["dog"]}"#, "Pet example"
+    }
+}
+
 #[test]
 fn derive_struct_with_optional_properties_success() {
     struct Book;
@@ -178,12 +280,133 @@ fn derive_struct_with_optional_properties_success() {
         "properties.books.type" = r#""array""#, "Owner books"
         "properties.books.items.$ref" = r###""#/components/schemas/Book""###, "Owner books items ref"
         "properties.metadata.type" = r#""object""#, "Owner metadata"
+        "properties.metadata.additionalProperties.type" = r#""string""#, "Owner metadata values type"
     };
     assert_value! {owner=>
         "required" = Value::Array(vec![Value::String("id".to_string())]), "Owner required"
     }
 }
 
+#[test]
+fn derive_struct_with_map_of_untyped_values() {
+    let settings = api_doc! {
+        struct Settings {
+            values: HashMap<String, serde_json::Value>,
+            overrides: Option<BTreeMap<String, ::serde_json::Value>>,
+            /// Default value
+            default: serde_json::Value
+        }
+    };
+
+    assert_value! {settings=>
+        "properties.values.type" = r#""object""#, "Settings values type"
+        "properties.values.additionalProperties" = r#"null"#, "Settings values additionalProperties"
+        "properties.overrides.type" = r#""object""#, "Settings overrides type"
+        "properties.overrides.additionalProperties" = r#"null"#, "Settings overrides additionalProperties"
+        "properties.default.type" = r#""object""#, "Settings default type"
+        "properties.default.description" = r#""Default value""#, "Settings default description"
+        "properties.default.$ref" = r#"null"#, "Settings default reference"
+    }
+}
+
+#[test]
+fn derive_struct_with_map_of_custom_value_type() {
+    mod custom {
+        #[allow(dead_code)]
+        #[derive(utoipa::Component)]
+        pub struct Value {
+            pub value: String,
+        }
+    }
+
+    let settings = api_doc! {
+        struct Settings {
+            values: HashMap<String, custom::Value>
+        }
+    };
+
+    assert_value! {settings=>
+        "properties.values.type" = r#""object""#, "Settings values type"
+        "properties.values.additionalProperties.$ref" = r###""#/components/schemas/Value""###, "Settings values reference"
+    }
+}
+
+#[test]
+fn derive_struct_with_direct_recursion() {
+    let node = api_doc! {
+        struct Node {
+            value: i32,
+            children: Vec<Node>,
+            parent: Option<Box<Node>>,
+            named: HashMap<String, Node>,
+            sorted: Option<BTreeMap<String, Box<Node>>>,
+            nested: Vec<Option<Box<Node>>>
+        }
+    };
+
+    assert_value! {node=>
+        "properties.value.type" = r#""integer""#, "Node value type"
+        "properties.children.type" = r#""array""#, "Node children type"
+        "properties.children.items.$ref" = r###""#/components/schemas/Node""###, "Node children items ref"
+        "properties.parent.$ref" = r###""#/components/schemas/Node""###, "Node parent ref"
+        "properties.named.type" = r#""object""#, "Node named type"
+        "properties.named.additionalProperties.$ref" = r###""#/components/schemas/Node""###, "Node named values ref"
+        "properties.sorted.additionalProperties.$ref" = r###""#/components/schemas/Node""###, "Node sorted values ref"
+        "properties.nested.items.$ref" = r###""#/components/schemas/Node""###, "Node nested items ref"
+        "required" = r#"["value","children","named","nested"]"#, "Node required"
+    }
+}
+
+#[test]
+fn derive_components_with_mutual_recursion() {
+    #[allow(unused)]
+    #[derive(Component)]
+    struct Employee {
+        name: String,
+        department: Box<Department>,
+        manager: Option<Box<Employee>>,
+    }
+
+    #[allow(unused)]
+    #[derive(Component)]
+    struct Department {
+        head: Option<Box<Employee>>,
+        employees: Vec<Employee>,
+        teams: HashMap<String, Vec<Department>>,
+    }
+
+    #[allow(unused)]
+    #[derive(Component)]
+    enum Expr {
+        Literal(i64),
+        Not(Box<Expr>),
+        Sum { left: Box<Expr>, right: Box<Expr> },
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(Employee, Department, Expr))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemas = get_json_path(&doc, "components.schemas");
+
+    assert_eq!(
+        schemas.as_object().unwrap().len(),
+        3,
+        "Expected exactly 3 schemas"
+    );
+    assert_value! {schemas=>
+        "Employee.properties.department.$ref" = r###""#/components/schemas/Department""###, "Employee department ref"
+        "Employee.properties.manager.$ref" = r###""#/components/schemas/Employee""###, "Employee manager ref"
+        "Department.properties.head.$ref" = r###""#/components/schemas/Employee""###, "Department head ref"
+        "Department.properties.employees.items.$ref" = r###""#/components/schemas/Employee""###, "Department employees items ref"
+        "Department.properties.teams.additionalProperties.items.$ref" = r###""#/components/schemas/Department""###, "Department teams values items ref"
+        "Expr.oneOf.[1].properties.Not.$ref" = r###""#/components/schemas/Expr""###, "Expr Not ref"
+        "Expr.oneOf.[2].properties.Sum.properties.left.$ref" = r###""#/components/schemas/Expr""###, "Expr Sum left ref"
+        "Expr.oneOf.[2].properties.Sum.properties.right.$ref" = r###""#/components/schemas/Expr""###, "Expr Sum right ref"
+    }
+}
+
 #[test]
 fn derive_struct_with_comments_success() {
     let account = api_doc! {
@@ -233,6 +456,102 @@ fn derive_enum_with_comments_success() {
     }
 }
 
+#[test]
+fn derive_enum_with_variant_docs_one_of() {
+    #[allow(deprecated)]
+    let status = api_doc! {
+        /// Status of account
+        #[derive(Serialize)]
+        #[serde(rename_all = "lowercase")]
+        #[component(variant_docs = OneOf)]
+        enum AccountStatus {
+            /// Account is valid to login
+            Enabled,
+            /// Login failed too many times
+            #[deprecated]
+            Locked,
+            Disabled
+        }
+    };
+
+    assert_value! {status=>
+        "description" = r#""Status of account""#, "AccountStatus description"
+        "oneOf.[0].type" = r#""string""#, "Enabled type"
+        "oneOf.[0].enum" = r#"["enabled"]"#, "Enabled enum"
+        "oneOf.[0].description" = r#""Account is valid to login""#, "Enabled description"
+        "oneOf.[1].enum" = r#"["locked"]"#, "Locked enum"
+        "oneOf.[1].description" = r#""Login failed too many times""#, "Locked description"
+        "oneOf.[1].deprecated" = r#"true"#, "Locked deprecated"
+        "oneOf.[2].enum" = r#"["disabled"]"#, "Disabled enum"
+        "oneOf.[2].description" = r#"null"#, "Disabled description"
+        "enum" = r#"null"#, "AccountStatus enum"
+        "deprecated" = r#"null"#, "AccountStatus deprecated"
+    }
+}
+
+#[test]
+fn derive_deprecated_enum_with_variant_docs_one_of() {
+    #[allow(deprecated)]
+    let status = api_doc! {
+        #[derive(Serialize)]
+        #[component(variant_docs = OneOf)]
+        #[deprecated]
+        enum AccountStatus {
+            Enabled,
+            Disabled
+        }
+    };
+
+    assert_value! {status=>
+        "deprecated" = r#"true"#, "AccountStatus deprecated"
+        "oneOf.[0].deprecated" = r#"null"#, "Enabled deprecated"
+    }
+}
+
+#[test]
+fn derive_enum_with_variant_docs_extensions() {
+    let status = api_doc! {
+        #[derive(Serialize)]
+        #[serde(rename_all = "lowercase")]
+        #[component(variant_docs = Extensions, default = "enabled")]
+        enum AccountStatus {
+            /// Account is valid to login
+            Enabled,
+            #[serde(skip)]
+            Unknown,
+            /// Login failed too many times
+            Locked,
+            Disabled
+        }
+    };
+
+    assert_value! {status=>
+        "type" = r#""string""#, "AccountStatus type"
+        "enum" = r#"["enabled","locked","disabled"]"#, "AccountStatus enum"
+        "default" = r#""enabled""#, "AccountStatus default"
+        "x-enum-varnames" = r#"["Enabled","Locked","Disabled"]"#, "AccountStatus x-enum-varnames"
+        "x-enum-descriptions" = r#"["Account is valid to login","Login failed too many times",""]"#, "AccountStatus x-enum-descriptions"
+    }
+}
+
+#[test]
+fn derive_enum_with_variant_docs_extensions_and_extensions() {
+    let status = api_doc! {
+        #[derive(Serialize)]
+        #[component(variant_docs = Extensions, extensions("x-go-type" = "AccountStatus"))]
+        enum AccountStatus {
+            /// Account is valid to login
+            Enabled,
+            Disabled
+        }
+    };
+
+    assert_value! {status=>
+        "x-enum-varnames" = r#"["Enabled","Disabled"]"#, "AccountStatus x-enum-varnames"
+        "x-go-type" = r#""AccountStatus""#, "AccountStatus x-go-type"
+    }
+}
+
 #[test]
 fn derive_struct_unnamed_field_single_value_type_success() {
     let point = api_doc! {
@@ -714,6 +1033,28 @@ fn derive_struct_component_field_type_override_with_format_with_vec() {
     }
 }
 
+#[test]
+fn derive_struct_component_field_with_string_format() {
+    let user = api_doc! {
+        struct User {
+            #[component(format = "email")]
+            email: String,
+            #[component(format = "decimal")]
+            balance: String,
+            #[component(format = "date-time")]
+            created: String,
+        }
+    };
+
+    assert_value! {user=>
+        "properties.email.type" = r#""string""#, "User email type"
+        "properties.email.format" = r#""email""#, "User email format"
+        "properties.balance.type" = r#""string""#, "User balance type"
+        "properties.balance.format" = r#""decimal""#, "User balance format"
+        "properties.created.format" = r#""date-time""#, "User created format"
+    }
+}
+
 #[test]
 fn derive_unnamed_struct_component_type_override() {
     let value = api_doc! {
@@ -910,3 +1251,20 @@ fn derive_component_with_aliases() {
         "MyAlias.properties.bar.$ref" = r###""#/components/schemas/A""###, "MyAlias aliased property"
     }
 }
+
+#[test]
+fn derive_struct_with_extensions() {
+    let pet = api_doc! {
+        #[component(extensions("x-go-type" = "Pet", "x-internal" = true))]
+        struct Pet {
+            #[component(extensions("x-order" = 1))]
+            name: String,
+        }
+    };
+
+    assert_value! {pet=>
+        "x-go-type" = r#""Pet""#, "Pet x-go-type"
+        "x-internal" = r#"true"#, "Pet x-internal"
+        "properties.name.x-order" = r#"1"#, "Pet name x-order"
+    }
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "validator")]
+#![cfg(feature = "serde_json")]
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use utoipa::{Component, OpenApi};
+use validator::Validate;
+
+use crate::common::get_json_path;
+
+mod common;
+
+lazy_static! {
+    static ref USERNAME: Regex = Regex::new(r"^[a-z0-9_]+$").unwrap();
+}
+
+#[test]
+fn derive_component_with_validator_attributes() {
+    #[derive(Validate, Component)]
+    #[allow(unused)]
+    struct User {
+        #[validate(length(min = 1, max = 50), regex = "USERNAME")]
+        username: String,
+        #[validate(email)]
+        email: String,
+        #[validate(url)]
+        homepage: Option<String>,
+        #[validate(range(min = 0, max = 150))]
+        age: i32,
+        #[validate(range(min = 0.5, max = 5.0))]
+        rating: Option<f64>,
+        #[validate(length(min = 1, max = 3))]
+        tags: Vec<String>,
+        #[validate(length(equal = 2))]
+        country: String,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(User))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let user = get_json_path(&doc, "components.schemas.User");
+
+    assert_value! {user=>
+        "properties.username.minLength" = r#"1"#, "User username minLength"
+        "properties.username.maxLength" = r#"50"#, "User username maxLength"
+        "properties.username.pattern" = r#""^[a-z0-9_]+$""#, "User username pattern"
+        "properties.email.format" = r#""email""#, "User email format"
+        "properties.homepage.format" = r#""uri""#, "User homepage format"
+        "properties.age.minimum" = r#"0.0"#, "User age minimum"
+        "properties.age.maximum" = r#"150.0"#, "User age maximum"
+        "properties.rating.minimum" = r#"0.5"#, "User rating minimum"
+        "properties.rating.maximum" = r#"5.0"#, "User rating maximum"
+        "properties.tags.type" = r#""array""#, "User tags type"
+        "properties.tags.minItems" = r#"1"#, "User tags minItems"
+        "properties.tags.maxItems" = r#"3"#, "User tags maxItems"
+        "properties.tags.items.minLength" = r#"null"#, "User tags items minLength"
+        "properties.country.minLength" = r#"2"#, "User country minLength"
+        "properties.country.maxLength" = r#"2"#, "User country maxLength"
+    }
+}
+
+#[test]
+fn derive_component_with_validator_attributes_and_format() {
+    #[derive(Validate, Component)]
+    #[allow(unused)]
+    struct User {
+        #[validate(email)]
+        #[component(format = "idn-email")]
+        email: String,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(User))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let user = get_json_path(&doc, "components.schemas.User");
+
+    assert_value! {user=>
+        "properties.email.format" = r#""idn-email""#, "User email format"
+    }
+}
+
+#[test]
+fn derive_component_with_validator_messages_and_codes() {
+    #[derive(Validate, Component)]
+    #[allow(unused)]
+    struct User {
+        #[validate(
+            length(min = 1, message = "username.length"),
+            regex(path = "USERNAME", code = "invalid_username", message = "USERNAME")
+        )]
+        username: String,
+        #[validate(email(code = "email", message = "must be an email"))]
+        email: String,
+        #[validate(range(max = 150, message = "age > 150"))]
+        age: i32,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(User))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let user = get_json_path(&doc, "components.schemas.User");
+
+    assert_value! {user=>
+        "properties.username.minLength" = r#"1"#, "User username minLength"
+        "properties.username.maxLength" = r#"null"#, "User username maxLength"
+        "properties.username.pattern" = r#""^[a-z0-9_]+$""#, "User username pattern"
+        "properties.email.format" = r#""email""#, "User email format"
+        "properties.age.minimum" = r#"null"#, "User age minimum"
+        "properties.age.maximum" = r#"150.0"#, "User age maximum"
+    }
+}
+
+#[test]
+fn derive_component_with_nested_validator_attribute() {
+    #[derive(Validate, Component)]
+    #[allow(unused)]
+    struct Address {
+        #[validate(length(min = 1))]
+        street: String,
+    }
+
+    #[derive(Validate, Component)]
+    #[allow(unused)]
+    struct User {
+        #[validate(email)]
+        email: String,
+        #[validate]
+        address: Address,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(User, Address))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let user = get_json_path(&doc, "components.schemas.User");
+    let address = get_json_path(&doc, "components.schemas.Address");
+
+    assert_value! {user=>
+        "properties.email.format" = r#""email""#, "User email format"
+        "properties.address.$ref" = r##""#/components/schemas/Address""##, "User address reference"
+    }
+    assert_value! {address=>
+        "properties.street.minLength" = r#"1"#, "Address street minLength"
+    }
+}
@@ -105,3 +105,255 @@ fn derive_openapi_with_components_in_different_module() {
         "Expected components.schemas.Todo not to be null"
     );
 }
+
+#[test]
+fn derive_openapi_with_openapi_31_version() {
+    #[derive(utoipa::Component)]
+    #[allow(unused)]
+    struct Pet {
+        #[component(nullable)]
+        name: Option<String>,
+        #[component(example = 10)]
+        age: i32,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(openapi_version = "3.1", components(Pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let pet = common::get_json_path(&doc, "components.schemas.Pet.properties");
+
+    assert_value! {doc=>
+        "openapi" = r#""3.1.0""#, "OpenAPI version"
+        "info.license.identifier" = r#""MIT OR Apache-2.0""#, "License identifier"
+    }
+    assert_value! {pet=>
+        "name.type" = r#"["string","null"]"#, "Nullable property type"
+        "age.examples" = r#"[10]"#, "Property examples"
+    }
+}
+
+#[test]
+fn derive_openapi_with_webhooks() {
+    #[derive(utoipa::Component)]
+    #[allow(unused)]
+    struct Pet {
+        id: u64,
+        name: String,
+    }
+
+    mod webhooks {
+        /// Pet has been created
+        #[utoipa::webhook(
+            post,
+            name = "petCreated",
+            request_body = Pet,
+            responses(
+                (status = 200, description = "Webhook received")
+            )
+        )]
+        #[allow(unused)]
+        pub fn pet_created() {}
+
+        /// Pet has been deleted
+        #[utoipa::webhook(
+            delete,
+            name = "petCreated",
+            operation_id = "pet_created_deleted",
+            responses(
+                (status = 204, description = "Webhook received")
+            )
+        )]
+        #[allow(unused)]
+        pub fn pet_created_deleted() {}
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(
+        openapi_version = "3.1",
+        webhooks(webhooks::pet_created, webhooks::pet_created_deleted),
+        components(Pet)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "webhooks.petCreated.post.operationId" = r#""pet_created""#, "Webhook operation id"
+        "webhooks.petCreated.post.summary" = r#""Pet has been created""#, "Webhook summary"
+        "webhooks.petCreated.post.tags" = r#"["webhooks"]"#, "Webhook tags"
+        "webhooks.petCreated.post.requestBody.content.application/json.schema.$ref" = r##""#/components/schemas/Pet""##, "Webhook request body"
+        "webhooks.petCreated.post.responses.200.description" = r#""Webhook received""#, "Webhook response"
+        "webhooks.petCreated.delete.operationId" = r#""pet_created_deleted""#, "Merged webhook operation id"
+        "paths" = r#"{}"#, "Paths"
+    }
+}
+
+#[test]
+fn derive_openapi_with_extensions() {
+    #[derive(OpenApi)]
+    #[openapi(
+        tags((name = "pet", extensions("x-display-name" = "Pets"))),
+        extensions("x-logo" = json!({"url": "https://localhost/logo.png"}))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "x-logo.url" = r#""https://localhost/logo.png""#, "OpenApi x-logo"
+        "tags.[0].x-display-name" = r#""Pets""#, "Tag x-display-name"
+    }
+}
+
+mod nested_api {
+    #[derive(utoipa::Component)]
+    #[allow(unused)]
+    pub struct Pet {
+        id: u64,
+        name: String,
+    }
+
+    pub mod pets {
+        use super::Pet;
+
+        #[utoipa::path(
+            get,
+            path = "/",
+            responses((status = 200, description = "List pets", body = [Pet]))
+        )]
+        #[allow(unused)]
+        pub fn list_pets() {}
+
+        #[utoipa::path(
+            get,
+            path = "/{id}",
+            responses((status = 200, description = "Pet found", body = Pet))
+        )]
+        #[allow(unused)]
+        pub fn get_pet() {}
+
+        #[derive(utoipa::OpenApi)]
+        #[openapi(
+            handlers(list_pets, get_pet),
+            components(Pet),
+            tags((name = "pets", description = "Pet operations"))
+        )]
+        pub struct PetApi;
+    }
+
+    pub mod store {
+        use super::Pet;
+
+        #[utoipa::path(
+            get,
+            path = "/orders",
+            responses((status = 200, description = "List orders"))
+        )]
+        #[allow(unused)]
+        pub fn list_orders() {}
+
+        #[derive(utoipa::OpenApi)]
+        #[openapi(handlers(list_orders), components(Pet))]
+        pub struct StoreApi;
+    }
+
+    pub mod conflicting {
+        use super::Pet;
+
+        #[derive(utoipa::OpenApi)]
+        #[openapi(components(Pet), tags((name = "pets", description = "Store pets")))]
+        pub struct ConflictingApi;
+    }
+}
+
+#[test]
+fn derive_openapi_with_nested_api() {
+    #[derive(OpenApi)]
+    #[openapi(nest(
+        ("/api/v1/pets", nested_api::pets::PetApi, tag = "pets"),
+        ("/api/v1/store/", nested_api::store::StoreApi)
+    ))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let paths = common::get_json_path(&doc, "paths");
+
+    assert_value! {paths=>
+        "/api/v1/pets.get.operationId" = r#""list_pets""#, "Nested root path operation id"
+        "/api/v1/pets.get.tags" = r#"["crate","pets"]"#, "Nested root path tags"
+        "/api/v1/pets/{id}.get.operationId" = r#""get_pet""#, "Nested path operation id"
+        "/api/v1/store/orders.get.operationId" = r#""list_orders""#, "Nested store operation id"
+        "/api/v1/store/orders.get.tags" = r#"["crate"]"#, "Nested store tags"
+    }
+    assert_value! {doc=>
+        "components.schemas.Pet.type" = r#""object""#, "Merged Pet component"
+        "tags.[0].name" = r#""pets""#, "Merged tag name"
+        "info.title" = r#""utoipa""#, "Info of the nesting document"
+    }
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(
+        expected = "cannot nest `nested_api :: conflicting :: ConflictingApi` without dropping definitions: conflicting definitions of `tags.pets`"
+    )
+)]
+fn derive_openapi_with_conflicting_nested_api() {
+    #[derive(OpenApi)]
+    #[openapi(nest(
+        ("/api/v1/pets", nested_api::pets::PetApi),
+        ("/api/v1/store", nested_api::conflicting::ConflictingApi)
+    ))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "tags.[0].description" = r#""Pet operations""#, "First nested tag description"
+        "tags.[1]" = r#"null"#, "Conflicting nested tag"
+    }
+}
+
+mod validated_api {
+    #[derive(utoipa::Component)]
+    #[allow(unused)]
+    pub struct Order {
+        id: u64,
+        pet_id: u64,
+    }
+
+    #[utoipa::path(
+        get,
+        path = "/orders/{id}",
+        responses((status = 200, description = "Order found", body = Order)),
+        params(("id" = u64, path, description = "Id of the order"))
+    )]
+    #[allow(unused)]
+    pub fn get_order() {}
+
+    #[derive(utoipa::OpenApi)]
+    #[openapi(
+        handlers(get_order),
+        components(Order),
+        tags((name = "orders", description = "Order operations"))
+    )]
+    pub struct OrderApi;
+}
+
+#[test]
+fn derive_openapi_validate_nested_api() {
+    #[derive(OpenApi)]
+    #[openapi(nest(("/api/v1/store", validated_api::OrderApi, tag = "orders")))]
+    struct ApiDoc;
+
+    let openapi = ApiDoc::openapi();
+
+    assert_eq!(openapi.validate(), Ok(()));
+    assert!(openapi
+        .paths
+        .paths
+        .contains_key("/api/v1/store/orders/{id}"));
+}
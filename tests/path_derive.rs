@@ -264,3 +264,106 @@ fn derive_path_with_uuid() {
         "parameters.[0].in" = r#""path""#, "Parameter in"
     }
 }
+
+#[test]
+fn derive_path_with_callbacks() {
+    mod callbacks {
+        /// Pet created event
+        #[utoipa::path(
+            post,
+            path = "/pet-created",
+            operation_id = "pet_created_event",
+            tag = "pet",
+            responses(
+                (status = 204, description = "Event received")
+            )
+        )]
+        #[allow(unused)]
+        fn pet_created() {}
+
+        #[utoipa::path(
+            put,
+            path = "/pet-updated",
+            operation_id = "pet_updated_event",
+            tag = "pet",
+            responses(
+                (status = 204, description = "Event received")
+            )
+        )]
+        #[allow(unused)]
+        fn pet_updated() {}
+    }
+
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        responses(
+            (status = 201, description = "Pet created")
+        ),
+        callbacks(
+            ("onPetCreated" = ("{$request.body#/callbackUrl}" = callbacks::pet_created)),
+            ("onPetEvent" = (
+                "{$request.body#/eventUrl}" = callbacks::pet_created,
+                "{$request.body#/eventUrl}" = callbacks::pet_updated,
+            ))
+        )
+    )]
+    #[allow(unused)]
+    fn create_pet() {}
+
+    let operation = test_api_fn_doc! {
+        create_pet,
+        operation: post,
+        path: "/pets"
+    };
+    let callbacks = operation.get("callbacks").unwrap();
+
+    let created_path_item = &callbacks["onPetCreated"]["{$request.body#/callbackUrl}"];
+    assert_eq!(
+        created_path_item["post"]["operationId"],
+        serde_json::json!("pet_created_event")
+    );
+    assert_eq!(
+        created_path_item["post"]["summary"],
+        serde_json::json!("Pet created event")
+    );
+    assert_eq!(
+        created_path_item["post"]["responses"]["204"]["description"],
+        serde_json::json!("Event received")
+    );
+
+    let event_path_item = &callbacks["onPetEvent"]["{$request.body#/eventUrl}"];
+    assert_eq!(
+        event_path_item["post"]["operationId"],
+        serde_json::json!("pet_created_event")
+    );
+    assert_eq!(
+        event_path_item["put"]["operationId"],
+        serde_json::json!("pet_updated_event")
+    );
+}
+
+#[test]
+fn derive_path_with_extensions() {
+    #[utoipa::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, description = "Pets found")
+        ),
+        extensions("x-rate-limit" = 100, "x-audience" = json!(["internal", "partner"]))
+    )]
+    #[allow(unused)]
+    fn list_pets() {}
+
+    let operation = test_api_fn_doc! {
+        list_pets,
+        operation: get,
+        path: "/pets"
+    };
+
+    assert_value! {operation=>
+        "x-rate-limit" = r#"100"#, "Operation x-rate-limit"
+        "x-audience" = r#"["internal","partner"]"#, "Operation x-audience"
+    }
+}
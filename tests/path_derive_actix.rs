@@ -57,6 +57,82 @@ fn derive_path_one_value_actix_success() {
     };
 }
 
+mod mod_derive_path_inferred_responses_actix {
+    use std::fmt::Display;
+
+    use actix_web::{get, web, ResponseError};
+
+    #[derive(utoipa::IntoResponses, Debug)]
+    #[allow(unused)]
+    enum FooError {
+        #[response(status = 404, description = "Foo not found")]
+        NotFound,
+    }
+
+    impl Display for FooError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self)
+        }
+    }
+
+    impl ResponseError for FooError {}
+
+    #[utoipa::path(infer_responses(error))]
+    #[get("/foo/{id}")]
+    #[allow(unused)]
+    async fn get_foo_by_id(id: web::Path<i32>) -> Result<web::Json<Vec<String>>, FooError> {
+        Ok(web::Json(vec![id.to_string()]))
+    }
+}
+
+mod mod_derive_path_inferred_responses_actix_error {
+    use actix_web::{get, web};
+
+    #[utoipa::path(infer_responses)]
+    #[get("/foo/{id}")]
+    #[allow(unused)]
+    async fn get_foo_by_id(id: web::Path<i32>) -> Result<web::Json<String>, actix_web::Error> {
+        Ok(web::Json(id.to_string()))
+    }
+}
+
+#[test]
+fn derive_path_with_inferred_responses_actix_success() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(mod_derive_path_inferred_responses_actix::get_foo_by_id))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let responses = common::get_json_path(&doc, "paths./foo/{id}.get.responses");
+
+    assert_value! {responses=>
+        "200.description" = r#""OK""#, "Success response description"
+        "200.content.application/json.schema.type" = r#""array""#, "Success response content type"
+        "200.content.application/json.schema.items.type" = r#""string""#, "Success response content items type"
+        "404.description" = r#""Foo not found""#, "Not found response description"
+    };
+}
+
+#[test]
+fn derive_path_with_inferred_responses_without_error_responses_actix() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(mod_derive_path_inferred_responses_actix_error::get_foo_by_id))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let responses = common::get_json_path(&doc, "paths./foo/{id}.get.responses");
+
+    assert_value! {responses=>
+        "200.description" = r#""OK""#, "Success response description"
+        "200.content.application/json.schema.type" = r#""string""#, "Success response content type"
+    };
+    assert_eq!(
+        responses.as_object().unwrap().len(),
+        1,
+        "Expected only success response"
+    );
+}
+
 mod mod_derive_path_unnamed_regex_actix {
     use actix_web::{get, web, HttpResponse, Responder};
     use serde_json::json;
@@ -471,6 +547,48 @@ fn derive_path_with_struct_variables_with_into_params() {
     }
 }
 
+#[test]
+fn derive_into_params_with_map_of_untyped_values() {
+    use actix_web::{get, HttpResponse, Responder};
+    use std::collections::HashMap;
+
+    #[derive(Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        labels: HashMap<String, String>,
+        values: HashMap<String, serde_json::Value>,
+    }
+
+    #[utoipa::path(
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[get("/foo")]
+    #[allow(unused)]
+    async fn get_foo(query: Query<Filter>) -> impl Responder {
+        HttpResponse::Ok()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = common::get_json_path(&doc, "paths./foo.get.parameters");
+
+    common::assert_json_array_len(parameters, 2);
+    assert_value! {parameters=>
+        "[0].name" = r#""labels""#, "Parameter name"
+        "[0].schema.type" = r#""object""#, "Parameter schema type"
+        "[0].schema.additionalProperties.type" = r#""string""#, "Parameter schema values type"
+
+        "[1].name" = r#""values""#, "Parameter name"
+        "[1].schema.type" = r#""object""#, "Parameter schema type"
+        "[1].schema.additionalProperties" = r#"null"#, "Parameter schema values"
+    }
+}
+
 #[test]
 fn derive_path_with_multiple_instances_same_path_params() {
     use actix_web::{delete, get, HttpResponse, Responder};
@@ -664,6 +782,101 @@ fn derive_into_params_with_custom_attributes() {
     }
 }
 
+#[test]
+fn derive_into_params_with_format() {
+    use actix_web::{get, HttpResponse, Responder};
+
+    #[derive(Deserialize, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        #[param(format = "date")]
+        since: Option<String>,
+        #[param(format = ComponentFormat::Int32)]
+        limit: i64,
+        #[param(format = "email")]
+        emails: Vec<String>,
+    }
+
+    #[utoipa::path(
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[get("/foo")]
+    #[allow(unused)]
+    async fn get_foo(query: Query<Filter>) -> impl Responder {
+        HttpResponse::Ok()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = common::get_json_path(&doc, "paths./foo.get.parameters");
+
+    common::assert_json_array_len(parameters, 3);
+    assert_value! {parameters=>
+        "[0].name" = r#""since""#, "Parameter name"
+        "[0].schema.type" = r#""string""#, "Parameter schema type"
+        "[0].schema.format" = r#""date""#, "Parameter schema format"
+
+        "[1].name" = r#""limit""#, "Parameter name"
+        "[1].schema.type" = r#""integer""#, "Parameter schema type"
+        "[1].schema.format" = r#""int32""#, "Parameter schema format"
+
+        "[2].name" = r#""emails""#, "Parameter name"
+        "[2].schema.type" = r#""array""#, "Parameter schema type"
+        "[2].schema.items.format" = r#""email""#, "Parameter items schema format"
+    }
+}
+
+#[cfg(feature = "validator")]
+#[test]
+fn derive_into_params_with_validator_attributes() {
+    use actix_web::{get, HttpResponse, Responder};
+    use validator::Validate;
+
+    #[derive(Deserialize, Validate, IntoParams)]
+    #[allow(unused)]
+    struct Filter {
+        #[validate(length(min = 3, max = 20))]
+        name: Option<String>,
+        #[validate(range(min = 1, max = 100))]
+        limit: i32,
+        #[validate(length(max = 5))]
+        ids: Vec<i64>,
+    }
+
+    #[utoipa::path(
+        responses(
+            (status = 200, description = "success response")
+        )
+    )]
+    #[get("/foo")]
+    #[allow(unused)]
+    async fn get_foo(query: Query<Filter>) -> impl Responder {
+        HttpResponse::Ok()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = common::get_json_path(&doc, "paths./foo.get.parameters");
+
+    common::assert_json_array_len(parameters, 3);
+    assert_value! {parameters=>
+        "[0].schema.minLength" = r#"3"#, "Parameter schema minLength"
+        "[0].schema.maxLength" = r#"20"#, "Parameter schema maxLength"
+        "[1].schema.minimum" = r#"1.0"#, "Parameter schema minimum"
+        "[1].schema.maximum" = r#"100.0"#, "Parameter schema maximum"
+        "[2].schema.type" = r#""array""#, "Parameter schema type"
+        "[2].schema.maxItems" = r#"5"#, "Parameter schema maxItems"
+    }
+}
+
 #[test]
 fn derive_into_params_in_another_module() {
     use actix_web::{get, HttpResponse, Responder};
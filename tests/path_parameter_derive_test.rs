@@ -292,3 +292,40 @@ fn derive_params_with_params_ext() {
         "[0].explode" = r#"true"#, "Parameter explode"
     };
 }
+
+#[test]
+fn derive_params_with_format() {
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            (status = 200, description = "success"),
+        ),
+        params(
+            ("since" = String, query, format = "date-time"),
+            ("email" = Option<String>, query, format = "email"),
+            ("id" = i64, query, format = ComponentFormat::Int32),
+        )
+    )]
+    #[allow(unused)]
+    async fn get_foo() -> String {
+        "".to_string()
+    }
+
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(get_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = common::get_json_path(&doc, "paths./foo.get.parameters");
+
+    common::assert_json_array_len(parameters, 3);
+    assert_value! {parameters=>
+        "[0].schema.type" = r#""string""#, "Parameter schema type"
+        "[0].schema.format" = r#""date-time""#, "Parameter schema format"
+        "[1].schema.type" = r#""string""#, "Parameter schema type"
+        "[1].schema.format" = r#""email""#, "Parameter schema format"
+        "[2].schema.type" = r#""integer""#, "Parameter schema type"
+        "[2].schema.format" = r#""int32""#, "Parameter schema format"
+    };
+}
@@ -215,3 +215,411 @@ fn derive_reponse_multiple_content_types() {
         "responses.200.headers" = r#"null"#, "Response headers"
     }
 }
+
+test_fn! {
+    module: response_with_named_examples,
+    responses: (
+        (status = 200, description = "success", body = Foo, examples(
+            ("Demo" = (summary = "This is summary", description = "Long description", value = json!({"foo": "bar"}))),
+            ("External" = (external_value = "https://example.com/foo.json"))
+        ))
+    )
+}
+
+#[test]
+fn derive_response_with_named_examples() {
+    let doc = api_doc!(module: response_with_named_examples);
+
+    assert_value! {doc=>
+        "responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/Foo""###, "Response content ref"
+        "responses.200.content.application/json.example" = r###"null"###, "Response content example"
+        "responses.200.content.application/json.examples.Demo.summary" = r###""This is summary""###, "Demo example summary"
+        "responses.200.content.application/json.examples.Demo.description" = r###""Long description""###, "Demo example description"
+        "responses.200.content.application/json.examples.Demo.value" = r###"{"foo":"bar"}"###, "Demo example value"
+        "responses.200.content.application/json.examples.Demo.externalValue" = r###"null"###, "Demo example external value"
+        "responses.200.content.application/json.examples.External.externalValue" = r###""https://example.com/foo.json""###, "External example external value"
+        "responses.200.content.application/json.examples.External.value" = r###"null"###, "External example value"
+    }
+}
+
+test_fn! {
+    module: response_with_example_from_file,
+    responses: (
+        (status = 200, description = "success", body = Foo, example = file("tests/testdata/pet_example.json"))
+    )
+}
+
+#[test]
+fn derive_response_with_example_from_file() {
+    let doc = api_doc!(module: response_with_example_from_file);
+
+    assert_value! {doc=>
+        "responses.200.content.application/json.example" = r###"{"id":1,"name":"bob the cat","tags":["cat","lazy"]}"###, "Response content example"
+    }
+}
+
+#[allow(unused)]
+#[derive(utoipa::IntoResponses)]
+enum MyErrors {
+    /// Missing or invalid credentials
+    #[response(status = 401)]
+    Unauthorized,
+
+    #[response(status = 404, description = "Resource not found")]
+    NotFound(ErrorMessage),
+
+    #[response(status = 500, description = "Unexpected error", body = String)]
+    ServerError { reason: String },
+}
+
+#[allow(unused)]
+struct ErrorMessage {
+    message: String,
+}
+
+test_fn! {
+    module: response_with_into_responses,
+    responses: (
+        (status = 200, description = "success"),
+        super::MyErrors
+    )
+}
+
+#[test]
+fn derive_response_with_into_responses() {
+    let doc = api_doc!(module: response_with_into_responses);
+
+    assert_value! {doc=>
+        "responses.200.description" = r#""success""#, "Success response description"
+        "responses.401.description" = r#""Missing or invalid credentials""#, "Unauthorized response description"
+        "responses.401.content" = r#"null"#, "Unauthorized response content"
+        "responses.404.description" = r#""Resource not found""#, "Not found response description"
+        "responses.404.content.application/json.schema.$ref" = r###""#/components/schemas/ErrorMessage""###, "Not found response content ref"
+        "responses.500.description" = r#""Unexpected error""#, "Server error response description"
+        "responses.500.content.text/plain.schema.type" = r#""string""#, "Server error response content type"
+    }
+}
+
+#[allow(unused)]
+#[derive(utoipa::IntoResponses)]
+#[response(status = 201, description = "Pet created")]
+struct PetCreated {
+    id: u64,
+}
+
+#[allow(unused)]
+/// Requested resource was not found
+#[derive(utoipa::ToResponse)]
+struct NotFound;
+
+#[allow(unused)]
+#[derive(utoipa::ToResponse)]
+#[response(description = "Unexpected error", content_type = "text/xml")]
+struct ServerError {
+    message: String,
+}
+
+#[test]
+fn derive_path_with_reusable_responses() {
+    use utoipa::OpenApi;
+
+    #[utoipa::path(
+        get,
+        path = "/foo",
+        responses(
+            PetCreated,
+            (status = 404, response = NotFound),
+            (status = 500, response = ServerError)
+        )
+    )]
+    #[allow(unused)]
+    fn get_foo() {}
+
+    #[derive(OpenApi)]
+    #[openapi(handlers(get_foo), responses(NotFound, ServerError))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths./foo.get.responses.201.description" = r#""Pet created""#, "Created response description"
+        "paths./foo.get.responses.201.content.application/json.schema.$ref" = r###""#/components/schemas/PetCreated""###, "Created response content ref"
+        "paths./foo.get.responses.404.$ref" = r###""#/components/responses/NotFound""###, "Not found response ref"
+        "paths./foo.get.responses.500.$ref" = r###""#/components/responses/ServerError""###, "Server error response ref"
+        "components.responses.NotFound.description" = r#""Requested resource was not found""#, "Not found response description"
+        "components.responses.NotFound.content" = r#"null"#, "Not found response content"
+        "components.responses.ServerError.description" = r#""Unexpected error""#, "Server error response description"
+        "components.responses.ServerError.content.text/xml.schema.$ref" = r###""#/components/schemas/ServerError""###, "Server error response content ref"
+    }
+}
+
+#[allow(unused)]
+struct Json<T>(T);
+
+#[allow(unused)]
+struct Pet {
+    name: String,
+}
+
+#[test]
+fn derive_path_with_inferred_responses() {
+    #[allow(unused)]
+    mod inferred_responses {
+        use super::{Json, MyErrors, Pet};
+
+        #[utoipa::path(get, path = "/foo", infer_responses(error))]
+        fn get_foo() -> Result<Json<Vec<Pet>>, MyErrors> {
+            Ok(Json(Vec::new()))
+        }
+    }
+
+    let doc = api_doc!(module: inferred_responses);
+
+    assert_value! {doc=>
+        "responses.200.description" = r#""OK""#, "Success response description"
+        "responses.200.content.application/json.schema.type" = r#""array""#, "Success response content type"
+        "responses.200.content.application/json.schema.items.$ref" = r###""#/components/schemas/Pet""###, "Success response content items ref"
+        "responses.401.description" = r#""Missing or invalid credentials""#, "Unauthorized response description"
+        "responses.404.description" = r#""Resource not found""#, "Not found response description"
+        "responses.500.description" = r#""Unexpected error""#, "Server error response description"
+    }
+}
+
+#[allow(unused)]
+struct PagedErrors<T>(T);
+
+impl<T> utoipa::IntoResponses for PagedErrors<T> {
+    fn responses() -> std::collections::BTreeMap<
+        String,
+        utoipa::openapi::RefOr<utoipa::openapi::response::Response>,
+    > {
+        utoipa::openapi::ResponsesBuilder::new()
+            .response(
+                "400",
+                utoipa::openapi::ResponseBuilder::new().description("Invalid page"),
+            )
+            .build()
+            .responses
+    }
+}
+
+#[test]
+fn derive_path_with_inferred_responses_of_maps_and_generic_errors() {
+    #[allow(unused)]
+    mod inferred_map_response {
+        use std::collections::HashMap;
+
+        use super::{Json, PagedErrors, Pet};
+
+        #[utoipa::path(
+            get,
+            path = "/foo",
+            infer_responses(error, description = "Pets by name")
+        )]
+        fn get_foo() -> Result<Json<HashMap<String, Pet>>, PagedErrors<Pet>> {
+            Ok(Json(HashMap::new()))
+        }
+    }
+
+    let doc = api_doc!(module: inferred_map_response);
+
+    assert_value! {doc=>
+        "responses.200.description" = r#""Pets by name""#, "Success response description"
+        "responses.200.content.application/json.schema.type" = r#""object""#, "Success response content type"
+        "responses.200.content.application/json.schema.additionalProperties.$ref" = r###""#/components/schemas/Pet""###, "Success response content values ref"
+        "responses.400.description" = r#""Invalid page""#, "Invalid page response description"
+    }
+}
+
+#[test]
+fn derive_path_with_inferred_responses_without_error_responses() {
+    #[allow(unused)]
+    mod inferred_success_response {
+        use super::{Json, Pet};
+
+        #[utoipa::path(get, path = "/foo", infer_responses)]
+        fn get_foo() -> Result<Json<Pet>, std::io::Error> {
+            Ok(Json(Pet {
+                name: String::new(),
+            }))
+        }
+    }
+
+    let doc = api_doc!(module: inferred_success_response);
+
+    assert_value! {doc=>
+        "responses.200.content.application/json.schema.$ref" = r###""#/components/schemas/Pet""###, "Success response content ref"
+    }
+    assert_eq!(doc["responses"].as_object().unwrap().len(), 1);
+}
+
+#[test]
+fn derive_path_with_inferred_error_responses_disabled() {
+    #[allow(unused)]
+    mod inferred_error_responses_disabled {
+        use super::MyErrors;
+
+        #[utoipa::path(get, path = "/foo", infer_responses(error = false))]
+        fn get_foo() -> Result<String, MyErrors> {
+            Ok(String::new())
+        }
+    }
+
+    let doc = api_doc!(module: inferred_error_responses_disabled);
+
+    assert_value! {doc=>
+        "responses.200.content.text/plain.schema.type" = r#""string""#, "Success response content type"
+    }
+    assert_eq!(doc["responses"].as_object().unwrap().len(), 1);
+}
+
+#[test]
+fn derive_path_with_inferred_responses_overridden() {
+    #[allow(unused)]
+    mod inferred_responses_overridden {
+        #[utoipa::path(
+            get,
+            path = "/foo",
+            infer_responses,
+            responses(
+                (status = 200, description = "Pet names", body = String, content_type = "text/csv")
+            )
+        )]
+        fn get_foo() -> String {
+            String::new()
+        }
+    }
+
+    let doc = api_doc!(module: inferred_responses_overridden);
+
+    assert_value! {doc=>
+        "responses.200.description" = r#""Pet names""#, "Success response description"
+        "responses.200.content.text/csv.schema.type" = r#""string""#, "Success response content type"
+        "responses.200.content.text/plain" = r#"null"#, "Inferred response content"
+    }
+}
+
+#[test]
+fn derive_path_without_inferred_responses() {
+    #[allow(unused)]
+    mod not_inferred_responses {
+        use super::{Json, Pet};
+
+        #[utoipa::path(get, path = "/foo", responses((status = 204, description = "No content")))]
+        fn get_foo() -> Json<Pet> {
+            Json(Pet {
+                name: String::new(),
+            })
+        }
+    }
+
+    let doc = api_doc!(module: not_inferred_responses);
+
+    assert_value! {doc=>
+        "responses.200" = r#"null"#, "Success response"
+        "responses.204.description" = r#""No content""#, "No content response description"
+    }
+}
+
+test_fn! {
+    module: response_with_status_ranges_and_default,
+    responses: (
+        (status = default, description = "Unexpected error"),
+        (status = "4XX", description = "Client error", body = String),
+        (status = 404, description = "Not found"),
+        (status = "200", description = "Success"),
+        (status = "2XX", description = "Other success")
+    )
+}
+
+#[test]
+fn derive_response_with_status_ranges_and_default() {
+    let doc = api_doc!(module: response_with_status_ranges_and_default);
+
+    assert_value! {doc=>
+        "responses.200.description" = r#""Success""#, "Success response description"
+        "responses.2XX.description" = r#""Other success""#, "Success range response description"
+        "responses.404.description" = r#""Not found""#, "Not found response description"
+        "responses.4XX.description" = r#""Client error""#, "Client error range response description"
+        "responses.4XX.content.text/plain.schema.type" = r#""string""#, "Client error range response content type"
+        "responses.default.description" = r#""Unexpected error""#, "Default response description"
+    }
+
+    let responses = doc
+        .get("responses")
+        .and_then(|responses| responses.as_object())
+        .unwrap();
+    assert_eq!(
+        responses.keys().collect::<Vec<_>>(),
+        ["200", "2XX", "404", "4XX", "default"]
+    );
+}
+
+#[allow(unused)]
+mod response_links {
+    #[utoipa::path(
+        post,
+        path = "/pets",
+        responses(
+            (status = 201, description = "Pet created", links(
+                ("GetPetById" = (
+                    operation_id = "get_pet_by_id",
+                    parameters(("id" = "$response.body#/id"), ("verbose" = json!(true))),
+                    description = "Id of created pet can be used to get the pet"
+                )),
+                ("PetOwner" = (operation_ref = "#/paths/~1owners~1{id}/get", request_body = "$request.body#/owner"))
+            ))
+        )
+    )]
+    fn create_pet() {}
+
+    #[utoipa::path(get, path = "/pets/{id}", responses((status = 200, description = "Pet found")))]
+    fn get_pet_by_id() {}
+
+    #[utoipa::path(
+        delete,
+        path = "/pets/{id}",
+        responses((status = 200, description = "Pet deleted", links(
+            ("Missing" = (operation_id = "undo_delete_pet"))
+        )))
+    )]
+    fn delete_pet() {}
+}
+
+#[test]
+fn derive_response_with_links() {
+    use utoipa::OpenApi;
+
+    #[derive(OpenApi)]
+    #[openapi(handlers(response_links::create_pet, response_links::get_pet_by_id))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let links = common::get_json_path(&doc, "paths./pets.post.responses.201.links");
+
+    assert_value! {links=>
+        "GetPetById.operationId" = r#""get_pet_by_id""#, "Link operation id"
+        "GetPetById.parameters.id" = r#""$response.body#/id""#, "Link id parameter"
+        "GetPetById.parameters.verbose" = r#"true"#, "Link verbose parameter"
+        "GetPetById.description" = r#""Id of created pet can be used to get the pet""#, "Link description"
+        "PetOwner.operationRef" = r###""#/paths/~1owners~1{id}/get""###, "Link operation ref"
+        "PetOwner.operationId" = r#"null"#, "Link operation id"
+        "PetOwner.requestBody" = r#""$request.body#/owner""#, "Link request body"
+    }
+}
+
+#[test]
+fn derive_response_with_link_to_unknown_operation() {
+    use utoipa::OpenApi;
+
+    #[derive(OpenApi)]
+    #[openapi(handlers(response_links::delete_pet))]
+    struct ApiDoc;
+
+    let openapi = ApiDoc::openapi();
+
+    assert_eq!(
+        openapi.unresolved_link_operation_ids(),
+        vec!["undo_delete_pet"]
+    );
+}
@@ -204,3 +204,28 @@ fn derive_request_body_complex_primitive_array_success() {
         "paths./foo.post.requestBody.description" = r###""Create new foo references""###, "Request body description"
     }
 }
+
+test_fn! {
+    module: derive_request_body_with_examples,
+    body: (content = Foo, examples(
+        ("Foo" = (summary = "Foo example", value = json!({"name": "foo"}))),
+        ("Bar" = (value = json!({"name": "bar"})))
+    ))
+}
+
+#[test]
+fn derive_request_body_with_examples_success() {
+    #[derive(OpenApi, Default)]
+    #[openapi(handlers(derive_request_body_with_examples::post_foo))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_value! {doc=>
+        "paths./foo.post.requestBody.content.application/json.schema.$ref" = r###""#/components/schemas/Foo""###, "Request body content object type"
+        "paths./foo.post.requestBody.content.application/json.examples.Foo.summary" = r###""Foo example""###, "Foo example summary"
+        "paths./foo.post.requestBody.content.application/json.examples.Foo.value" = r###"{"name":"foo"}"###, "Foo example value"
+        "paths./foo.post.requestBody.content.application/json.examples.Bar.summary" = r###"null"###, "Bar example summary"
+        "paths./foo.post.requestBody.content.application/json.examples.Bar.value" = r###"{"name":"bar"}"###, "Bar example value"
+    }
+}
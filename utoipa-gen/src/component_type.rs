@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use quote::{quote, ToTokens};
+use syn::{parse::Parse, Error, ExprPath, LitStr};
 
 /// Tokenizes OpenAPI data type correctly according to the Rust type
 pub struct ComponentType<'a, T: Display>(pub &'a T);
@@ -186,3 +187,50 @@ impl<T: Display> ToTokens for ComponentFormat<T> {
         }
     }
 }
+
+/// User defined format given with `format = ...` attribute. Format can either be a path
+/// to a [`ComponentFormat`] variant e.g. `ComponentFormat::Binary` or any string literal
+/// e.g. `"email"` which is converted to `ComponentFormat` at runtime.
+///
+/// [`ComponentFormat`]: ../utoipa/openapi/schema/enum.ComponentFormat.html
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ComponentFormatAttr {
+    Path(ExprPath),
+    Str(LitStr),
+}
+
+impl Parse for ComponentFormatAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Self::Str(input.parse()?));
+        }
+
+        let format = input.parse::<ExprPath>().map_err(|error| {
+            Error::new(
+                error.span(),
+                format!(
+                    "unparseable format expected expression path e.g. ComponentFormat::String or string literal e.g. \"email\", {}",
+                    error
+                ),
+            )
+        })?;
+
+        if format.path.segments.first().unwrap().ident != "utoipa" {
+            let appended_path: ExprPath = syn::parse_quote!(utoipa::openapi::#format);
+            Ok(Self::Path(appended_path))
+        } else {
+            Ok(Self::Path(format))
+        }
+    }
+}
+
+impl ToTokens for ComponentFormatAttr {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Self::Path(path) => path.to_tokens(tokens),
+            Self::Str(format) => tokens.extend(quote! {
+                utoipa::openapi::ComponentFormat::from(#format)
+            }),
+        }
+    }
+}
@@ -15,17 +15,17 @@ use schema::component::Component;
 use ext::{PathOperationResolver, PathOperations, PathResolver};
 use openapi::OpenApi;
 use proc_macro::TokenStream;
-use proc_macro_error::{proc_macro_error, OptionExt, ResultExt};
+use proc_macro_error::{abort, proc_macro_error, OptionExt, ResultExt};
 use quote::{quote, ToTokens, TokenStreamExt};
 #[cfg(feature = "actix_extras")]
 use schema::into_params::IntoParams;
 
-use proc_macro2::{Group, Ident, Punct, TokenStream as TokenStream2};
+use proc_macro2::{Group, Ident, Punct, Span, TokenStream as TokenStream2};
 use syn::{
-    bracketed,
+    bracketed, parenthesized,
     parse::{Parse, ParseBuffer, ParseStream},
     punctuated::Punctuated,
-    token::Bracket,
+    token::{Bracket, Paren},
     DeriveInput, ExprPath, ItemFn, Lit, LitStr, Token,
 };
 
@@ -37,7 +37,10 @@ mod path;
 mod schema;
 mod security_requirement;
 
-use crate::path::{Path, PathAttr};
+use crate::path::{
+    response::derive::{IntoResponses, ToResponse},
+    Path, PathAttr,
+};
 
 #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
 use ext::ArgumentResolver;
@@ -58,42 +61,85 @@ use ext::ArgumentResolver;
 /// OpenAPI. OpenAPI has only a boolean flag to determine deprecation. While it is totally okay to declare deprecated with reason
 /// `#[deprecated  = "There is better way to do this"]` the reason would not render in OpenAPI spec.
 ///
+/// Fields of non primitive types are always referenced with _`$ref`_ to the registered component
+/// and never inlined. This also applies to types wrapped in `Box`, `Vec`, `Option` and maps, so recursive
+/// types such as `struct Node { children: Vec<Node>, parent: Option<Box<Node>> }` and mutually
+/// recursive types are supported out of the box. Maps are documented as objects with the type of the
+/// map values as _`additionalProperties`_. Arbitrary values and maps of them such as `HashMap<String, serde_json::Value>`
+/// are documented as untyped objects. The value type must be written as `serde_json::Value` since imports cannot be
+/// resolved and plain `Value` is treated as any other type.
+///
 /// # Struct Optional Configuration Options for `#[component(...)]`
-/// * `example = ...` Can be either _`json!(...)`_ or literal string that can be parsed to json. _`json!`_
-///   should be something that _`serde_json::json!`_ can parse as a _`serde_json::Value`_. [^json]
+/// * `example = ...` Can be either _`json!(...)`_, method reference or literal string that can be parsed to json. _`json!`_
+///   should be something that _`serde_json::json!`_ can parse as a _`serde_json::Value`_. Method reference
+///   should return a value implementing _`serde::Serialize`_ which is serialized with _`serde_json::to_value`_
+///   when the OpenAPI spec is built. [^json]
+/// * `example_from_default` Use value returned by the [`Default`] implementation of the struct as an example.
+///   This is shorthand for `example = Self::default`. [^json]
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to Structs.
+/// * `extensions(...)` Specification extensions of the struct in format `"x-name" = value` where value
+///   can be literal value, method reference or _`json!(...)`_. [^json2] Extension names must start with _`x-`_.
 ///  
 /// [^json]: **json** feature need to be enabled for _`json!(...)`_ type to work.
 ///
 /// # Enum Optional Configuration Options for `#[component(...)]`
 /// * `example = ...` Can be literal value, method reference or _`json!(...)`_. [^json2]
 /// * `default = ...` Can be literal value, method reference or _`json!(...)`_. [^json2]
+/// * `variant_docs = ...` Defines how doc comments and deprecation of the variants of simple enum
+///   are documented. By default variant docs are not included in the OpenAPI spec.
+///   * `OneOf` Enum is documented as _`oneOf`_ of single value string properties each having the
+///     description and deprecation of the variant. Cannot be used together with `example` or `default`
+///     since _`oneOf`_ component does not have them, use `Extensions` instead to keep them.
+///   * `Extensions` Enum is documented as usual with _`x-enum-varnames`_ and _`x-enum-descriptions`_
+///     extensions listing variant names and descriptions in order of the enum values. **json** feature
+///     need to be enabled.
+/// * `extensions(...)` Specification extensions of the enum in format `"x-name" = value` where value
+///   can be literal value, method reference or _`json!(...)`_. [^json2] Extension names must start with _`x-`_.
 ///
 /// # Unnamed Field Struct Optional Configuration Options for `#[component(...)]`
 /// * `example = ...` Can be literal value, method reference or _`json!(...)`_. [^json2]
+/// * `example_from_default` Use value returned by the [`Default`] implementation of the struct as an example. [^json]
 /// * `default = ...` Can be literal value, method reference or _`json!(...)`_. [^json2]
-/// * `format = ...` [`ComponentFormat`][format] to use for the property. By default the format is derived from
+/// * `format = ...` [`ComponentFormat`][format] to use for the property. Format can be either a variant of
+///   [`ComponentFormat`][format] e.g. `ComponentFormat::Binary` or any literal string e.g. `"email"`. Unknown
+///   strings are used as [`ComponentFormat::Custom`][custom]. By default the format is derived from
 ///   the type of the property according OpenApi spec.
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases the where default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not components nor primitive types. With **value_type** we can enforce
 ///   type used to certain type. Value type may only be [`primitive`][primitive] type or [`String`]. Generic types are not allowed.
+/// * `extensions(...)` Specification extensions of the struct in format `"x-name" = value` where value
+///   can be literal value, method reference or _`json!(...)`_. [^json2] Extension names must start with _`x-`_.
 ///
 /// # Named Fields Optional Configuration Options for `#[component(...)]`
 /// * `example = ...` Can be literal value, method reference or _`json!(...)`_. [^json2]
 /// * `default = ...` Can be literal value, method reference or _`json!(...)`_. [^json2]
-/// * `format = ...` [`ComponentFormat`][format] to use for the property. By default the format is derived from
+/// * `format = ...` [`ComponentFormat`][format] to use for the property. Format can be either a variant of
+///   [`ComponentFormat`][format] e.g. `ComponentFormat::Binary` or any literal string e.g. `"email"`. Unknown
+///   strings are used as [`ComponentFormat::Custom`][custom]. By default the format is derived from
 ///   the type of the property according OpenApi spec.
 /// * `write_only` Defines property is only used in **write** operations *POST,PUT,PATCH* but not in *GET*
 /// * `read_only` Defines property is only used in **read** operations *GET* but not in *POST,PUT,PATCH*
+/// * `nullable` Defines property value can be `null`. This is serialized as _`nullable: true`_ in
+///   OpenAPI 3.0 and as _`null`_ type e.g. _`"type": ["string", "null"]`_ in OpenAPI 3.1.
 /// * `xml(...)` Can be used to define [`Xml`][xml] object properties applicable to named fields.
 /// * `value_type = ...` Can be used to override default type derived from type of the field used in OpenAPI spec.
 ///   This is useful in cases the where default type does not correspond to the actual type e.g. when
 ///   any third-party types are used which are not components nor primitive types. With **value_type** we can enforce
 ///   type used to certain type. Value type may only be [`primitive`][primitive] type or [`String`]. Generic types are not allowed.
+/// * `extensions(...)` Specification extensions of the field in format `"x-name" = value` where value
+///   can be literal value, method reference or _`json!(...)`_. [^json2] Extension names must start with _`x-`_.
 ///
 /// [^json2]: Values are converted to string if **json** feature is not enabled.
 ///
+/// # Examples from files
+///
+/// Any `example = ...` can also be loaded from a file with _`file("...")`_ e.g.
+/// _`example = file("examples/pet.json")`_. The path is resolved relative to `CARGO_MANIFEST_DIR` and
+/// the file content is embedded to the generated code at compile time. Compilation fails if the file
+/// does not contain valid JSON. Files with _`.yaml`_ or _`.yml`_ extension are parsed as YAML which
+/// requires **yaml** feature. **json** feature need to be enabled for _`file("...")`_ to work.
+///
 /// # Xml attribute Configuration Options
 ///
 /// * `xml(name = "...")` Will set name for property or type.
@@ -139,6 +185,41 @@ use ext::ArgumentResolver;
 /// }
 /// ```
 ///
+/// # schemars support
+///
+/// With **schemars** feature enabled types deriving [schemars](https://crates.io/crates/schemars) `JsonSchema` can be
+/// marked with `#[component(schemars)]`. The component is then generated with schemars instead and all other
+/// `#[component(...)]` attributes are ignored. Types referenced by the schemars schema are registered to
+/// components alongside with the marked component. Marked types can be used as field types, request and response
+/// bodies and components like any other component.
+/// ```rust
+/// # #[cfg(feature = "schemars")]
+/// # {
+/// # use utoipa::Component;
+/// #[derive(schemars::JsonSchema, Component)]
+/// #[component(schemars)]
+/// struct Pet {
+///     id: u64,
+///     name: String,
+/// }
+/// # }
+/// ```
+///
+/// # `#[validate(...)]` attributes support
+///
+/// With **validator** feature enabled Component derive reads [validator](https://crates.io/crates/validator)
+/// crate's `#[validate(...)]` attributes from named fields. Validations which have equivalent in OpenAPI
+/// are added to the property, others are ignored.
+///
+/// * `email` Sets format of the property to `email` unless _`format`_ is defined with `#[component(...)]`.
+/// * `url` Sets format of the property to `uri` unless _`format`_ is defined with `#[component(...)]`.
+/// * `length(min = ..., max = ..., equal = ...)` Sets _`minLength`_ and _`maxLength`_ of string properties
+///   or _`minItems`_ and _`maxItems`_ of array properties.
+/// * `range(min = ..., max = ...)` Sets _`minimum`_ and _`maximum`_ of the property.
+/// * `regex = "..."` or `regex(path = ...)` Sets _`pattern`_ of the property from the referenced regex.
+///
+/// Other arguments of the validations such as `message` and `code` are ignored.
+///
 /// # Generic components with aliases
 ///
 /// Components can also be generic which allows reusing types. This enables certain behaviour patters
@@ -182,6 +263,30 @@ use ext::ArgumentResolver;
 /// }
 /// ```
 ///
+/// Struct level example can also be a method reference or the [`Default`] implementation of the struct.
+/// ```rust
+/// # use utoipa::Component;
+/// # use serde::Serialize;
+/// #[derive(Component, Serialize, Default)]
+/// #[component(example_from_default)]
+/// struct Pet {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// #[derive(Component, Serialize)]
+/// #[component(example = Owner::example)]
+/// struct Owner {
+///     name: String,
+/// }
+///
+/// impl Owner {
+///     fn example() -> Self {
+///         Self { name: "bob".to_string() }
+///     }
+/// }
+/// ```
+///
 /// The `component` attribute can also be placed at field level as follows.
 /// ```rust
 /// # use utoipa::Component;
@@ -279,6 +384,18 @@ use ext::ArgumentResolver;
 /// }
 /// ```
 ///
+/// Use string formats which do not have a Rust type of their own.
+/// ```rust
+/// # use utoipa::Component;
+/// #[derive(Component)]
+/// struct User {
+///     #[component(format = "email")]
+///     email: String,
+///     #[component(format = "decimal")]
+///     balance: String,
+/// }
+/// ```
+///
 /// Enforce type being used in OpenAPI spec to [`String`] with `value_type` option.
 /// ```rust
 /// # use utoipa::Component;
@@ -290,6 +407,7 @@ use ext::ArgumentResolver;
 /// [c]: trait.Component.html
 /// [format]: openapi/schema/enum.ComponentFormat.html
 /// [binary]: openapi/schema/enum.ComponentFormat.html#variant.Binary
+/// [custom]: openapi/schema/enum.ComponentFormat.html#variant.Custom
 /// [xml]: openapi/xml/struct.Xml.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 pub fn derive_component(input: TokenStream) -> TokenStream {
@@ -336,8 +454,17 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// * `request_body = ... | request_body(...)` Defining request body indicates that the request is expecting request body within
 ///   the performed request.
 /// * `responses(...)` Slice of responses the endpoint is going to possibly return to the caller.
+/// * `infer_responses` Opt in to infer responses from the return type of the handler function. Use
+///   `infer_responses(error)` to also infer error responses from the error type of returned `Result` and
+///   `infer_responses(description = "...")` to define description of the inferred success response. See
+///   [Inferred Responses](#inferred-responses) for more details.
 /// * `params(...)` Slice of params that the endpoint accepts.
 /// * `security(...)` List of [`SecurityRequirement`][security]s local to the path operation.
+/// * `callbacks(...)` Named callbacks of the path operation. See [Callback Attributes](#callback-attributes)
+///   for more details.
+/// * `extensions(...)` Specification extensions of the path operation in format `"x-name" = value`
+///   e.g. `extensions("x-internal" = true)`. Value can be literal value, method reference or
+///   _`json!(...)`_. Extension names must start with _`x-`_.
 ///
 ///
 /// # Request Body Attributes
@@ -349,6 +476,8 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 ///   from the `content` attribute. If defined the value should be valid content type such as
 ///   _`application/json`_. By default the content type is _`text/plain`_ for
 ///   [primitive Rust types][primitive] and _`application/json`_ for struct and complex enum types.
+/// * `examples(...)` Define multiple named examples for the request body content. See
+///   [Example Attributes](#example-attributes) for the supported format.
 ///
 /// **Request body supports following formats:**
 ///
@@ -364,7 +493,11 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 ///
 /// # Responses Attributes
 ///
-/// * `status = ...` Is valid http status code. E.g. _`200`_
+/// * `status = ...` Is either valid http status code e.g. _`200`_, range of status codes as str e.g.
+///   _`"4XX"`_ or _`default`_. Supported ranges are _`"1XX"`_, _`"2XX"`_, _`"3XX"`_, _`"4XX"`_ and
+///   _`"5XX"`_. The _`default`_ response is used for all status codes not covered by other responses.
+///   Status is validated at compile time. Responses are ordered so that status codes come before
+///   the range they belong to and _`default`_ is the last one.
 /// * `description = "..."` Define description for the response as str.
 /// * `body = ...` Optional response body object type. When left empty response does not expect to send any
 ///   response body. Should be an identifier or slice. E.g _`Pet`_ or _`[Pet]`_
@@ -376,8 +509,20 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 ///  response content types. E.g _`["application/json", "text/xml"]`_ would indicate that endpoint can return both
 ///  _`json`_ and _`xml`_ formats.
 /// * `headers(...)` Slice of response headers that are returned back to a caller.
-/// * `example = ...` Can be either `json!(...)` or literal str that can be parsed to json. `json!`
-///   should be something that `serde_json::json!` can parse as a `serde_json::Value`. [^json]
+/// * `example = ...` Can be either `json!(...)`, `file("...")` or literal str that can be parsed to json. `json!`
+///   should be something that `serde_json::json!` can parse as a `serde_json::Value`. `file("...")` loads the
+///   example from JSON or YAML file relative to `CARGO_MANIFEST_DIR` at compile time, see
+///   [`Component`][component_examples] for details. [^json]
+/// * `examples(...)` Define multiple named examples for the response body content. This should not be
+///   used together with `example`. See [Example Attributes](#example-attributes) for the supported format.
+/// * `links(...)` Define operation links that can be followed from the response. See
+///   [Link Attributes](#link-attributes) for the supported format.
+/// * `response = ...` Reference to reusable response of type implementing [`ToResponse`][to_response].
+///   This cannot be used together with other attributes than `status`. The response type must be registered
+///   to [`OpenApi`][openapi] with `responses(...)`.
+///
+/// Besides response tuples `responses(...)` also accepts types implementing
+/// [`IntoResponses`][into_responses]. All responses of the type are added to the path operation.
 ///
 /// **Minimal response format:**
 /// ```text
@@ -397,6 +542,92 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// (status = 200, description = "Success response", body = Pet, content_type = ["application/json", "text/xml"])
 /// ```
 ///
+/// **Response status ranges and default response:**
+/// ```text
+/// (status = "4XX", description = "Client error", body = ErrorMessage),
+/// (status = default, description = "Unexpected error")
+/// ```
+///
+/// **Reusable responses:**
+/// ```text
+/// responses(
+///     (status = 200, description = "Success response", body = Pet),
+///     (status = 404, response = NotFound),
+///     MyErrors
+/// )
+/// ```
+///
+/// # Inferred Responses
+///
+/// With `infer_responses` responses are inferred from the return type of the handler function.
+/// Inferred responses are added before the responses defined in `responses(...)` thus explicitly
+/// defined response with same status code will override the inferred one.
+///
+/// * `Json<T>` produces _`200`_ response with `T` as _`application/json`_ response body. Any type
+///   named `Json` is supported e.g. actix-web's `web::Json`, rocket's `Json` and axum's `Json`. `T` is
+///   documented the same way as types of [`Component`][component] fields e.g. `HashMap<String, Pet>`
+///   is documented as object with `Pet` values.
+/// * `String` and `&str` produce _`200`_ response with _`text/plain`_ response body.
+/// * `Result<T, E>` produces the success response from `T`. Responses of `E` are added only with
+///   `infer_responses(error)` or `infer_responses(error = true)` in which case `E` must implement
+///   [`IntoResponses`][into_responses]. Error types such as `actix_web::Error` which do not implement it
+///   are ignored with plain `infer_responses`.
+///
+/// Other return types such as `impl Responder` do not produce any responses. Types are recognized by
+/// their name since macros cannot resolve type aliases or imports. Description of the success response
+/// is _`OK`_ unless defined with `infer_responses(description = "...")`.
+///
+/// ```text
+/// #[utoipa::path(get, path = "/pets", infer_responses(error))]
+/// async fn get_pets() -> Result<Json<Vec<Pet>>, MyErrors> { ... }
+///
+/// #[utoipa::path(get, path = "/pets/{id}", infer_responses)]
+/// async fn get_pet(id: Path<u64>) -> Result<Json<Pet>, actix_web::Error> { ... }
+/// ```
+///
+/// # Example Attributes
+///
+/// * `name` Name of the example. Name is defined first and it is followed by equals sign and the rest of the
+///   attributes within parentheses.
+/// * `summary = "..."` Short summary of the example.
+/// * `description = "..."` Long description of the example. Markdown syntax is supported.
+/// * `value = ...` Embedded example value. Can be literal value, method reference or _`json!(...)`_. [^json]
+/// * `external_value = "..."` Url pointing to the example value if the value cannot be embedded. This
+///   should not be used together with `value`.
+///
+/// **Example supported formats:**
+///
+/// ```text
+/// examples(
+///     ("Demo" = (summary = "Demo pet", value = json!({"id": 1, "name": "bob the cat"}))),
+///     ("Remote" = (external_value = "https://example.com/pet.json")),
+/// )
+/// ```
+///
+/// # Link Attributes
+///
+/// * `name` Name of the link. Name is defined first and it is followed by equals sign and the rest of the
+///   attributes within parentheses.
+/// * `operation_id = "..."` Operation id of the linked operation. The operation should exist in the
+///   [`OpenApi`][openapi] the path is registered to. Operation ids which do not exist can be found with
+///   [`OpenApi::unresolved_link_operation_ids`][unresolved_links].
+/// * `operation_ref = "..."` Relative or absolute reference to the linked operation. This should not be
+///   used together with `operation_id`.
+/// * `parameters(...)` Parameters passed to the linked operation in format _`("name" = value)`_. Value
+///   can be literal value, runtime expression such as _`"$response.body#/id"`_ or _`json!(...)`_. [^json]
+/// * `request_body = ...` Literal value, runtime expression or _`json!(...)`_ used as request body of the
+///   linked operation. [^json]
+/// * `description = "..."` Description of the link. Markdown syntax is supported.
+///
+/// **Link supported formats:**
+///
+/// ```text
+/// links(
+///     ("GetPetById" = (operation_id = "get_pet_by_id", parameters(("id" = "$response.body#/id")))),
+///     ("Owner" = (operation_ref = "#/paths/~1owners~1{id}/get", description = "Owner of the pet")),
+/// )
+/// ```
+///
 /// # Response Header Attributes
 ///
 /// * `name` Name of the header. E.g. _`x-csrf-token`_
@@ -426,6 +657,9 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
 /// * `example = ...` Can be literal value, method reference or _`json!(...)`_. [^json]. Given example
 ///   will override any example in underlying parameter type.
+/// * `format = ...` [`ComponentFormat`][format] of the parameter type. Can be either a variant of
+///   [`ComponentFormat`][format] or any literal string e.g. `"date"`. Given format will override the format
+///   derived from the parameter type.
 ///
 /// **Params supports following representation formats:**
 ///
@@ -453,6 +687,28 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// Leaving empty _`()`_ creates an empty [`SecurityRequirement`][security] this is useful when
 /// security requirement is optional for operation.
 ///
+/// # Callback Attributes
+///
+/// * `name` Name of the callback. Name is defined first and it is followed by equals sign and the
+///   callback expressions within parentheses.
+/// * `expression = handler` Runtime expression resolving the url of the callback request e.g.
+///   _`"{$request.body#/callbackUrl}"`_ followed by equals sign and path to a function decorated with
+///   `#[utoipa::path(...)]` describing the callback operation. The `path` of the callback operation is
+///   not used since the expression defines the url. Same expression can be given multiple times to
+///   define multiple operations for the callback request.
+///
+/// **Callback supported formats:**
+///
+/// ```text
+/// callbacks(
+///     ("onPetCreated" = ("{$request.body#/callbackUrl}" = pet_created_callback)),
+///     ("onPetEvent" = (
+///         "{$request.body#/eventUrl}" = pet_updated_callback,
+///         "{$request.body#/eventUrl}" = pet_deleted_callback,
+///     )),
+/// )
+/// ```
+///
 /// # actix_extras support for actix-web
 ///
 /// **actix_extras** feature gives **utoipa** ability to parse path operation information from **actix-web** types and macros.
@@ -624,6 +880,13 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [into_params]: trait.IntoParams.html
 /// [style]: openapi/path/enum.ParameterStyle.html
+/// [format]: openapi/schema/enum.ComponentFormat.html
+/// [component_validate]: derive.Component.html#validate-attributes-support
+/// [component_examples]: derive.Component.html#examples-from-files
+/// [to_response]: trait.ToResponse.html
+/// [into_responses]: trait.IntoResponses.html
+/// [component]: derive.Component.html
+/// [unresolved_links]: openapi/struct.OpenApi.html#method.unresolved_link_operation_ids
 ///
 /// [^json]: **json** feature need to be enabled for `json!(...)` type to work.
 ///
@@ -631,12 +894,12 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
 pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     let path_attribute = syn::parse_macro_input!(attr as PathAttr);
 
-    #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
-    let mut path_attribute = path_attribute;
-
     let ast_fn = syn::parse::<ItemFn>(item).unwrap_or_abort();
     let fn_name = &*ast_fn.sig.ident.to_string();
 
+    let mut path_attribute = path_attribute;
+    path_attribute.update_responses(&ast_fn.sig.output);
+
     let mut resolved_operation = PathOperations::resolve_operation(&ast_fn);
 
     let resolved_path = PathOperations::resolve_path(
@@ -661,14 +924,7 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         .path_operation(resolved_operation.map(|operation| operation.path_operation))
         .path(|| resolved_path.map(|path| path.path))
         .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
-        .deprecated(ast_fn.attrs.iter().find_map(|attr| {
-            if !matches!(attr.path.get_ident(), Some(ident) if &*ident.to_string() == "deprecated")
-            {
-                None
-            } else {
-                Some(true)
-            }
-        }));
+        .deprecated(get_fn_deprecated(&ast_fn));
 
     quote! {
         #path
@@ -677,6 +933,94 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Check whether function is marked with `#[deprecated]` attribute.
+fn get_fn_deprecated(ast_fn: &ItemFn) -> Option<bool> {
+    ast_fn.attrs.iter().find_map(|attr| {
+        if !matches!(attr.path.get_ident(), Some(ident) if &*ident.to_string() == "deprecated") {
+            None
+        } else {
+            Some(true)
+        }
+    })
+}
+
+#[proc_macro_error]
+#[proc_macro_attribute]
+/// Webhook attribute macro
+///
+/// Attribute macro which is placed on a function describing a webhook: a request the API sends to
+/// the consumers instead of a request the API receives. The macro keeps the function as is and
+/// generates an implementation of [`Path`][path] trait for it. Webhooks are registered to
+/// [`OpenApi`][openapi] with _`webhooks(...)`_ and they are serialized to the _`webhooks`_ of
+/// the OpenAPI 3.1 document.
+///
+/// Webhook accepts the same attributes as [`#[utoipa::path(...)]`][path_macro] except `path` and
+/// `context_path`. Instead the webhook must be given a unique `name` which is used as the key in
+/// the _`webhooks`_ map. Webhooks are not resolved from framework specific attributes such as
+/// _`#[post(...)]`_ even if **actix_extras** or **rocket_extras** is enabled.
+///
+/// # Webhook Attributes
+///
+/// * `operation` _**Must be first parameter!**_ Http operation of the webhook request e.g. _`post`_.
+/// * `name = "..."` Unique name of the webhook.
+/// * Any other attribute of [`#[utoipa::path(...)]`][path_macro] e.g. `operation_id`, `tag`,
+///   `request_body`, `responses(...)`, `params(...)`, `security(...)`, `callbacks(...)` and
+///   `extensions(...)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use utoipa::{OpenApi, Component};
+/// #[derive(Component)]
+/// struct Pet {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// /// New pet has been created
+/// #[utoipa::webhook(
+///     post,
+///     name = "petCreated",
+///     request_body = Pet,
+///     responses(
+///         (status = 200, description = "Webhook received successfully")
+///     )
+/// )]
+/// fn pet_created() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(openapi_version = "3.1", webhooks(pet_created), components(Pet))]
+/// struct ApiDoc;
+/// ```
+///
+/// [path]: trait.Path.html
+/// [path_macro]: attr.path.html
+/// [openapi]: derive.OpenApi.html
+pub fn webhook(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut webhook_attribute = syn::parse_macro_input!(attr with PathAttr::parse_webhook);
+    if webhook_attribute.path.is_none() {
+        abort! {
+            Span::call_site(), "webhook name is not defined";
+            help = r###"Try to define it in #[utoipa::webhook(name = "...")]"###
+        }
+    }
+
+    let ast_fn = syn::parse::<ItemFn>(item).unwrap_or_abort();
+    let fn_name = &*ast_fn.sig.ident.to_string();
+
+    webhook_attribute.update_responses(&ast_fn.sig.output);
+
+    let webhook = Path::new(webhook_attribute, fn_name)
+        .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
+        .deprecated(get_fn_deprecated(&ast_fn));
+
+    quote! {
+        #webhook
+        #ast_fn
+    }
+    .into()
+}
+
 #[proc_macro_error]
 #[proc_macro_derive(OpenApi, attributes(openapi))]
 /// OpenApi derive macro
@@ -685,10 +1029,23 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// **Accepted argument attributes:**
 ///
+/// * `openapi_version = "..."` OpenAPI version of the document, either _`"3.0"`_ or _`"3.1"`_. Defaults
+///   to _`"3.0"`_. Version defines how the document is serialized, see
+///   [`OpenApiVersion`][openapi_version] for more details. OpenAPI 3.1 requires **json** feature.
 /// * `handlers(...)`  List of method references having attribute [`#[utoipa::path]`][path] macro.
+/// * `webhooks(...)` List of method references having attribute [`#[utoipa::webhook]`][webhook] macro.
+///   Webhooks require _`openapi_version = "3.1"`_.
 /// * `components(...)`  List of [`Component`][component]s in OpenAPI schema.
+/// * `responses(...)` List of types implementing [`ToResponse`][to_response] registered as reusable
+///   responses in OpenAPI components.
 /// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
 ///   See the [trait documentation][modify] for more details.
+/// * `nest(...)` List of other [`OpenApi`][openapi] types nested to this document in format
+///   `("/path/prefix", ApiDoc)` or `("/path/prefix", ApiDoc, tag = "name")`. Every path of the nested
+///   document is prefixed with the given path and optional tag is added to its path operations.
+///   Components, tags and security requirements of the nested document are merged to this document.
+///   See [`OpenApi::nest`][openapi_nest] for more details. Nested documents are merged before
+///   modifiers are applied.
 /// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
 ///   See more details in [`#[utoipa::path(...)]`][path] [attribute macro security options][path_security].
 /// * `tags(...)` List of [`Tag`][tags] which must match the tag _**path operation**_. By default
@@ -697,6 +1054,15 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///   Tag can be used to define extra information for the api to produce richer documentation.
 /// * `external_docs(...)` Can be used to reference external resource to the OpenAPI doc for extended documentation.
 ///   External docs can be in [`OpenApi`][openapi_struct] or in [`Tag`][tags] level.
+/// * `extensions(...)` Specification extensions of the OpenAPI document in format `"x-name" = value`.
+///   Value can be literal value, method reference or _`json!(...)`_. Extension names must start
+///   with _`x-`_. Extensions can also be given to [`Tag`][tags]s e.g.
+///   `tags((name = "pet", extensions("x-display-name" = "Pets")))`.
+///
+/// Conflicting definitions of nested documents are resolved by keeping the first definition. Definitions
+/// of this document take precedence over nested documents and nested documents over the ones nested
+/// after them. Conflicts fail a debug assertion listing the conflicting definitions so that they are
+/// noticed in debug builds, release builds keep the first definition silently.
 ///
 /// OpenApi derive macro will also derive [`Info`][info] for OpenApi specification using Cargo
 /// environment variables.
@@ -705,7 +1071,7 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * env `CARGO_PKG_VERSION` map to info `version`
 /// * env `CARGO_PKG_DESCRIPTION` map info `description`
 /// * env `CARGO_PKG_AUTHORS` map to contact `name` and `email` **only first author will be used**
-/// * env `CARGO_PKG_LICENSE` map to info `license`
+/// * env `CARGO_PKG_LICENSE` map to info `license` name and SPDX `identifier`
 ///
 /// # Examples
 ///
@@ -755,8 +1121,29 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// struct ApiDoc;
 /// ```
 ///
+/// Nest OpenApi documents of api modules under their path prefixes.
+/// ```rust
+/// # use utoipa::OpenApi;
+/// mod pets {
+///     #[utoipa::path(get, path = "/{id}", responses((status = 200, description = "Pet found")))]
+///     pub fn get_pet() {}
+///
+///     #[derive(utoipa::OpenApi)]
+///     #[openapi(handlers(get_pet))]
+///     pub struct PetApi;
+/// }
+///
+/// #[derive(OpenApi)]
+/// #[openapi(nest(("/api/v1/pets", pets::PetApi, tag = "pets")))]
+/// struct ApiDoc;
+///
+/// let openapi = ApiDoc::openapi();
+/// assert!(openapi.paths.get_path_item("/api/v1/pets/{id}").is_some());
+/// ```
+///
 /// [openapi]: trait.OpenApi.html
 /// [openapi_struct]: openapi/struct.OpenApi.html
+/// [openapi_nest]: openapi/struct.OpenApi.html#method.nest
 /// [component]: derive.Component.html
 /// [path]: attr.path.html
 /// [modify]: trait.Modify.html
@@ -764,6 +1151,9 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// [security]: openapi/security/struct.SecurityRequirement.html
 /// [path_security]: attr.path.html#security-requirement-attributes
 /// [tags]: openapi/tag/struct.Tag.html
+/// [to_response]: trait.ToResponse.html
+/// [openapi_version]: openapi/enum.OpenApiVersion.html
+/// [webhook]: attr.webhook.html
 pub fn openapi(input: TokenStream) -> TokenStream {
     let DeriveInput { attrs, ident, .. } = syn::parse_macro_input!(input);
 
@@ -803,6 +1193,12 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `allow_reserved` Defines whether reserved characters _`:/?#[]@!$&'()*+,;=`_ is allowed within value.
 /// * `example = ...` Can be literal value, method reference or _`json!(...)`_. [^json] Given example
 ///   will override any example in underlying parameter type.
+/// * `format = ...` [`ComponentFormat`][format] of the parameter type. Can be either a variant of
+///   [`ComponentFormat`][format] or any literal string e.g. `"date"`. Given format will override the format
+///   derived from the field type.
+///
+/// With **validator** feature enabled `#[validate(...)]` attributes of fields are also reflected to parameter
+/// schemas the same way as with [`Component`][component_validate] derive.
 ///
 /// # IntoParams Attributes for `#[into_params(...)]`
 ///
@@ -869,6 +1265,8 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// [path_params]: attr.path.html#params-attributes
 /// [struct]: https://doc.rust-lang.org/std/keyword.struct.html
 /// [style]: openapi/path/enum.ParameterStyle.html
+/// [format]: openapi/schema/enum.ComponentFormat.html
+/// [component_validate]: derive.Component.html#validate-attributes-support
 ///
 /// [^actix]: Feature **actix_extras** need to be enabled
 ///
@@ -892,6 +1290,154 @@ pub fn into_params(input: TokenStream) -> TokenStream {
     into_params.to_token_stream().into()
 }
 
+#[proc_macro_error]
+#[proc_macro_derive(ToResponse, attributes(response))]
+/// ToResponse derive macro
+///
+/// This is `#[derive]` implementation for [`ToResponse`][to_response] trait. The type is converted
+/// to reusable response which can be registered to [`OpenApi`][openapi] with `responses(...)` and
+/// referenced from [`#[utoipa::path(...)]`][path] with `(status = ..., response = Type)`.
+///
+/// Name of the response is the name of the type. Unit structs have no response body, all other types
+/// use the type itself as response body thus the type should also implement [`Component`][component]
+/// and be registered to [`OpenApi`][openapi] with `components(...)`.
+///
+/// # ToResponse Attributes for `#[response(...)]`
+///
+/// Accepts the same attributes as [`#[utoipa::path(...)]`][path_responses] responses except `status` and
+/// `response`. The `#[response(...)]` attribute is optional and if `description` is not defined the first
+/// line of the doc comment is used as description. `body = ...` can be used to override the response body.
+///
+/// # Examples
+///
+/// ```rust
+/// use utoipa::{Component, OpenApi, ToResponse};
+///
+/// /// Requested resource was not found
+/// #[derive(ToResponse)]
+/// struct NotFound;
+///
+/// #[derive(Component, ToResponse)]
+/// #[response(description = "Unexpected error", content_type = "application/json")]
+/// struct ServerError {
+///     message: String,
+/// }
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/pet/{id}",
+///     responses(
+///         (status = 200, description = "Pet found"),
+///         (status = 404, response = NotFound),
+///         (status = 500, response = ServerError),
+///     )
+/// )]
+/// fn get_pet() {}
+///
+/// #[derive(OpenApi)]
+/// #[openapi(handlers(get_pet), components(ServerError), responses(NotFound, ServerError))]
+/// struct ApiDoc;
+/// ```
+///
+/// [to_response]: trait.ToResponse.html
+/// [openapi]: derive.OpenApi.html
+/// [path]: attr.path.html
+/// [path_responses]: attr.path.html#responses-attributes
+/// [component]: derive.Component.html
+pub fn to_response(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        attrs,
+        ..
+    } = syn::parse_macro_input!(input);
+
+    let to_response = ToResponse {
+        ident,
+        generics,
+        data,
+        attrs,
+    };
+
+    to_response.to_token_stream().into()
+}
+
+#[proc_macro_error]
+#[proc_macro_derive(IntoResponses, attributes(response))]
+/// IntoResponses derive macro
+///
+/// This is `#[derive]` implementation for [`IntoResponses`][into_responses] trait. Type implementing
+/// the trait can be given as is to `responses(...)` of [`#[utoipa::path(...)]`][path] to add all of
+/// its responses to the path operation.
+///
+/// Derive is supported for enums and structs. Each variant of enum is a response of its own and must
+/// have `#[response(...)]` attribute defining at least the `status` of the response. Unit variants have
+/// no response body and variants with single unnamed field use the type of the field as response body.
+/// Structs define the `#[response(...)]` attribute on the type and unit structs have no response
+/// body while other structs use the type itself as response body.
+///
+/// # IntoResponses Attributes for `#[response(...)]`
+///
+/// Accepts the same attributes as [`#[utoipa::path(...)]`][path_responses] responses. If `description`
+/// is not defined the first line of the doc comment is used as description.
+///
+/// # Examples
+///
+/// ```rust
+/// use utoipa::{Component, IntoResponses};
+///
+/// #[derive(Component)]
+/// struct ErrorMessage {
+///     message: String,
+/// }
+///
+/// #[derive(IntoResponses)]
+/// enum MyErrors {
+///     /// Missing or invalid credentials
+///     #[response(status = 401)]
+///     Unauthorized,
+///
+///     #[response(status = 404, description = "Resource not found")]
+///     NotFound(ErrorMessage),
+///
+///     #[response(status = 500, description = "Unexpected error", body = String)]
+///     ServerError { reason: String },
+/// }
+///
+/// #[utoipa::path(
+///     get,
+///     path = "/pet/{id}",
+///     responses(
+///         (status = 200, description = "Pet found"),
+///         MyErrors
+///     )
+/// )]
+/// fn get_pet() {}
+/// ```
+///
+/// [into_responses]: trait.IntoResponses.html
+/// [path]: attr.path.html
+/// [path_responses]: attr.path.html#responses-attributes
+pub fn into_responses(input: TokenStream) -> TokenStream {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        attrs,
+        ..
+    } = syn::parse_macro_input!(input);
+
+    let into_responses = IntoResponses {
+        ident,
+        generics,
+        data,
+        attrs,
+    };
+
+    into_responses.to_token_stream().into()
+}
+
 /// Tokenizes slice or Vec of tokenizable items as array either with reference (`&[...]`)
 /// or without correctly to OpenAPI JSON.
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -1013,7 +1559,6 @@ struct Type<'a> {
 }
 
 impl<'a> Type<'a> {
-    #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
     pub fn new(ident: Cow<'a, Ident>, is_array: bool, is_option: bool) -> Self {
         Self {
             ty: ident,
@@ -1081,7 +1626,7 @@ impl Parse for ExternalDocs {
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
-                syn::Error::new(error.span(), &format!("{}, {}", EXPECTED_ATTRIBUTE, error))
+                syn::Error::new(error.span(), format!("{}, {}", EXPECTED_ATTRIBUTE, error))
             })?;
             let attribute_name = &*ident.to_string();
 
@@ -1124,7 +1669,7 @@ impl ToTokens for ExternalDocs {
 
 /// Represents OpenAPI Any value used in example and default fields.
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub(self) enum AnyValue {
+enum AnyValue {
     String(TokenStream2),
     Json(TokenStream2),
     #[cfg(not(feature = "json"))]
@@ -1157,6 +1702,8 @@ impl AnyValue {
                     Ok(AnyValue::Literal(lit))
                 }
             }
+        } else if Self::is_file(input) {
+            Self::parse_file(input)
         } else {
             let fork = input.fork();
             let is_json = if fork.peek(syn::Ident) && fork.peek2(Token![!]) {
@@ -1202,10 +1749,106 @@ impl AnyValue {
             Ok(AnyValue::String(
                 input.parse::<LitStr>().unwrap().to_token_stream(),
             ))
+        } else if Self::is_file(input) {
+            Self::parse_file(input)
         } else {
             Ok(AnyValue::Json(parse_utils::parse_json_token_stream(input)?))
         }
     }
+
+    fn is_file(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.peek(syn::Ident)
+            && fork.peek2(Paren)
+            && fork.parse::<Ident>().map_or(false, |ident| ident == "file")
+    }
+
+    /// Parse `file("path")` which loads the value from JSON or YAML file relative to
+    /// `CARGO_MANIFEST_DIR` at compile time.
+    fn parse_file(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Ident>()?;
+        let file;
+        parenthesized!(file in input);
+        let file = file.parse::<LitStr>()?;
+
+        #[cfg(not(feature = "json"))]
+        return Err(syn::Error::new(
+            file.span(),
+            "file(...) requires **json** feature to be enabled",
+        ));
+
+        #[cfg(feature = "json")]
+        {
+            let path = std::path::Path::new(
+                &std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| String::from(".")),
+            )
+            .join(file.value());
+            let content = std::fs::read_to_string(&path).map_err(|error| {
+                syn::Error::new(
+                    file.span(),
+                    format!("failed to read file: {}, {}", path.display(), error),
+                )
+            })?;
+
+            let is_yaml = matches!(
+                path.extension().and_then(|extension| extension.to_str()),
+                Some("yaml" | "yml")
+            );
+            let value = if is_yaml {
+                Self::parse_yaml(&content, &file)?
+            } else {
+                serde_json::from_str::<serde_json::Value>(&content).map_err(|error| {
+                    syn::Error::new(
+                        file.span(),
+                        format!("invalid JSON in file: {}, {}", path.display(), error),
+                    )
+                })?
+            };
+
+            let path = path.to_string_lossy();
+            let json = value.to_string();
+            Ok(AnyValue::Json(quote! {
+                ({
+                    // include the file so that changes to it trigger recompilation
+                    const _: &str = include_str!(#path);
+                    serde_json::from_str::<serde_json::Value>(#json)
+                        .expect("file content should be valid JSON")
+                })
+            }))
+        }
+    }
+
+    #[cfg(all(feature = "json", feature = "yaml"))]
+    fn parse_yaml(content: &str, file: &LitStr) -> syn::Result<serde_json::Value> {
+        serde_yaml::from_str::<serde_json::Value>(content).map_err(|error| {
+            syn::Error::new(
+                file.span(),
+                format!("invalid YAML in file: {}, {}", file.value(), error),
+            )
+        })
+    }
+
+    #[cfg(all(feature = "json", not(feature = "yaml")))]
+    fn parse_yaml(_: &str, file: &LitStr) -> syn::Result<serde_json::Value> {
+        Err(syn::Error::new(
+            file.span(),
+            "YAML files require **yaml** feature to be enabled",
+        ))
+    }
+
+    fn parse_lit_str_json_or_method(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            Self::parse_lit_str_or_json(input)
+        } else {
+            Self::parse_any(input)
+        }
+    }
+
+    /// Value created with [`Default`] implementation of the type the component is derived for.
+    #[cfg(feature = "json")]
+    fn new_default_trait() -> Self {
+        AnyValue::Json(quote! { <Self as ::std::default::Default>::default() })
+    }
 }
 
 impl ToTokens for AnyValue {
@@ -1221,6 +1864,59 @@ impl ToTokens for AnyValue {
     }
 }
 
+/// Specification extensions parsed from `extensions("x-name" = value, ...)` attribute.
+///
+/// Name of each extension must start with `x-` and value can be anything [`AnyValue`] accepts.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Extensions(Vec<(LitStr, AnyValue)>);
+
+impl Extensions {
+    /// Tokens of `add(...)` calls for each extension to be chained to `ExtensionsBuilder`.
+    fn to_add_tokens(&self) -> TokenStream2 {
+        self.0
+            .iter()
+            .map(|(name, value)| quote! { .add(#name, #value) })
+            .collect()
+    }
+}
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut extensions = Vec::new();
+
+        while !input.is_empty() {
+            let name = input.parse::<LitStr>()?;
+            if !name.value().starts_with("x-") {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "extension name must start with `x-`",
+                ));
+            }
+
+            let value = parse_utils::parse_next(input, || AnyValue::parse_any(input))?;
+            extensions.push((name, value));
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self(extensions))
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let extensions = self.to_add_tokens();
+
+        tokens.extend(quote! {
+            utoipa::openapi::extensions::ExtensionsBuilder::new()
+                #extensions
+                .build()
+        })
+    }
+}
+
 /// Parsing utils
 mod parse_utils {
     use proc_macro2::{Group, Ident, TokenStream};
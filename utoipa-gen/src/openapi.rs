@@ -1,11 +1,11 @@
 use proc_macro2::Ident;
-use proc_macro_error::ResultExt;
+use proc_macro_error::{abort, ResultExt};
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::{And, Comma},
-    Attribute, Error, ExprPath, GenericParam, Generics, Token,
+    Attribute, Error, ExprPath, GenericParam, Generics, LitStr, Token,
 };
 
 use proc_macro2::TokenStream;
@@ -13,7 +13,7 @@ use quote::{format_ident, quote, quote_spanned, ToTokens};
 
 use crate::{
     parse_utils, path::PATH_STRUCT_PREFIX, security_requirement::SecurityRequirementAttr, Array,
-    ExternalDocs,
+    Extensions, ExternalDocs,
 };
 
 mod info;
@@ -21,12 +21,17 @@ mod info;
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct OpenApiAttr {
+    openapi_version: Option<OpenApiVersion>,
     handlers: Punctuated<ExprPath, Comma>,
+    webhooks: Punctuated<ExprPath, Comma>,
     components: Punctuated<Component, Comma>,
+    responses: Punctuated<ExprPath, Comma>,
     modifiers: Punctuated<Modifier, Comma>,
+    nest: Punctuated<NestOpenApi, Comma>,
     security: Option<Array<SecurityRequirementAttr>>,
     tags: Option<Array<Tag>>,
     external_docs: Option<ExternalDocs>,
+    extensions: Option<Extensions>,
 }
 
 pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Option<OpenApiAttr> {
@@ -39,7 +44,7 @@ pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Option<OpenApiAttr> {
 impl Parse for OpenApiAttr {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute, expected any of: handlers, components, modifiers, security, tags, external_docs";
+            "unexpected attribute, expected any of: openapi_version, handlers, webhooks, components, responses, modifiers, nest, security, tags, external_docs, extensions";
         let mut openapi = OpenApiAttr::default();
 
         while !input.is_empty() {
@@ -49,15 +54,28 @@ impl Parse for OpenApiAttr {
             let attribute = &*ident.to_string();
 
             match attribute {
+                "openapi_version" => {
+                    openapi.openapi_version =
+                        Some(parse_utils::parse_next(input, || input.parse())?);
+                }
                 "handlers" => {
                     openapi.handlers = parse_utils::parse_punctuated_within_parenthesis(input)?;
                 }
+                "webhooks" => {
+                    openapi.webhooks = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                }
                 "components" => {
                     openapi.components = parse_utils::parse_punctuated_within_parenthesis(input)?
                 }
+                "responses" => {
+                    openapi.responses = parse_utils::parse_punctuated_within_parenthesis(input)?
+                }
                 "modifiers" => {
                     openapi.modifiers = parse_utils::parse_punctuated_within_parenthesis(input)?;
                 }
+                "nest" => {
+                    openapi.nest = parse_utils::parse_punctuated_within_parenthesis(input)?;
+                }
                 "security" => {
                     let security;
                     parenthesized!(security in input);
@@ -73,6 +91,11 @@ impl Parse for OpenApiAttr {
                     parenthesized!(external_docs in input);
                     openapi.external_docs = Some(external_docs.parse()?);
                 }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    openapi.extensions = Some(extensions.parse()?);
+                }
                 _ => {
                     return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE));
                 }
@@ -87,6 +110,46 @@ impl Parse for OpenApiAttr {
     }
 }
 
+/// OpenAPI version of the generated document. Supported values are `"3.0"` and `"3.1"` or the
+/// full `"3.0.3"` and `"3.1.0"` versions.
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum OpenApiVersion {
+    Version3,
+    #[cfg(feature = "json")]
+    Version31,
+}
+
+impl Parse for OpenApiVersion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let version = input.parse::<LitStr>()?;
+
+        match &*version.value() {
+            "3.0" | "3.0.3" => Ok(Self::Version3),
+            #[cfg(feature = "json")]
+            "3.1" | "3.1.0" => Ok(Self::Version31),
+            #[cfg(not(feature = "json"))]
+            "3.1" | "3.1.0" => Err(Error::new(
+                version.span(),
+                "OpenAPI 3.1 requires json feature to be enabled",
+            )),
+            _ => Err(Error::new(
+                version.span(),
+                "unexpected openapi_version, expected one of: 3.0, 3.1",
+            )),
+        }
+    }
+}
+
+impl ToTokens for OpenApiVersion {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Version3 => quote! { utoipa::openapi::OpenApiVersion::Version3 },
+            #[cfg(feature = "json")]
+            Self::Version31 => quote! { utoipa::openapi::OpenApiVersion::Version31 },
+        })
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Component {
     path: ExprPath,
@@ -140,18 +203,91 @@ impl Parse for Modifier {
     }
 }
 
+/// Nested [`OpenApi`] document in format `("/prefix", ApiDoc)` or
+/// `("/prefix", ApiDoc, tag = "name")`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct NestOpenApi {
+    path: LitStr,
+    api: ExprPath,
+    tag: Option<LitStr>,
+}
+
+impl Parse for NestOpenApi {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let path = content.parse::<LitStr>()?;
+        content.parse::<Comma>()?;
+        let api = content.parse::<ExprPath>()?;
+
+        let mut tag = None;
+        if !content.is_empty() {
+            content.parse::<Comma>()?;
+        }
+        if !content.is_empty() {
+            let ident = content.parse::<Ident>()?;
+            if ident != "tag" {
+                return Err(Error::new(
+                    ident.span(),
+                    "unexpected attribute, expected: tag",
+                ));
+            }
+            tag = Some(parse_utils::parse_next(&content, || {
+                content.parse::<LitStr>()
+            })?);
+            if !content.is_empty() {
+                content.parse::<Comma>()?;
+            }
+        }
+
+        Ok(Self { path, api, tag })
+    }
+}
+
+impl ToTokens for NestOpenApi {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let path = &self.path;
+        let api = &self.api;
+
+        let nest = match &self.tag {
+            Some(tag) => quote! {
+                openapi.nest_with_tag(#path, #tag, <#api as utoipa::OpenApi>::openapi())
+            },
+            None => quote! {
+                openapi.nest(#path, <#api as utoipa::OpenApi>::openapi())
+            },
+        };
+
+        tokens.extend(quote! {
+            let conflicts = #nest;
+            debug_assert!(
+                conflicts.is_empty(),
+                "cannot nest `{}` without dropping definitions: {}",
+                stringify!(#api),
+                conflicts
+                    .iter()
+                    .map(::std::string::ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        })
+    }
+}
+
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Tag {
     name: String,
     description: Option<String>,
     external_docs: Option<ExternalDocs>,
+    extensions: Option<Extensions>,
 }
 
 impl Parse for Tag {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected token, expected any of: name, description, external_docs";
+            "unexpected token, expected any of: name, description, external_docs, extensions";
 
         let mut tag = Tag::default();
 
@@ -171,6 +307,11 @@ impl Parse for Tag {
                     parenthesized!(content in input);
                     tag.external_docs = Some(content.parse::<ExternalDocs>()?);
                 }
+                "extensions" => {
+                    let content;
+                    parenthesized!(content in input);
+                    tag.extensions = Some(content.parse::<Extensions>()?);
+                }
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
 
@@ -202,6 +343,12 @@ impl ToTokens for Tag {
             });
         }
 
+        if let Some(ref extensions) = self.extensions {
+            tokens.extend(quote! {
+                .extensions(Some(#extensions))
+            });
+        }
+
         tokens.extend(quote! { .build() })
     }
 }
@@ -213,9 +360,10 @@ impl ToTokens for OpenApi {
         let OpenApi(attributes, ident) = self;
 
         let info = info::impl_info();
-        let components = impl_components(&attributes.components, tokens).map(|components| {
-            quote! { .components(Some(#components)) }
-        });
+        let components = impl_components(&attributes.components, &attributes.responses, tokens)
+            .map(|components| {
+                quote! { .components(Some(#components)) }
+            });
 
         let modifiers = &attributes.modifiers;
         let modifiers_len = modifiers.len();
@@ -230,6 +378,18 @@ impl ToTokens for OpenApi {
 
         let path_items = impl_paths(&attributes.handlers);
 
+        #[cfg(feature = "json")]
+        let is_openapi_31 = matches!(attributes.openapi_version, Some(OpenApiVersion::Version31));
+        #[cfg(not(feature = "json"))]
+        let is_openapi_31 = false;
+        if let Some(webhook) = attributes.webhooks.first().filter(|_| !is_openapi_31) {
+            abort! {
+                webhook, "webhooks are only supported in OpenAPI 3.1";
+                help = r###"Try to define #[openapi(openapi_version = "3.1")]"###
+            }
+        }
+        let webhooks = impl_webhooks(&attributes.webhooks);
+
         let securities = attributes.security.as_ref().map(|securities| {
             quote! {
                 .security(Some(#securities))
@@ -245,18 +405,40 @@ impl ToTokens for OpenApi {
                 .external_docs(Some(#external_docs))
             }
         });
+        let extensions = attributes.extensions.as_ref().map(|extensions| {
+            quote! {
+                .extensions(Some(#extensions))
+            }
+        });
+        let nested = if attributes.nest.is_empty() {
+            None
+        } else {
+            let nest = attributes.nest.iter();
+            Some(quote! {
+                #(#nest;)*
+            })
+        };
+        let openapi_version = attributes
+            .openapi_version
+            .as_ref()
+            .map(|openapi_version| quote! { .openapi(#openapi_version) });
 
         tokens.extend(quote! {
             impl utoipa::OpenApi for #ident {
                 fn openapi() -> utoipa::openapi::OpenApi {
                     use utoipa::{Component, Path};
                     let mut openapi = utoipa::openapi::OpenApiBuilder::new()
+                        #openapi_version
                         .info(#info)
                         .paths(#path_items)
+                        #webhooks
                         #components
                         #securities
                         #tags
-                        #external_docs.build();
+                        #external_docs
+                        #extensions.build();
+
+                    #nested
 
                     let _mods: [&dyn utoipa::Modify; #modifiers_len] = [#modifiers];
                     _mods.iter().for_each(|modifier| modifier.modify(&mut openapi));
@@ -270,9 +452,10 @@ impl ToTokens for OpenApi {
 
 fn impl_components(
     components: &Punctuated<Component, Comma>,
+    responses: &Punctuated<ExprPath, Comma>,
     tokens: &mut TokenStream,
 ) -> Option<TokenStream> {
-    if !components.is_empty() {
+    if !components.is_empty() || !responses.is_empty() {
         let mut components_tokens = components.iter().fold(
             quote! { utoipa::openapi::ComponentsBuilder::new() },
             |mut schema, component| {
@@ -302,11 +485,17 @@ fn impl_components(
                 schema.extend(quote! {
                     .component(#component_name, <#path #ty_generics>::component())
                     .components_from_iter(<#path #ty_generics>::aliases())
+                    .components_from_iter(<#path #ty_generics>::definitions())
                 });
 
                 schema
             },
         );
+        responses.iter().for_each(|response| {
+            components_tokens.extend(quote! {
+                .responses_from_iter([<#response as utoipa::ToResponse>::response()])
+            })
+        });
         components_tokens.extend(quote! { .build() });
         Some(components_tokens)
     } else {
@@ -318,30 +507,7 @@ fn impl_paths(handler_paths: &Punctuated<ExprPath, Comma>) -> TokenStream {
     handler_paths.iter().fold(
         quote! { utoipa::openapi::path::PathsBuilder::new() },
         |mut paths, handler| {
-            let segments = handler.path.segments.iter().collect::<Vec<_>>();
-            let handler_fn_name = &*segments.last().unwrap().ident.to_string();
-
-            let tag = &*segments
-                .iter()
-                .take(segments.len() - 1)
-                .map(|part| part.ident.to_string())
-                .collect::<Vec<_>>()
-                .join("::");
-
-            let handler_ident = format_ident!("{}{}", PATH_STRUCT_PREFIX, handler_fn_name);
-            let handler_ident_name = &*handler_ident.to_string();
-
-            let usage = syn::parse_str::<ExprPath>(
-                &vec![
-                    if tag.is_empty() { None } else { Some(tag) },
-                    Some(handler_ident_name),
-                ]
-                .into_iter()
-                .flatten()
-                .collect::<Vec<_>>()
-                .join("::"),
-            )
-            .unwrap();
+            let (usage, tag) = path_struct_usage(handler);
 
             paths.extend(quote! {
                 .path(#usage::path(), #usage::path_item(Some(#tag)))
@@ -351,3 +517,47 @@ fn impl_paths(handler_paths: &Punctuated<ExprPath, Comma>) -> TokenStream {
         },
     )
 }
+
+fn impl_webhooks(webhooks: &Punctuated<ExprPath, Comma>) -> TokenStream {
+    webhooks
+        .iter()
+        .map(|webhook| {
+            let (usage, tag) = path_struct_usage(webhook);
+
+            quote! {
+                .webhook(#usage::path(), #usage::path_item(Some(#tag)))
+            }
+        })
+        .collect()
+}
+
+/// Resolve usage of the path struct generated for the handler function and the default tag of
+/// the handler derived from the module path of the handler.
+fn path_struct_usage(handler: &ExprPath) -> (ExprPath, String) {
+    let segments = handler.path.segments.iter().collect::<Vec<_>>();
+    let handler_fn_name = &*segments.last().unwrap().ident.to_string();
+
+    let tag = segments
+        .iter()
+        .take(segments.len() - 1)
+        .map(|part| part.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+
+    let handler_ident = format_ident!("{}{}", PATH_STRUCT_PREFIX, handler_fn_name);
+    let handler_ident_name = &*handler_ident.to_string();
+
+    let usage = syn::parse_str::<ExprPath>(
+        &vec![
+            if tag.is_empty() { None } else { Some(&*tag) },
+            Some(handler_ident_name),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("::"),
+    )
+    .unwrap();
+
+    (usage, tag)
+}
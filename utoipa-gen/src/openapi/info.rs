@@ -9,13 +9,23 @@ pub(crate) fn impl_info() -> TokenStream2 {
     let license = std::env::var("CARGO_PKG_LICENSE").unwrap_or_default();
 
     let contact = get_contact(&authors);
+    let license_identifier = if license.is_empty() {
+        None
+    } else {
+        Some(quote! { .identifier(Some(#license)) })
+    };
 
     quote! {
         utoipa::openapi::InfoBuilder::new()
             .title(#name)
             .version(#version)
             .description(Some(#description))
-            .license(Some(utoipa::openapi::License::new(#license)))
+            .license(Some(
+                utoipa::openapi::LicenseBuilder::new()
+                    .name(#license)
+                    #license_identifier
+                    .build()
+            ))
             .contact(Some(#contact))
             .build()
     }
@@ -4,24 +4,30 @@ use std::{io::Error, str::FromStr};
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use proc_macro_error::abort;
 use quote::{format_ident, quote, ToTokens};
-use syn::{parenthesized, parse::Parse, Token};
+use syn::{parenthesized, parse::Parse, token::Paren, ReturnType, Token};
 
-use crate::{component_type::ComponentType, security_requirement::SecurityRequirementAttr, Array};
+use crate::{
+    component_type::ComponentType, security_requirement::SecurityRequirementAttr, Array, Extensions,
+};
 use crate::{parse_utils, Deprecated};
 
 use self::{
+    callback::Callback,
     parameter::Parameter,
     request_body::RequestBodyAttr,
-    response::{Response, Responses},
+    response::{InferResponses, Response, Responses},
 };
 
 #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
 use crate::ext::Argument;
 
+mod callback;
+mod example;
+mod link;
 pub mod parameter;
 mod property;
 mod request_body;
-mod response;
+pub mod response;
 
 pub(crate) const PATH_STRUCT_PREFIX: &str = "__path_";
 
@@ -67,9 +73,28 @@ pub struct PathAttr<'p> {
     params: Option<Vec<Parameter<'p>>>,
     security: Option<Array<SecurityRequirementAttr>>,
     context_path: Option<String>,
+    infer_responses: Option<InferResponses>,
+    callbacks: Vec<Callback>,
+    extensions: Option<Extensions>,
 }
 
 impl<'p> PathAttr<'p> {
+    /// Infer responses from return type of the handler function if `infer_responses` is enabled.
+    /// Inferred responses are added before the explicitly defined responses thus explicit responses
+    /// override the inferred ones with same status code.
+    pub fn update_responses(&mut self, output: &'p ReturnType) {
+        let infer_responses = match self.infer_responses {
+            Some(ref infer_responses) => infer_responses,
+            None => return,
+        };
+
+        if let ReturnType::Type(_, ty) = output {
+            let mut responses = Response::from_return_type(ty, infer_responses);
+            responses.append(&mut self.responses);
+            self.responses = responses;
+        }
+    }
+
     #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
     pub fn update_parameters(&mut self, arguments: Option<Vec<Argument<'p>>>) {
         if let Some(arguments) = arguments {
@@ -156,14 +181,32 @@ impl<'p> PathAttr<'p> {
 
 impl Parse for PathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, path, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, params, tag, security, context_path";
+        PathAttr::parse_attributes(input, false)
+    }
+}
+
+impl PathAttr<'_> {
+    /// Parse `#[utoipa::webhook(...)]` attribute. Webhook is defined with `name` instead of
+    /// `path` and the `name` is used in place of the path of the operation.
+    pub fn parse_webhook(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        PathAttr::parse_attributes(input, true)
+    }
+
+    fn parse_attributes(input: syn::parse::ParseStream, webhook: bool) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, path, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, infer_responses, params, tag, security, context_path, callbacks, extensions";
+        const EXPECTED_WEBHOOK_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: operation_id, name, get, post, put, delete, options, head, patch, trace, connect, request_body, responses, infer_responses, params, tag, security, callbacks, extensions";
+        let expected_attribute_message = if webhook {
+            EXPECTED_WEBHOOK_ATTRIBUTE_MESSAGE
+        } else {
+            EXPECTED_ATTRIBUTE_MESSAGE
+        };
         let mut path_attr = PathAttr::default();
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
                 syn::Error::new(
                     error.span(),
-                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                    format!("{}, {}", expected_attribute_message, error),
                 )
             })?;
             let attribute_name = &*ident.to_string();
@@ -172,6 +215,18 @@ impl Parse for PathAttr<'_> {
                 "operation_id" => {
                     path_attr.operation_id = Some(parse_utils::parse_next_literal_str(input)?);
                 }
+                "path" | "context_path" if webhook => {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!(
+                            "{} is not supported in webhook, did you mean name?",
+                            attribute_name
+                        ),
+                    ));
+                }
+                "name" if webhook => {
+                    path_attr.path = Some(parse_utils::parse_next_literal_str(input)?);
+                }
                 "path" => {
                     path_attr.path = Some(parse_utils::parse_next_literal_str(input)?);
                 }
@@ -179,10 +234,21 @@ impl Parse for PathAttr<'_> {
                     path_attr.request_body = Some(input.parse::<RequestBodyAttr>()?);
                 }
                 "responses" => {
-                    let responses;
-                    parenthesized!(responses in input);
                     path_attr.responses =
-                        parse_utils::parse_groups::<Response, Vec<_>>(&responses)?;
+                        parse_utils::parse_punctuated_within_parenthesis::<Response>(input)?
+                            .into_iter()
+                            .collect();
+                }
+                "infer_responses" => {
+                    path_attr.infer_responses = if input.peek(Paren) {
+                        let infer_responses;
+                        parenthesized!(infer_responses in input);
+                        Some(infer_responses.parse()?)
+                    } else if parse_utils::parse_bool_or_true(input)? {
+                        Some(InferResponses::default())
+                    } else {
+                        None
+                    };
                 }
                 "params" => {
                     let params;
@@ -200,6 +266,16 @@ impl Parse for PathAttr<'_> {
                 "context_path" => {
                     path_attr.context_path = Some(parse_utils::parse_next_literal_str(input)?)
                 }
+                "callbacks" => {
+                    let callbacks;
+                    parenthesized!(callbacks in input);
+                    path_attr.callbacks = parse_utils::parse_groups(&callbacks)?;
+                }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    path_attr.extensions = Some(extensions.parse()?);
+                }
                 _ => {
                     // any other case it is expected to be path operation
                     if let Some(path_operation) =
@@ -207,7 +283,7 @@ impl Parse for PathAttr<'_> {
                     {
                         path_attr.path_operation = Some(path_operation)
                     } else {
-                        return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE));
+                        return Err(syn::Error::new(ident.span(), expected_attribute_message));
                     }
                 }
             }
@@ -423,6 +499,8 @@ impl ToTokens for Path<'_> {
             request_body: self.path_attr.request_body.as_ref(),
             responses: self.path_attr.responses.as_ref(),
             security: self.path_attr.security.as_ref(),
+            callbacks: &self.path_attr.callbacks,
+            extensions: self.path_attr.extensions.as_ref(),
         };
 
         tokens.extend(quote! {
@@ -460,6 +538,8 @@ struct Operation<'a> {
     request_body: Option<&'a RequestBodyAttr<'a>>,
     responses: &'a Vec<Response<'a>>,
     security: Option<&'a Array<SecurityRequirementAttr>>,
+    callbacks: &'a [Callback],
+    extensions: Option<&'a Extensions>,
 }
 
 impl ToTokens for Operation<'_> {
@@ -481,6 +561,9 @@ impl ToTokens for Operation<'_> {
                 .securities(Some(#security_requirements))
             })
         }
+        self.callbacks
+            .iter()
+            .for_each(|callback| tokens.extend(quote! { #callback }));
         let operation_id = self.operation_id;
         tokens.extend(quote! {
             .operation_id(Some(#operation_id))
@@ -519,6 +602,12 @@ impl ToTokens for Operation<'_> {
                 Parameter::TokenStream(_) => tokens.extend(quote! { .parameters(Some(#parameter))}),
             });
         }
+
+        if let Some(extensions) = self.extensions {
+            tokens.extend(quote! {
+                .extensions(Some(#extensions))
+            })
+        }
     }
 }
 
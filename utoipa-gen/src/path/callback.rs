@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use syn::{parenthesized, parse::Parse, punctuated::Punctuated, ExprPath, LitStr, Token};
+
+use super::PATH_STRUCT_PREFIX;
+
+/// Parsed representation of named callback defined in `callbacks(...)` of path.
+///
+/// Supported configuration format is `("name" = ("expression" = handler, ...))` where expression
+/// is a runtime expression resolving the url of the callback request and handler is path to
+/// a function decorated with `#[utoipa::path(...)]` describing the callback operation.
+///
+/// # Examples
+///
+/// ```text
+/// callbacks(
+///     ("onPetCreated" = ("{$request.body#/callbackUrl}" = pet_created_callback)),
+/// )
+/// ```
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Callback {
+    name: String,
+    expressions: Punctuated<CallbackExpression, Token![,]>,
+}
+
+impl Parse for Callback {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<LitStr>()?.value();
+        input.parse::<Token![=]>()?;
+
+        let expressions;
+        parenthesized!(expressions in input);
+
+        Ok(Self {
+            name,
+            expressions: Punctuated::parse_terminated(&expressions)?,
+        })
+    }
+}
+
+impl ToTokens for Callback {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let name = &self.name;
+        let callback = self.expressions.iter().fold(
+            quote! { utoipa::openapi::callback::CallbackBuilder::new() },
+            |mut callback, expression| {
+                callback.extend(quote! { #expression });
+
+                callback
+            },
+        );
+
+        tokens.extend(quote! { .callback(#name, #callback.build()) })
+    }
+}
+
+/// Runtime expression of callback in format `"expression" = handler` where handler is path to
+/// function decorated with `#[utoipa::path(...)]`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct CallbackExpression {
+    expression: String,
+    handler: ExprPath,
+}
+
+impl CallbackExpression {
+    /// Resolve path of the generated path struct of the callback handler function.
+    fn path_struct(&self) -> ExprPath {
+        let mut path_struct = self.handler.clone();
+        if let Some(last) = path_struct.path.segments.last_mut() {
+            last.ident = format_ident!("{}{}", PATH_STRUCT_PREFIX, last.ident);
+        }
+
+        path_struct
+    }
+}
+
+impl Parse for CallbackExpression {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let expression = input.parse::<LitStr>()?.value();
+        input.parse::<Token![=]>()?;
+
+        Ok(Self {
+            expression,
+            handler: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for CallbackExpression {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let expression = &self.expression;
+        let path_struct = self.path_struct();
+
+        tokens.extend(quote! {
+            .expression(#expression, <#path_struct as utoipa::Path>::path_item(None))
+        })
+    }
+}
@@ -0,0 +1,117 @@
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{parenthesized, parse::Parse, Error, LitStr, Token};
+
+use crate::{parse_utils, AnyValue};
+
+/// Parsed representation of named example defined in `examples(...)` of response or request body.
+///
+/// Supported configuration format is `("name" = (summary = "...", description = "...", value = ..., external_value = "..."))`
+/// where all attributes within the parentheses are optional.
+///
+/// # Examples
+///
+/// ```text
+/// examples(
+///     ("Demo" = (summary = "This is summary", description = "Long description",
+///                 value = json!({"name": "Demo"}))),
+///     ("John" = (summary = "Another user", external_value = "https://example.com/john.json")),
+/// )
+/// ```
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Example {
+    name: String,
+    summary: Option<String>,
+    description: Option<String>,
+    value: Option<AnyValue>,
+    external_value: Option<String>,
+}
+
+impl Parse for Example {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str =
+            "unexpected attribute, expected any of: summary, description, value, external_value";
+        let mut example = Example {
+            name: input.parse::<LitStr>()?.value(),
+            ..Default::default()
+        };
+        input.parse::<Token![=]>()?;
+
+        let attributes;
+        parenthesized!(attributes in input);
+
+        while !attributes.is_empty() {
+            let ident = attributes.parse::<Ident>().map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                )
+            })?;
+            let attribute_name = &*ident.to_string();
+
+            match attribute_name {
+                "summary" => {
+                    example.summary = Some(parse_utils::parse_next_literal_str(&attributes)?)
+                }
+                "description" => {
+                    example.description = Some(parse_utils::parse_next_literal_str(&attributes)?)
+                }
+                "value" => {
+                    example.value = Some(parse_utils::parse_next(&attributes, || {
+                        AnyValue::parse_any(&attributes)
+                    })?)
+                }
+                "external_value" => {
+                    example.external_value = Some(parse_utils::parse_next_literal_str(&attributes)?)
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !attributes.is_empty() {
+                attributes.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(example)
+    }
+}
+
+impl ToTokens for Example {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(quote! { utoipa::openapi::example::ExampleBuilder::new() });
+
+        if let Some(ref summary) = self.summary {
+            tokens.extend(quote! { .summary(Some(#summary)) })
+        }
+
+        if let Some(ref description) = self.description {
+            tokens.extend(quote! { .description(Some(#description)) })
+        }
+
+        if let Some(ref value) = self.value {
+            tokens.extend(quote! { .value(Some(#value)) })
+        }
+
+        if let Some(ref external_value) = self.external_value {
+            tokens.extend(quote! { .external_value(Some(#external_value)) })
+        }
+    }
+}
+
+/// Named examples of response or request body content. Tokens are tuples of name and the example
+/// which can be given to `examples_from_iter` of content builder.
+pub struct Examples<'a>(pub &'a [Example]);
+
+impl ToTokens for Examples<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let examples = self.0.iter().fold(quote! {}, |mut acc, example| {
+            let name = &example.name;
+            acc.extend(quote! { (#name, #example), });
+
+            acc
+        });
+
+        tokens.extend(quote! { [#examples] })
+    }
+}
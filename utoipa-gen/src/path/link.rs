@@ -0,0 +1,143 @@
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{parenthesized, parse::Parse, Error, LitStr, Token};
+
+use crate::{parse_utils, AnyValue};
+
+/// Parsed representation of named link defined in `links(...)` of response.
+///
+/// Supported configuration format is `("name" = (operation_id = "...", operation_ref = "...",
+/// parameters(...), request_body = ..., description = "..."))` where all attributes within the
+/// parentheses are optional. Either `operation_id` or `operation_ref` should be defined but not both.
+///
+/// # Examples
+///
+/// ```text
+/// links(
+///     ("GetPetById" = (operation_id = "get_pet_by_id", parameters(("id" = "$response.body#/id")),
+///                      description = "Id of created pet can be used to get the pet")),
+/// )
+/// ```
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Link {
+    name: String,
+    operation_id: Option<String>,
+    operation_ref: Option<String>,
+    parameters: Vec<LinkParameter>,
+    request_body: Option<AnyValue>,
+    description: Option<String>,
+}
+
+impl Link {
+    /// Name of the link used as key in response links.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Parse for Link {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: operation_id, operation_ref, parameters, request_body, description";
+        let mut link = Link {
+            name: input.parse::<LitStr>()?.value(),
+            ..Default::default()
+        };
+        input.parse::<Token![=]>()?;
+
+        let attributes;
+        parenthesized!(attributes in input);
+
+        while !attributes.is_empty() {
+            let ident = attributes.parse::<Ident>().map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                )
+            })?;
+            let attribute_name = &*ident.to_string();
+
+            match attribute_name {
+                "operation_id" => {
+                    link.operation_id = Some(parse_utils::parse_next_literal_str(&attributes)?)
+                }
+                "operation_ref" => {
+                    link.operation_ref = Some(parse_utils::parse_next_literal_str(&attributes)?)
+                }
+                "parameters" => {
+                    let parameters;
+                    parenthesized!(parameters in attributes);
+
+                    link.parameters = parse_utils::parse_groups(&parameters)?;
+                }
+                "request_body" => {
+                    link.request_body = Some(parse_utils::parse_next(&attributes, || {
+                        AnyValue::parse_any(&attributes)
+                    })?)
+                }
+                "description" => {
+                    link.description = Some(parse_utils::parse_next_literal_str(&attributes)?)
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !attributes.is_empty() {
+                attributes.parse::<Token![,]>()?;
+            }
+        }
+
+        if link.operation_id.is_some() && link.operation_ref.is_some() {
+            return Err(Error::new(
+                input.span(),
+                "operation_id and operation_ref cannot be used together",
+            ));
+        }
+
+        Ok(link)
+    }
+}
+
+impl ToTokens for Link {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(quote! { utoipa::openapi::link::LinkBuilder::new() });
+
+        if let Some(ref operation_id) = self.operation_id {
+            tokens.extend(quote! { .operation_id(Some(#operation_id)) })
+        }
+
+        if let Some(ref operation_ref) = self.operation_ref {
+            tokens.extend(quote! { .operation_ref(Some(#operation_ref)) })
+        }
+
+        self.parameters.iter().for_each(|parameter| {
+            let name = &parameter.name;
+            let value = &parameter.value;
+            tokens.extend(quote! { .parameter(#name, #value) })
+        });
+
+        if let Some(ref request_body) = self.request_body {
+            tokens.extend(quote! { .request_body(Some(#request_body)) })
+        }
+
+        if let Some(ref description) = self.description {
+            tokens.extend(quote! { .description(Some(#description)) })
+        }
+    }
+}
+
+/// Named link parameter in format `("name" = value)` where value is either literal value, runtime
+/// expression e.g. `"$response.body#/id"` or `json!(...)`.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct LinkParameter {
+    name: String,
+    value: AnyValue,
+}
+
+impl Parse for LinkParameter {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse::<LitStr>()?.value(),
+            value: parse_utils::parse_next(input, || AnyValue::parse_any(input))?,
+        })
+    }
+}
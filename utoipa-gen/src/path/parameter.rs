@@ -9,7 +9,9 @@ use syn::{
 
 #[cfg(any(feature = "actix_extras", feature = "rocket_extras"))]
 use crate::ext::{Argument, ArgumentIn};
-use crate::{parse_utils, AnyValue, Deprecated, Required, Type};
+use crate::{
+    component_type::ComponentFormatAttr, parse_utils, AnyValue, Deprecated, Required, Type,
+};
 
 use super::property::Property;
 
@@ -99,7 +101,7 @@ impl Parse for Parameter<'_> {
         }
 
         input.parse::<Token![,]>()?;
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: path, query, header, cookie, deprecated, description, style, explode, allow_reserved, example";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: path, query, header, cookie, deprecated, description, style, explode, allow_reserved, example, format";
 
         while !input.is_empty() {
             let fork = input.fork();
@@ -108,7 +110,10 @@ impl Parse for Parameter<'_> {
                 let ident = fork.parse::<Ident>().unwrap();
                 let name = &*ident.to_string();
 
-                matches!(name, "style" | "explode" | "allow_reserved" | "example")
+                matches!(
+                    name,
+                    "style" | "explode" | "allow_reserved" | "example" | "format"
+                )
             } else {
                 false
             };
@@ -186,7 +191,12 @@ impl ToTokens for Parameter<'_> {
             }
 
             if let Some(ref parameter_type) = parameter.parameter_type {
-                let property = Property::new(parameter_type.is_array, &parameter_type.ty);
+                let format = parameter
+                    .parameter_ext
+                    .as_ref()
+                    .and_then(|ext| ext.format.as_ref());
+                let property =
+                    Property::new(parameter_type.is_array, &parameter_type.ty).with_format(format);
                 let required: Required = (!parameter_type.is_option).into();
 
                 tokens.extend(quote! { .schema(Some(#property)).required(#required) });
@@ -229,7 +239,7 @@ impl FromStr for ParameterIn {
             "cookie" => Ok(Self::Cookie),
             _ => Err(syn::Error::new(
                 Span::call_site(),
-                &format!(
+                format!(
                     "unexpected str: {}, expected one of: path, query, header, cookie",
                     s
                 ),
@@ -258,6 +268,7 @@ pub struct ParameterExt {
     pub explode: Option<bool>,
     pub allow_reserved: Option<bool>,
     pub(crate) example: Option<AnyValue>,
+    pub(crate) format: Option<ComponentFormatAttr>,
 }
 
 impl ParameterExt {
@@ -274,11 +285,14 @@ impl ParameterExt {
         if from.example.is_some() {
             self.example = from.example
         }
+        if from.format.is_some() {
+            self.format = from.format
+        }
     }
 
     fn parse_once(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: style, explode, allow_reserved, example";
+            "unexpected attribute, expected any of: style, explode, allow_reserved, example, format";
 
         let ident = input.parse::<Ident>().map_err(|error| {
             Error::new(
@@ -309,6 +323,10 @@ impl ParameterExt {
                 })?),
                 ..Default::default()
             },
+            "format" => ParameterExt {
+                format: Some(parse_utils::parse_next(input, || input.parse())?),
+                ..Default::default()
+            },
             _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
         };
 
@@ -2,13 +2,14 @@ use std::fmt::Display;
 
 use quote::{quote, ToTokens};
 
-use crate::component_type::{ComponentFormat, ComponentType};
+use crate::component_type::{ComponentFormat, ComponentFormatAttr, ComponentType};
 
 /// Tokenizable object property. It is used as a object property for components or as property
 /// of request or response body or response header.
 pub(crate) struct Property<'a, T: Display> {
     pub(crate) is_array: bool,
     pub(crate) component_type: ComponentType<'a, T>,
+    pub(crate) format: Option<&'a ComponentFormatAttr>,
 }
 
 impl<'a, T> Property<'a, T>
@@ -19,8 +20,15 @@ where
         Self {
             is_array,
             component_type: ComponentType(ident),
+            format: None,
         }
     }
+
+    /// Override the format resolved from the type with user defined format.
+    pub fn with_format(mut self, format: Option<&'a ComponentFormatAttr>) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl<T> ToTokens for Property<'_, T>
@@ -35,7 +43,11 @@ where
             };
 
             let format = ComponentFormat(self.component_type.0);
-            if format.is_known_format() {
+            if let Some(format) = self.format {
+                component.extend(quote! {
+                    .format(Some(#format))
+                })
+            } else if format.is_known_format() {
                 component.extend(quote! {
                     .format(Some(#format))
                 })
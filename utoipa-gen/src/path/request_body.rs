@@ -4,7 +4,11 @@ use syn::{parenthesized, parse::Parse, token::Paren, Error, Token};
 
 use crate::{parse_utils, Required, Type};
 
-use super::{property::Property, ContentTypeResolver};
+use super::{
+    example::{Example, Examples},
+    property::Property,
+    ContentTypeResolver,
+};
 
 /// Parsed information related to requst body of path.
 ///
@@ -14,6 +18,7 @@ use super::{property::Property, ContentTypeResolver};
 ///     If not provided really rough guess logic is used. Basically all primitive types are treated as `text/plain`
 ///     and Object types are expected to be `application/json` by default.
 ///   * **description** Additional description for request body content type.
+///   * **examples** Named examples of the request body content.
 /// # Examples
 ///
 /// Request body in path with all supported info. Where content type is treated as a String and expected
@@ -49,12 +54,13 @@ pub struct RequestBodyAttr<'r> {
     content: Option<Type<'r>>,
     content_type: Option<String>,
     description: Option<String>,
+    examples: Vec<Example>,
 }
 
 impl Parse for RequestBodyAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: content, content_type, description";
+            "unexpected attribute, expected any of: content, content_type, description, examples";
         let lookahead = input.lookahead1();
 
         if lookahead.peek(Paren) {
@@ -90,6 +96,12 @@ impl Parse for RequestBodyAttr<'_> {
                         request_body_attr.description =
                             Some(parse_utils::parse_next_literal_str(&group)?)
                     }
+                    "examples" => {
+                        let examples;
+                        parenthesized!(examples in group);
+
+                        request_body_attr.examples = parse_utils::parse_groups(&examples)?;
+                    }
                     _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
                 }
 
@@ -109,8 +121,7 @@ impl Parse for RequestBodyAttr<'_> {
                         format!("unexpected token, expected type such as String, {}", error),
                     )
                 })?),
-                content_type: None,
-                description: None,
+                ..Default::default()
             })
         } else {
             Err(lookahead.error())
@@ -129,9 +140,20 @@ impl ToTokens for RequestBodyAttr<'_> {
                 self.resolve_content_type(self.content_type.as_ref(), &property.component_type);
             let required: Required = (!body_type.is_option).into();
 
+            let mut content = quote! {
+                utoipa::openapi::ContentBuilder::new().schema(#property)
+            };
+
+            if !self.examples.is_empty() {
+                let examples = Examples(&self.examples);
+                content.extend(quote! {
+                    .examples_from_iter(#examples)
+                })
+            }
+
             tokens.extend(quote! {
                 utoipa::openapi::request_body::RequestBodyBuilder::new()
-                    .content(#content_type, utoipa::openapi::Content::new(#property))
+                    .content(#content_type, #content.build())
                     .required(Some(#required))
             });
         }
@@ -1,33 +1,243 @@
+use std::fmt::Display;
+
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
 use syn::{
     bracketed, parenthesized,
     parse::Parse,
     punctuated::Punctuated,
-    token::{Bracket, Comma},
-    Error, LitInt, LitStr, Token,
+    token::{Bracket, Comma, Paren},
+    Error, ExprPath, GenericArgument, LitInt, LitStr, PathArguments, Token,
 };
 
-use crate::{parse_utils, AnyValue, Type};
+use crate::{parse_utils, schema::component, AnyValue, Type};
+
+use super::{
+    example::{Example, Examples},
+    link::Link,
+    property::Property,
+    ContentTypeResolver,
+};
+
+pub mod derive;
+
+/// Parsed representation of a single response defined in `responses(...)` of `#[utoipa::path]`
+/// attribute.
+///
+/// Response is either a tuple defining the response inline e.g.
+/// `(status = 200, description = "success")` or a path to a type implementing `IntoResponses`
+/// e.g. `MyErrors` in which case all responses of the type are added to the operation.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum Response<'r> {
+    Value(ResponseValue<'r>),
+    IntoResponses(syn::Type),
+}
+
+impl Parse for Response<'_> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Paren) {
+            let response;
+            let paren = parenthesized!(response in input);
+
+            let value = response.parse::<ResponseValue>()?;
+            if value.status.is_none() {
+                return Err(Error::new(
+                    paren.span,
+                    "missing status of the response, expected: status = ...",
+                ));
+            }
+
+            Ok(Self::Value(value))
+        } else {
+            Ok(Self::IntoResponses(input.parse().map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!(
+                        "unexpected token, expected response tuple or type implementing IntoResponses, {}",
+                        error
+                    ),
+                )
+            })?))
+        }
+    }
+}
+
+impl<'r> Response<'r> {
+    /// Infer responses from the handler return type. `Json<T>` and `String` return types will
+    /// produce a _`200`_ success response with `T` or `String` as response body. `Result<T, E>`
+    /// will produce the success response from `T` and, if error responses are inferred, error
+    /// responses from `E` which must then implement `IntoResponses`. Other return types do not
+    /// produce any responses.
+    pub fn from_return_type(ty: &'r syn::Type, infer: &InferResponses) -> Vec<Response<'r>> {
+        let mut responses = Vec::new();
+
+        let (success, error) = match generic_arguments(ty, "Result").as_deref() {
+            Some([success, error]) => (*success, Some(*error)),
+            Some([success]) => (*success, None),
+            _ => (ty, None),
+        };
+
+        if let Some(response) = ResponseValue::from_success_type(success, infer) {
+            responses.push(Response::Value(response));
+        }
+
+        if let Some(error) = error.filter(|_| infer.error) {
+            responses.push(Response::IntoResponses(error.clone()));
+        }
+
+        responses
+    }
+}
+
+/// Options of `infer_responses(...)` defining how responses are inferred from the handler
+/// return type.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct InferResponses {
+    error: bool,
+    description: Option<String>,
+}
+
+impl Parse for InferResponses {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str =
+            "unexpected identifier, expected any of: error, description";
+        let mut infer_responses = InferResponses::default();
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>().map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("{}, {}", EXPECTED_ATTRIBUTE_MESSAGE, error),
+                )
+            })?;
+
+            match &*ident.to_string() {
+                "error" => infer_responses.error = parse_utils::parse_bool_or_true(input)?,
+                "description" => {
+                    infer_responses.description = Some(parse_utils::parse_next_literal_str(input)?)
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(infer_responses)
+    }
+}
+
+/// Get generic type arguments of the type if the last segment of the type path matches given name.
+fn generic_arguments<'t>(ty: &'t syn::Type, name: &str) -> Option<Vec<&'t syn::Type>> {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == name)
+            .map(|segment| match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => arguments
+                    .args
+                    .iter()
+                    .filter_map(|argument| match argument {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            }),
+        _ => None,
+    }
+}
+
+/// Status of the response. Status is either a http status code e.g. `200`, a range of status codes
+/// e.g. `"4XX"` or `default` which is used for all status codes not covered by other responses.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ResponseStatus {
+    Code(u16),
+    Range(char),
+    Default,
+}
+
+impl ResponseStatus {
+    const INVALID_STATUS_MESSAGE: &'static str = "invalid status, expected http status code between 100 and 599, status code range such as \"4XX\" or default";
 
-use super::{property::Property, ContentTypeResolver};
+    fn from_code(code: u16, span: proc_macro2::Span) -> syn::Result<Self> {
+        if (100..=599).contains(&code) {
+            Ok(Self::Code(code))
+        } else {
+            Err(Error::new(span, Self::INVALID_STATUS_MESSAGE))
+        }
+    }
+}
+
+impl Parse for ResponseStatus {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(LitInt) {
+            let code = input.parse::<LitInt>()?;
+            Self::from_code(code.base10_parse()?, code.span())
+        } else if lookahead.peek(LitStr) {
+            let status = input.parse::<LitStr>()?;
+            let value = status.value();
+
+            match value.as_bytes() {
+                [range @ b'1'..=b'5', b'X', b'X'] => Ok(Self::Range(*range as char)),
+                _ => value
+                    .parse::<u16>()
+                    .map_err(|_| Error::new(status.span(), Self::INVALID_STATUS_MESSAGE))
+                    .and_then(|code| Self::from_code(code, status.span())),
+            }
+        } else if lookahead.peek(Token![default]) {
+            input.parse::<Token![default]>()?;
+            Ok(Self::Default)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl Display for ResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Code(code) => write!(f, "{}", code),
+            Self::Range(range) => write!(f, "{}XX", range),
+            Self::Default => write!(f, "default"),
+        }
+    }
+}
+
+impl ToTokens for ResponseStatus {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let status = self.to_string();
+        tokens.extend(quote! { #status })
+    }
+}
 
 /// Parsed representation of response attributes from `#[utoipa::path]` attribute.
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Response<'r> {
-    status_code: i32,
+pub struct ResponseValue<'r> {
+    status: Option<ResponseStatus>,
     description: String,
     response_type: Option<Type<'r>>,
+    /// Response body type inferred from the return type of the handler function or from the
+    /// field of the response type.
+    return_type: Option<&'r syn::Type>,
+    response: Option<ExprPath>,
     content_type: Option<Vec<String>>,
     headers: Vec<Header<'r>>,
     example: Option<AnyValue>,
+    examples: Vec<Example>,
+    links: Vec<Link>,
 }
 
-impl Parse for Response<'_> {
+impl Parse for ResponseValue<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: status, description, body, content_type, headers";
-        let mut response = Response::default();
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: status, description, body, response, content_type, headers, example, examples, links";
+        let mut response = ResponseValue::default();
 
         while !input.is_empty() {
             let ident = input.parse::<Ident>().map_err(|error| {
@@ -40,9 +250,7 @@ impl Parse for Response<'_> {
 
             match attribute_name {
                 "status" => {
-                    response.status_code =
-                        parse_utils::parse_next(input, || input.parse::<LitInt>())?
-                            .base10_parse()?;
+                    response.status = Some(parse_utils::parse_next(input, || input.parse())?);
                 }
                 "description" => {
                     response.description = parse_utils::parse_next_literal_str(input)?;
@@ -62,6 +270,19 @@ impl Parse for Response<'_> {
                         )?,
                     );
                 }
+                "response" => {
+                    response.response = Some(parse_utils::parse_next(input, || {
+                        input.parse::<ExprPath>().map_err(|error| {
+                            Error::new(
+                                error.span(),
+                                format!(
+                                    "unexpected token, expected type implementing ToResponse, {}",
+                                    error
+                                ),
+                            )
+                        })
+                    })?);
+                }
                 "content_type" => {
                     response.content_type = Some(parse_utils::parse_next(input, || {
                         let look_content_type = input.lookahead1();
@@ -92,6 +313,18 @@ impl Parse for Response<'_> {
                         AnyValue::parse_lit_str_or_json(input)
                     })?);
                 }
+                "examples" => {
+                    let examples;
+                    parenthesized!(examples in input);
+
+                    response.examples = parse_utils::parse_groups(&examples)?;
+                }
+                "links" => {
+                    let links;
+                    parenthesized!(links in input);
+
+                    response.links = parse_utils::parse_groups(&links)?;
+                }
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -100,21 +333,96 @@ impl Parse for Response<'_> {
             }
         }
 
+        if let Some(ref reference) = response.response {
+            if !response.description.is_empty()
+                || response.response_type.is_some()
+                || response.content_type.is_some()
+                || !response.headers.is_empty()
+                || response.example.is_some()
+                || !response.examples.is_empty()
+                || !response.links.is_empty()
+            {
+                return Err(Error::new_spanned(
+                    reference,
+                    "response cannot be used together with other attributes than status",
+                ));
+            }
+        }
+
         Ok(response)
     }
 }
 
-impl ToTokens for Response<'_> {
+impl<'r> ResponseValue<'r> {
+    /// Create _`200`_ success response from `Json<T>`, `String` or `&str` type. Other types
+    /// do not have known response body thus no response is created. Response body is documented
+    /// the same way as fields of components e.g. maps are documented as objects.
+    fn from_success_type(ty: &'r syn::Type, infer: &InferResponses) -> Option<Self> {
+        let is_string = |ty: &syn::Type| match ty {
+            syn::Type::Path(path) => path.path.is_ident("String"),
+            syn::Type::Reference(reference) => {
+                matches!(&*reference.elem, syn::Type::Path(path) if path.path.is_ident("str"))
+            }
+            _ => false,
+        };
+
+        let (body, content_type) = match generic_arguments(ty, "Json").as_deref() {
+            Some([body]) => (*body, "application/json"),
+            _ if is_string(ty) => (ty, "text/plain"),
+            _ => return None,
+        };
+
+        Some(Self {
+            status: Some(ResponseStatus::Code(200)),
+            description: infer
+                .description
+                .clone()
+                .unwrap_or_else(|| "OK".to_string()),
+            return_type: Some(body),
+            content_type: Some(vec![content_type.to_string()]),
+            ..Default::default()
+        })
+    }
+}
+
+impl ToTokens for ResponseValue<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
+        if let Some(ref reference) = self.response {
+            tokens.extend(quote! {
+                utoipa::openapi::Ref::new(format!(
+                    "#/components/responses/{}",
+                    <#reference as utoipa::ToResponse>::response().0
+                ))
+            });
+
+            return;
+        }
+
         let description = &self.description;
         tokens.extend(quote! {
             utoipa::openapi::ResponseBuilder::new().description(#description)
         });
 
-        if let Some(ref body_type) = self.response_type {
-            let body_ty = &body_type.ty;
+        let body = match (&self.response_type, self.return_type) {
+            (Some(body_type), _) => {
+                let component = Property::new(body_type.is_array, &body_type.ty);
+                let default_type = self.resolve_content_type(None, &component.component_type);
+
+                Some((component.into_token_stream(), default_type))
+            }
+            (None, Some(return_type)) => {
+                let default_type = if component::is_primitive_type(return_type) {
+                    "text/plain"
+                } else {
+                    "application/json"
+                };
+
+                Some((component::type_to_component(return_type), default_type))
+            }
+            (None, None) => None,
+        };
 
-            let component = Property::new(body_type.is_array, body_ty);
+        if let Some((component, default_type)) = body {
             let mut content = quote! {
                 utoipa::openapi::ContentBuilder::new().schema(#component)
             };
@@ -125,6 +433,13 @@ impl ToTokens for Response<'_> {
                 })
             }
 
+            if !self.examples.is_empty() {
+                let examples = Examples(&self.examples);
+                content.extend(quote! {
+                    .examples_from_iter(#examples)
+                })
+            }
+
             if let Some(content_types) = self.content_type.as_ref() {
                 content_types.iter().for_each(|content_type| {
                     tokens.extend(quote! {
@@ -132,7 +447,6 @@ impl ToTokens for Response<'_> {
                     })
                 })
             } else {
-                let default_type = self.resolve_content_type(None, &component.component_type);
                 tokens.extend(quote! {
                     .content(#default_type, #content.build())
                 });
@@ -146,30 +460,41 @@ impl ToTokens for Response<'_> {
             })
         });
 
+        self.links.iter().for_each(|link| {
+            let name = link.name();
+            tokens.extend(quote! {
+                .link(#name, #link)
+            })
+        });
+
         tokens.extend(quote! { .build() })
     }
 }
 
-impl ContentTypeResolver for Response<'_> {}
+impl ContentTypeResolver for ResponseValue<'_> {}
 
 pub struct Responses<'a>(pub &'a [Response<'a>]);
 
 impl ToTokens for Responses<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        if self.0.is_empty() {
-            tokens.extend(quote! { utoipa::openapi::Responses::new() })
-        } else {
-            let responses = self.0.iter().fold(quote! {}, |mut acc, response| {
-                let code = &response.status_code.to_string();
-                acc.extend(quote! { (#code, #response), });
+        tokens.extend(self.0.iter().fold(
+            quote! { utoipa::openapi::ResponsesBuilder::new() },
+            |mut acc, response| {
+                match response {
+                    Response::Value(value) => {
+                        let status = &value.status;
+                        acc.extend(quote! { .response(#status, #value) });
+                    }
+                    Response::IntoResponses(path) => acc.extend(quote! {
+                        .responses_from_iter(<#path as utoipa::IntoResponses>::responses())
+                    }),
+                }
 
                 acc
-            });
+            },
+        ));
 
-            tokens.extend(quote! {
-                utoipa::openapi::Responses::from_iter([#responses])
-            });
-        }
+        tokens.extend(quote! { .build() });
     }
 }
 
@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use proc_macro_error::{abort, ResultExt};
+use quote::{quote, ToTokens};
+use syn::{Attribute, Data, Fields, Generics, Variant};
+
+use crate::{doc_comment::CommentAttributes, Type};
+
+use super::ResponseValue;
+
+/// Parse `#[response(...)]` attribute from attributes of type or enum variant.
+fn parse_response_attr<'r>(attributes: &[Attribute]) -> Option<ResponseValue<'r>> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.path.is_ident("response"))
+        .map(|attribute| attribute.parse_args::<ResponseValue>().unwrap_or_abort())
+}
+
+/// Use first line of doc comment as response description if not defined in the attribute.
+fn description_from_doc_comment(response: &mut ResponseValue, attributes: &[Attribute]) {
+    if response.description.is_empty() && response.response.is_none() {
+        if let Some(comment) = CommentAttributes::from_attributes(attributes).first() {
+            response.description = comment.to_string();
+        }
+    }
+}
+
+/// Resolve response body type of type with given fields. Unit types have no response body,
+/// other types use the type itself as response body.
+fn self_body_type<'r>(ident: &'r Ident, data: &Data) -> Option<Type<'r>> {
+    match data {
+        Data::Struct(data) if matches!(data.fields, Fields::Unit) => None,
+        _ => Some(Type::new(Cow::Borrowed(ident), false, false)),
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ToResponse {
+    pub ident: Ident,
+    pub generics: Generics,
+    pub data: Data,
+    pub attrs: Vec<Attribute>,
+}
+
+impl ToTokens for ToResponse {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.ident;
+        let name = &*ident.to_string();
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let mut response = parse_response_attr(&self.attrs).unwrap_or_default();
+        description_from_doc_comment(&mut response, &self.attrs);
+        if response.status.is_some() {
+            abort!(
+                ident,
+                "status is not supported in #[derive(ToResponse)]";
+                help = "Did you mean to use #[derive(IntoResponses)]?"
+            )
+        }
+        if let Some(ref reference) = response.response {
+            abort!(
+                reference,
+                "response is not supported in #[derive(ToResponse)]"
+            )
+        }
+        if response.response_type.is_none() {
+            response.response_type = self_body_type(ident, &self.data);
+        }
+
+        tokens.extend(quote! {
+            impl #impl_generics utoipa::ToResponse for #ident #ty_generics #where_clause {
+                fn response() -> (
+                    &'static str,
+                    utoipa::openapi::RefOr<utoipa::openapi::response::Response>,
+                ) {
+                    (#name, #response.into())
+                }
+            }
+        })
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct IntoResponses {
+    pub ident: Ident,
+    pub generics: Generics,
+    pub data: Data,
+    pub attrs: Vec<Attribute>,
+}
+
+impl IntoResponses {
+    fn variant_response(variant: &Variant) -> ResponseValue<'_> {
+        let mut response = parse_response_attr(&variant.attrs).unwrap_or_else(|| {
+            abort!(
+                variant.ident,
+                "missing #[response(...)] attribute";
+                help = "Define status of the response with #[response(status = ...)]"
+            )
+        });
+        description_from_doc_comment(&mut response, &variant.attrs);
+
+        if response.response_type.is_none() && response.response.is_none() {
+            response.return_type = match &variant.fields {
+                Fields::Unit => None,
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    Some(&fields.unnamed.first().unwrap().ty)
+                }
+                _ => abort!(
+                    variant.ident,
+                    "unsupported variant, expected unit variant or variant with single unnamed field"
+                ),
+            };
+        }
+
+        response
+    }
+}
+
+impl ToTokens for IntoResponses {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let responses = match &self.data {
+            Data::Enum(data) => data
+                .variants
+                .iter()
+                .map(|variant| (&variant.ident, Self::variant_response(variant)))
+                .collect::<Vec<_>>(),
+            _ => {
+                let mut response = parse_response_attr(&self.attrs).unwrap_or_else(|| {
+                    abort!(
+                        ident,
+                        "missing #[response(...)] attribute";
+                        help = "Define status of the response with #[response(status = ...)]"
+                    )
+                });
+                description_from_doc_comment(&mut response, &self.attrs);
+                if response.response_type.is_none() && response.response.is_none() {
+                    response.response_type = self_body_type(ident, &self.data);
+                }
+
+                vec![(ident, response)]
+            }
+        };
+
+        let responses = responses.iter().fold(
+            quote! { utoipa::openapi::ResponsesBuilder::new() },
+            |mut acc, (ident, response)| {
+                let status = response.status.as_ref().unwrap_or_else(|| {
+                    abort!(
+                        ident,
+                        "missing status of the response";
+                        help = "Define status of the response with #[response(status = ...)]"
+                    )
+                });
+                acc.extend(quote! { .response(#status, #response) });
+
+                acc
+            },
+        );
+
+        tokens.extend(quote! {
+            impl #impl_generics utoipa::IntoResponses for #ident #ty_generics #where_clause {
+                fn responses() -> std::collections::BTreeMap<
+                    String,
+                    utoipa::openapi::RefOr<utoipa::openapi::response::Response>,
+                > {
+                    #responses.build().responses
+                }
+            }
+        })
+    }
+}
@@ -1,8 +1,8 @@
 use proc_macro2::Ident;
 use proc_macro_error::{abort, abort_call_site};
 use syn::{
-    punctuated::Pair, AngleBracketedGenericArguments, Attribute, GenericArgument, PathArguments,
-    PathSegment, Type, TypePath,
+    punctuated::Pair, AngleBracketedGenericArguments, Attribute, GenericArgument, Path,
+    PathArguments, PathSegment, Type, TypePath,
 };
 
 use crate::{component_type::ComponentType, Deprecated};
@@ -12,6 +12,9 @@ pub mod into_params;
 
 pub mod component;
 
+#[cfg(feature = "validator")]
+mod validator;
+
 /// Find `#[deprecated]` attribute from given attributes. Typically derive type attributes
 /// or field attributes of struct.
 fn get_deprecated(attributes: &[Attribute]) -> Option<Deprecated> {
@@ -86,7 +89,11 @@ impl<'a> ComponentPart<'a> {
             .unwrap();
 
         if segment.arguments.is_empty() {
-            op(&segment.ident, segment)
+            let mut component_part = op(&segment.ident, segment);
+            if is_value_path(&type_path.path) {
+                component_part.value_type = ValueType::Value;
+            }
+            component_part
         } else {
             or_else(segment)
         }
@@ -103,10 +110,16 @@ impl<'a> ComponentPart<'a> {
 
         let mut generic_component_type = ComponentPart::convert(&segment.ident, segment);
 
+        // Maps are documented by the type of their values which is the second generic argument.
+        let index = match generic_component_type.generic_type {
+            Some(GenericType::Map) => 1,
+            _ => 0,
+        };
+
         generic_component_type.child = Some(Box::new(ComponentPart::from_type(
             match &segment.arguments {
                 PathArguments::AngleBracketed(angle_bracketed_args) => {
-                    ComponentPart::get_generic_arg_type(0, angle_bracketed_args)
+                    ComponentPart::get_generic_arg_type(index, angle_bracketed_args)
                 }
                 _ => abort!(
                     segment.ident,
@@ -183,6 +196,23 @@ impl<'a> ComponentPart<'a> {
     fn update_ident(&mut self, ident: &'a Ident) {
         self.ident = ident
     }
+
+    /// Check whether the type is an arbitrary value e.g. `serde_json::Value` which has no
+    /// component of its own.
+    fn is_value(&self) -> bool {
+        self.generic_type.is_none() && self.value_type == ValueType::Value
+    }
+}
+
+/// Check whether path is path of `serde_json::Value`. Imports cannot be resolved within macro
+/// thus only the full path is recognized and e.g. plain `Value` can be any other type.
+fn is_value_path(path: &Path) -> bool {
+    let mut segments = path.segments.iter().map(|segment| &segment.ident);
+
+    matches!(
+        (segments.next(), segments.next(), segments.next()),
+        (Some(krate), Some(value), None) if krate == "serde_json" && value == "Value"
+    )
 }
 
 impl<'a> AsMut<ComponentPart<'a>> for ComponentPart<'a> {
@@ -196,6 +226,8 @@ impl<'a> AsMut<ComponentPart<'a>> for ComponentPart<'a> {
 enum ValueType {
     Primitive,
     Object,
+    /// Arbitrary value e.g. `serde_json::Value` which is documented as untyped object.
+    Value,
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -15,7 +15,7 @@ use crate::{
 };
 
 use self::{
-    attr::{ComponentAttr, Enum, NamedField, UnnamedFieldStruct},
+    attr::{ComponentAttr, Enum, NamedField, UnnamedFieldStruct, VariantDocs},
     xml::Xml,
 };
 
@@ -64,9 +64,34 @@ impl<'a> Component<'a> {
 impl ToTokens for Component<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let ident = self.ident;
-        let variant = ComponentVariant::new(self.data, self.attributes, ident, self.generics, None);
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
+        if is_schemars(self.attributes) {
+            #[cfg(not(feature = "schemars"))]
+            abort!(
+                ident,
+                "#[component(schemars)] requires **schemars** feature to be enabled"
+            );
+
+            #[cfg(feature = "schemars")]
+            tokens.extend(quote! {
+                impl #impl_generics utoipa::Component for #ident #ty_generics #where_clause {
+                    fn component() -> utoipa::openapi::schema::Component {
+                        utoipa::openapi::schemars::component_for::<Self>()
+                    }
+
+                    fn definitions() -> Vec<(String, utoipa::openapi::schema::Component)> {
+                        utoipa::openapi::schemars::definitions_for::<Self>()
+                    }
+                }
+            });
+
+            #[allow(unreachable_code)]
+            return;
+        }
+
+        let variant = ComponentVariant::new(self.data, self.attributes, ident, self.generics, None);
+
         let aliases = self.aliases.as_ref().map(|aliases| {
             let alias_components = aliases
                 .iter()
@@ -255,8 +280,24 @@ impl ToTokens for NamedStructComponent<'_> {
                     type_override.as_ref(),
                 );
 
+                #[allow(unused_mut)]
+                let mut property = component.to_token_stream();
+
+                #[cfg(feature = "validator")]
+                if let Some(validate) = super::validator::Validate::from_attributes(&field.attrs) {
+                    let has_format = attrs
+                        .as_ref()
+                        .map(|named_field| named_field.as_ref().has_format())
+                        .unwrap_or(false);
+                    property.extend(validate.to_tokens_for(
+                        component_part,
+                        type_override.as_ref(),
+                        has_format,
+                    ));
+                }
+
                 tokens.extend(quote! {
-                    .property(#name, #component)
+                    .property(#name, #property)
                 });
 
                 if !component.is_option() {
@@ -395,7 +436,7 @@ impl ToTokens for SimpleEnum<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let mut container_rules = serde::parse_container(self.attributes);
 
-        let enum_values = self
+        let variants = self
             .variants
             .iter()
             .filter_map(|variant| {
@@ -405,27 +446,109 @@ impl ToTokens for SimpleEnum<'_> {
                     let name = &*variant.ident.to_string();
                     let renamed = rename_variant(&mut container_rules, &mut variant_rules, name);
 
-                    renamed.or_else(|| Some(String::from(name)))
+                    Some((renamed.unwrap_or_else(|| String::from(name)), variant))
                 } else {
                     None
                 }
             })
-            .collect::<Array<String>>();
-        let len = enum_values.len();
-
-        tokens.extend(quote! {
-            utoipa::openapi::PropertyBuilder::new()
-            .component_type(utoipa::openapi::ComponentType::String)
-            .enum_values::<[&str; #len], &str>(Some(#enum_values))
-        });
+            .collect::<Vec<_>>();
 
         let attrs = attr::parse_component_attr::<ComponentAttr<Enum>>(self.attributes);
-        if let Some(attributes) = attrs {
-            tokens.extend(attributes.to_token_stream());
-        }
+        let variant_docs = attrs
+            .as_ref()
+            .and_then(|attrs| attrs.as_ref().variant_docs.as_ref());
+
+        if let Some(VariantDocs::OneOf) = variant_docs {
+            tokens.extend(quote! { utoipa::openapi::schema::OneOfBuilder::new() });
+
+            variants.iter().for_each(|(name, variant)| {
+                let mut item = quote! {
+                    utoipa::openapi::PropertyBuilder::new()
+                        .component_type(utoipa::openapi::ComponentType::String)
+                        .enum_values::<[&str; 1], &str>(Some([#name]))
+                };
+
+                if let Some(comment) = CommentAttributes::from_attributes(&variant.attrs).first() {
+                    item.extend(quote! { .description(Some(#comment)) })
+                }
 
-        if let Some(deprecated) = super::get_deprecated(self.attributes) {
-            tokens.extend(quote! { .deprecated(Some(#deprecated)) });
+                if let Some(deprecated) = super::get_deprecated(&variant.attrs) {
+                    item.extend(quote! { .deprecated(Some(#deprecated)) });
+                }
+
+                tokens.extend(quote! { .item(#item) })
+            });
+
+            if let Some(deprecated) = super::get_deprecated(self.attributes) {
+                tokens.extend(quote! { .deprecated(Some(#deprecated)) });
+            }
+
+            if let Some(extensions) = attrs
+                .as_ref()
+                .and_then(|attrs| attrs.as_ref().extensions.as_ref())
+            {
+                tokens.extend(quote! { .extensions(Some(#extensions)) })
+            }
+        } else {
+            let enum_values = variants
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect::<Array<String>>();
+            let len = enum_values.len();
+
+            tokens.extend(quote! {
+                utoipa::openapi::PropertyBuilder::new()
+                .component_type(utoipa::openapi::ComponentType::String)
+                .enum_values::<[&str; #len], &str>(Some(#enum_values))
+            });
+
+            if let Some(VariantDocs::Extensions) = variant_docs {
+                #[cfg(not(feature = "json"))]
+                abort!(
+                    self.attributes.first().unwrap(),
+                    "variant_docs = Extensions requires **json** feature to be enabled"
+                );
+
+                #[cfg(feature = "json")]
+                {
+                    let varnames = variants
+                        .iter()
+                        .map(|(_, variant)| variant.ident.to_string())
+                        .collect::<Array<String>>();
+                    let descriptions = variants
+                        .iter()
+                        .map(|(_, variant)| {
+                            CommentAttributes::from_attributes(&variant.attrs)
+                                .first()
+                                .cloned()
+                                .unwrap_or_default()
+                        })
+                        .collect::<Array<String>>();
+
+                    let extensions = attrs
+                        .as_ref()
+                        .and_then(|attrs| attrs.as_ref().extensions.as_ref())
+                        .map(|extensions| extensions.to_add_tokens());
+
+                    tokens.extend(quote! {
+                        .extensions(Some(
+                            utoipa::openapi::extensions::ExtensionsBuilder::new()
+                                .add("x-enum-varnames", #varnames.to_vec())
+                                .add("x-enum-descriptions", #descriptions.to_vec())
+                                #extensions
+                                .build()
+                        ))
+                    });
+                }
+            }
+
+            if let Some(attributes) = attrs {
+                tokens.extend(attributes.to_token_stream());
+            }
+
+            if let Some(deprecated) = super::get_deprecated(self.attributes) {
+                tokens.extend(quote! { .deprecated(Some(#deprecated)) });
+            }
         }
 
         if let Some(comment) = CommentAttributes::from_attributes(self.attributes).first() {
@@ -577,10 +700,27 @@ where
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         match self.component_part.generic_type {
             Some(GenericType::Map) => {
-                // Maps are treated just as generic objects without types. There is no Map type in OpenAPI spec.
-                tokens.extend(quote! {
-                    utoipa::openapi::ObjectBuilder::new()
-                });
+                // Maps are objects with values as additional properties. There is no Map type in OpenAPI spec.
+                let value_part = self.component_part.child.as_ref().unwrap().as_ref();
+
+                if self.type_override.is_none() && value_part.is_value() {
+                    // Arbitrary values have no component to reference, values are left untyped.
+                    tokens.extend(quote! { utoipa::openapi::ObjectBuilder::new() });
+                } else {
+                    let component_property = ComponentProperty::<T>::new(
+                        value_part,
+                        None,
+                        None,
+                        None,
+                        None,
+                        self.type_override,
+                    );
+
+                    tokens.extend(quote! {
+                        utoipa::openapi::ObjectBuilder::new()
+                            .additional_properties(Some(#component_property))
+                    });
+                }
 
                 if let Some(description) = self.comments.and_then(|attributes| attributes.0.first())
                 {
@@ -683,12 +823,74 @@ where
                             utoipa::openapi::Ref::from_component_name(#name)
                         })
                     }
+                    ValueType::Value => {
+                        // Arbitrary values have no component to reference, value is left untyped.
+                        tokens.extend(quote! { utoipa::openapi::ObjectBuilder::new() });
+
+                        if let Some(description) =
+                            self.comments.and_then(|attributes| attributes.0.first())
+                        {
+                            tokens.extend(quote! {
+                                .description(Some(#description))
+                            })
+                        }
+                    }
                 }
             }
         }
     }
 }
 
+/// Create component of given type the same way as components of struct fields are created.
+/// This is used to document types which are not fields e.g. response body inferred from the
+/// return type of the handler function.
+pub(crate) fn type_to_component(ty: &syn::Type) -> TokenStream2 {
+    match ty {
+        syn::Type::Slice(slice) => {
+            let items = type_to_component(&slice.elem);
+            quote! { #items.to_array_builder() }
+        }
+        syn::Type::Array(array) => {
+            let items = type_to_component(&array.elem);
+            quote! { #items.to_array_builder() }
+        }
+        syn::Type::Reference(reference) => type_to_component(&reference.elem),
+        ty => {
+            let component_part = ComponentPart::from_type(ty);
+            ComponentProperty::<NamedField>::new(&component_part, None, None, None, None, None)
+                .into_token_stream()
+        }
+    }
+}
+
+/// Check whether given type is documented as primitive property e.g. `String` or `&str`.
+pub(crate) fn is_primitive_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => is_primitive_type(&reference.elem),
+        syn::Type::Path(_) | syn::Type::Group(_) => {
+            let component_part = ComponentPart::from_type(ty);
+
+            component_part.generic_type.is_none()
+                && component_part.value_type == ValueType::Primitive
+        }
+        _ => false,
+    }
+}
+
+/// Check whether component is marked with `#[component(schemars)]` in which case the component
+/// is generated with schemars instead.
+fn is_schemars(attributes: &[Attribute]) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("component"))
+        .any(|attribute| {
+            attribute
+                .parse_args::<Ident>()
+                .map(|ident| ident == "schemars")
+                .unwrap_or(false)
+        })
+}
+
 #[inline]
 fn is_not_skipped(rule: &Option<Serde>) -> bool {
     rule.as_ref()
@@ -3,16 +3,13 @@ use std::mem;
 use proc_macro2::{Ident, TokenStream};
 use proc_macro_error::{abort, ResultExt};
 use quote::{quote, ToTokens};
-use syn::{
-    parenthesized,
-    parse::{Parse, ParseBuffer},
-    Attribute, Error, ExprPath, Token,
-};
+use syn::{parenthesized, parse::Parse, Attribute, Error, Token};
 
 use crate::{
+    component_type::ComponentFormatAttr,
     parse_utils,
     schema::{ComponentPart, GenericType},
-    AnyValue,
+    AnyValue, Extensions,
 };
 
 use super::xml::{Xml, XmlAttr};
@@ -39,6 +36,31 @@ where
 pub struct Enum {
     default: Option<AnyValue>,
     example: Option<AnyValue>,
+    pub(super) variant_docs: Option<VariantDocs>,
+    pub(super) extensions: Option<Extensions>,
+}
+
+/// Defines how variants of simple enum are documented.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum VariantDocs {
+    /// Document variants as `oneOf` of single value enum properties.
+    OneOf,
+    /// Document variants with `x-enum-varnames` and `x-enum-descriptions` extensions.
+    Extensions,
+}
+
+impl Parse for VariantDocs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        const EXPECTED_VARIANT_DOCS: &str =
+            "unexpected variant_docs, expected one of: OneOf, Extensions";
+        let variant_docs = input.parse::<Ident>()?;
+
+        match &*variant_docs.to_string() {
+            "OneOf" => Ok(Self::OneOf),
+            "Extensions" => Ok(Self::Extensions),
+            _ => Err(Error::new(variant_docs.span(), EXPECTED_VARIANT_DOCS)),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -46,15 +68,17 @@ pub struct Enum {
 pub struct Struct {
     example: Option<AnyValue>,
     xml_attr: Option<XmlAttr>,
+    extensions: Option<Extensions>,
 }
 
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct UnnamedFieldStruct {
     pub(super) ty: Option<Ident>,
-    format: Option<ExprPath>,
+    format: Option<ComponentFormatAttr>,
     default: Option<AnyValue>,
     example: Option<AnyValue>,
+    extensions: Option<Extensions>,
 }
 
 #[derive(Default)]
@@ -62,18 +86,20 @@ pub struct UnnamedFieldStruct {
 pub struct NamedField {
     example: Option<AnyValue>,
     pub(super) ty: Option<Ident>,
-    format: Option<ExprPath>,
+    format: Option<ComponentFormatAttr>,
     default: Option<AnyValue>,
     write_only: Option<bool>,
     read_only: Option<bool>,
+    nullable: Option<bool>,
     xml_attr: Option<XmlAttr>,
     pub(super) xml: Option<Xml>,
+    extensions: Option<Extensions>,
 }
 
 impl Parse for ComponentAttr<Enum> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: default, example";
+            "unexpected attribute, expected any of: default, example, variant_docs, extensions";
         let mut enum_attr = Enum::default();
 
         while !input.is_empty() {
@@ -96,9 +122,27 @@ impl Parse for ComponentAttr<Enum> {
                         AnyValue::parse_any(input)
                     })?)
                 }
+                "variant_docs" => {
+                    enum_attr.variant_docs = Some(parse_utils::parse_next(input, || input.parse())?)
+                }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    enum_attr.extensions = Some(extensions.parse()?)
+                }
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
+            // OneOf component does not have default or example, they would be silently lost.
+            if matches!(enum_attr.variant_docs, Some(VariantDocs::OneOf))
+                && (enum_attr.default.is_some() || enum_attr.example.is_some())
+            {
+                return Err(Error::new(
+                    ident.span(),
+                    "variant_docs = OneOf cannot be used with default or example since oneOf component has no default or example, use variant_docs = Extensions instead",
+                ));
+            }
+
             if !input.is_empty() {
                 input.parse::<Token![,]>()?;
             }
@@ -129,7 +173,7 @@ impl ComponentAttr<Struct> {
 impl Parse for ComponentAttr<Struct> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: example, xml";
+            "unexpected attribute, expected any of: example, example_from_default, xml, extensions";
         let mut struct_ = Struct::default();
 
         while !input.is_empty() {
@@ -144,14 +188,22 @@ impl Parse for ComponentAttr<Struct> {
             match name {
                 "example" => {
                     struct_.example = Some(parse_utils::parse_next(input, || {
-                        AnyValue::parse_lit_str_or_json(input)
+                        AnyValue::parse_lit_str_json_or_method(input)
                     })?);
                 }
+                "example_from_default" => {
+                    struct_.example = Some(parse_example_from_default(&ident)?)
+                }
                 "xml" => {
                     let xml;
                     parenthesized!(xml in input);
                     struct_.xml_attr = Some(xml.parse()?)
                 }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    struct_.extensions = Some(extensions.parse()?)
+                }
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -167,7 +219,7 @@ impl Parse for ComponentAttr<Struct> {
 impl Parse for ComponentAttr<UnnamedFieldStruct> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: default, example, format, value_type";
+            "unexpected attribute, expected any of: default, example, example_from_default, format, value_type, extensions";
         let mut unnamed_struct = UnnamedFieldStruct::default();
 
         while !input.is_empty() {
@@ -190,11 +242,21 @@ impl Parse for ComponentAttr<UnnamedFieldStruct> {
                         AnyValue::parse_any(input)
                     })?)
                 }
-                "format" => unnamed_struct.format = Some(parse_format(input)?),
+                "example_from_default" => {
+                    unnamed_struct.example = Some(parse_example_from_default(&attribute)?)
+                }
+                "format" => {
+                    unnamed_struct.format = Some(parse_utils::parse_next(input, || input.parse())?)
+                }
                 "value_type" => {
                     unnamed_struct.ty =
                         Some(parse_utils::parse_next(input, || input.parse::<Ident>())?)
                 }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    unnamed_struct.extensions = Some(extensions.parse()?)
+                }
                 _ => return Err(Error::new(attribute.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -209,6 +271,19 @@ impl Parse for ComponentAttr<UnnamedFieldStruct> {
     }
 }
 
+/// Parse `example_from_default` which uses [`Default`] implementation of the type as an example.
+#[cfg_attr(feature = "json", allow(unused_variables))]
+fn parse_example_from_default(ident: &Ident) -> syn::Result<AnyValue> {
+    #[cfg(feature = "json")]
+    return Ok(AnyValue::new_default_trait());
+
+    #[cfg(not(feature = "json"))]
+    Err(Error::new(
+        ident.span(),
+        "example_from_default requires **json** feature to be enabled",
+    ))
+}
+
 impl ComponentAttr<NamedField> {
     pub(super) fn from_attributes_validated(
         attributes: &[Attribute],
@@ -264,7 +339,7 @@ fn is_valid_xml_attr(attrs: &ComponentAttr<NamedField>, component_part: &Compone
 
 impl Parse for ComponentAttr<NamedField> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: example, format, default, write_only, read_only, xml, value_type";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected attribute, expected any of: example, format, default, write_only, read_only, nullable, xml, value_type, extensions";
         let mut field = NamedField::default();
 
         while !input.is_empty() {
@@ -282,7 +357,7 @@ impl Parse for ComponentAttr<NamedField> {
                         AnyValue::parse_any(input)
                     })?);
                 }
-                "format" => field.format = Some(parse_format(input)?),
+                "format" => field.format = Some(parse_utils::parse_next(input, || input.parse())?),
                 "default" => {
                     field.default = Some(parse_utils::parse_next(input, || {
                         AnyValue::parse_any(input)
@@ -290,6 +365,7 @@ impl Parse for ComponentAttr<NamedField> {
                 }
                 "write_only" => field.write_only = Some(parse_utils::parse_bool_or_true(input)?),
                 "read_only" => field.read_only = Some(parse_utils::parse_bool_or_true(input)?),
+                "nullable" => field.nullable = Some(parse_utils::parse_bool_or_true(input)?),
                 "xml" => {
                     let xml;
                     parenthesized!(xml in input);
@@ -298,6 +374,11 @@ impl Parse for ComponentAttr<NamedField> {
                 "value_type" => {
                     field.ty = Some(parse_utils::parse_next(input, || input.parse::<Ident>())?)
                 }
+                "extensions" => {
+                    let extensions;
+                    parenthesized!(extensions in input);
+                    field.extensions = Some(extensions.parse()?)
+                }
                 _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE_MESSAGE)),
             }
 
@@ -310,26 +391,6 @@ impl Parse for ComponentAttr<NamedField> {
     }
 }
 
-#[inline]
-fn parse_format(input: &ParseBuffer) -> Result<ExprPath, Error> {
-    let format = parse_utils::parse_next(input, || input.parse::<ExprPath>()).map_err(|error| {
-        Error::new(
-            error.span(),
-            format!(
-                "unparseable format expected expression path e.g. ComponentFormat::String, {}",
-                error
-            ),
-        )
-    })?;
-
-    if format.path.segments.first().unwrap().ident != "utoipa" {
-        let appended_path: ExprPath = syn::parse_quote!(utoipa::openapi::#format);
-        Ok(appended_path)
-    } else {
-        Ok(format)
-    }
-}
-
 pub fn parse_component_attr<T: Sized + Parse>(attributes: &[Attribute]) -> Option<T> {
     attributes
         .iter()
@@ -359,6 +420,15 @@ impl ToTokens for Enum {
                 .example(Some(#example))
             })
         }
+
+        // with variant docs extensions are added together with the variant docs extensions
+        if !matches!(self.variant_docs, Some(VariantDocs::Extensions)) {
+            if let Some(ref extensions) = self.extensions {
+                tokens.extend(quote! {
+                    .extensions(Some(#extensions))
+                })
+            }
+        }
     }
 }
 
@@ -374,6 +444,12 @@ impl ToTokens for Struct {
                  .xml(Some(#xml))
             ))
         }
+
+        if let Some(ref extensions) = self.extensions {
+            tokens.extend(quote! {
+                .extensions(Some(#extensions))
+            })
+        }
     }
 }
 
@@ -396,6 +472,20 @@ impl ToTokens for UnnamedFieldStruct {
                 .format(Some(#format))
             })
         }
+
+        if let Some(ref extensions) = self.extensions {
+            tokens.extend(quote! {
+                .extensions(Some(#extensions))
+            })
+        }
+    }
+}
+
+impl NamedField {
+    /// Check whether user has defined format for the field.
+    #[cfg(feature = "validator")]
+    pub(super) fn has_format(&self) -> bool {
+        self.format.is_some()
     }
 }
 
@@ -430,5 +520,17 @@ impl ToTokens for NamedField {
                 .read_only(Some(#read_only))
             })
         }
+
+        if let Some(ref nullable) = self.nullable {
+            tokens.extend(quote! {
+                .nullable(Some(#nullable))
+            })
+        }
+
+        if let Some(ref extensions) = self.extensions {
+            tokens.extend(quote! {
+                .extensions(Some(#extensions))
+            })
+        }
     }
 }
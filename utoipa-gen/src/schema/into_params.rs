@@ -6,7 +6,7 @@ use syn::{
 };
 
 use crate::{
-    component_type::{ComponentFormat, ComponentType},
+    component_type::{ComponentFormat, ComponentFormatAttr, ComponentType},
     doc_comment::CommentAttributes,
     parse_utils,
     path::parameter::ParameterExt,
@@ -193,7 +193,7 @@ impl ToTokens for Param<'_> {
             .find(|attribute| attribute.path.is_ident("param"))
             .map(|attribute| attribute.parse_args::<ParameterExt>().unwrap_or_abort());
 
-        if let Some(ext) = parameter_ext {
+        if let Some(ref ext) = parameter_ext {
             if let Some(ref style) = ext.style {
                 tokens.extend(quote! { .style(Some(#style)) });
             }
@@ -208,19 +208,27 @@ impl ToTokens for Param<'_> {
             }
         }
 
-        let param_type = ParamType(&component_part);
+        let format = parameter_ext.as_ref().and_then(|ext| ext.format.as_ref());
+        #[allow(unused_mut)]
+        let mut param_type = ParamType(&component_part, format).to_token_stream();
+
+        #[cfg(feature = "validator")]
+        if let Some(validate) = super::validator::Validate::from_attributes(&field.attrs) {
+            param_type.extend(validate.to_tokens_for(&component_part, None, format.is_some()));
+        }
+
         tokens.extend(quote! { .schema(Some(#param_type)).build() });
     }
 }
 
-struct ParamType<'a>(&'a ComponentPart<'a>);
+struct ParamType<'a>(&'a ComponentPart<'a>, Option<&'a ComponentFormatAttr>);
 
 impl ToTokens for ParamType<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let ty = self.0;
         match &ty.generic_type {
             Some(GenericType::Vec) => {
-                let param_type = ParamType(ty.child.as_ref().unwrap().as_ref());
+                let param_type = ParamType(ty.child.as_ref().unwrap().as_ref(), self.1);
 
                 tokens.extend(quote! { #param_type.to_array_builder() });
             }
@@ -233,7 +241,11 @@ impl ToTokens for ParamType<'_> {
                     });
 
                     let format = ComponentFormat(ty.ident);
-                    if format.is_known_format() {
+                    if let Some(format) = self.1 {
+                        tokens.extend(quote! {
+                            .format(Some(#format))
+                        })
+                    } else if format.is_known_format() {
                         tokens.extend(quote! {
                             .format(Some(#format))
                         })
@@ -245,20 +257,33 @@ impl ToTokens for ParamType<'_> {
                         utoipa::openapi::Ref::from_component_name(#name)
                     });
                 }
+                ValueType::Value => {
+                    tokens.extend(quote! { utoipa::openapi::ObjectBuilder::new() });
+                }
             },
             Some(GenericType::Option)
             | Some(GenericType::Cow)
             | Some(GenericType::Box)
             | Some(GenericType::RefCell) => {
-                let param_type = ParamType(ty.child.as_ref().unwrap().as_ref());
+                let param_type = ParamType(ty.child.as_ref().unwrap().as_ref(), self.1);
 
                 tokens.extend(param_type.into_token_stream())
             }
             Some(GenericType::Map) => {
-                // Maps are treated just as generic objects without types. There is no Map type in OpenAPI spec.
-                tokens.extend(quote! {
-                    utoipa::openapi::ObjectBuilder::new()
-                });
+                // Maps are objects with values as additional properties. There is no Map type in OpenAPI spec.
+                let value_part = ty.child.as_ref().unwrap().as_ref();
+
+                if value_part.is_value() {
+                    // Arbitrary values have no component to reference, values are left untyped.
+                    tokens.extend(quote! { utoipa::openapi::ObjectBuilder::new() });
+                } else {
+                    let param_type = ParamType(value_part, None);
+
+                    tokens.extend(quote! {
+                        utoipa::openapi::ObjectBuilder::new()
+                            .additional_properties(Some(#param_type))
+                    });
+                }
             }
         };
     }
@@ -0,0 +1,285 @@
+//! Support for reading [validator](https://crates.io/crates/validator) crate's `#[validate(...)]`
+//! attributes to OpenAPI schema constraints.
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Paren,
+    Attribute, Expr, ExprLit, Lit, Token,
+};
+
+use super::{ComponentPart, GenericType, ValueType};
+
+/// Parsed `#[validate(...)]` attributes of a field. Only validations which have corresponding
+/// keyword in OpenAPI are stored. Rest of the validations are ignored.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(super) struct Validate {
+    email: bool,
+    url: bool,
+    length: Option<Limits>,
+    range: Option<Limits>,
+    regex: Option<Expr>,
+}
+
+/// Arguments of `length(...)` and `range(...)` validations.
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Limits {
+    min: Option<Expr>,
+    max: Option<Expr>,
+    equal: Option<Expr>,
+}
+
+/// Target the validations are applied to, resolved from the type of the field.
+enum ValidateTarget {
+    Property,
+    Array,
+}
+
+impl Validate {
+    /// Parse all `#[validate(...)]` attributes from given field attributes. Returns `None` if
+    /// there are no `#[validate(...)]` attributes. Attributes without arguments such as
+    /// `#[validate]` of nested validation and forms which cannot be parsed are skipped since
+    /// they are validated by the validator derive itself.
+    pub(super) fn from_attributes(attributes: &[Attribute]) -> Option<Self> {
+        attributes
+            .iter()
+            .filter(|attribute| attribute.path.is_ident("validate") && !attribute.tokens.is_empty())
+            .filter_map(|attribute| {
+                attribute
+                    .parse_args_with(Punctuated::<Validation, Token![,]>::parse_terminated)
+                    .ok()
+            })
+            .fold(None, |validate, validations| {
+                let mut validate: Validate = validate.unwrap_or_default();
+                validations
+                    .into_iter()
+                    .for_each(|validation| validate.add(validation));
+
+                Some(validate)
+            })
+    }
+
+    fn add(&mut self, validation: Validation) {
+        match &*validation.name.to_string() {
+            "email" => self.email = true,
+            "url" => self.url = true,
+            "length" => self.length = Some(Limits::from_args(validation.args)),
+            "range" => self.range = Some(Limits::from_args(validation.args)),
+            "regex" => {
+                self.regex = validation.value.or_else(|| {
+                    validation
+                        .args
+                        .into_iter()
+                        .find(|(name, _)| name == "path")
+                        .map(|(_, path)| path)
+                })
+            }
+            _ => (),
+        }
+    }
+
+    /// Create tokens of the validations for given type of the field. Validations are created
+    /// as builder method calls which can be appended to property or array builder created for
+    /// the same type. `has_format` defines whether user has explicitly defined a format in
+    /// which case format from validations is not used.
+    pub(super) fn to_tokens_for(
+        &self,
+        component_part: &ComponentPart,
+        type_override: Option<&ComponentPart>,
+        has_format: bool,
+    ) -> TokenStream2 {
+        let target = if type_override.is_some() {
+            Some(ValidateTarget::Property)
+        } else {
+            ValidateTarget::resolve(component_part)
+        };
+
+        match target {
+            Some(ValidateTarget::Property) => self.property_tokens(has_format),
+            Some(ValidateTarget::Array) => self.array_tokens(),
+            None => TokenStream2::new(),
+        }
+    }
+
+    fn property_tokens(&self, has_format: bool) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+
+        if !has_format {
+            if self.email {
+                tokens.extend(quote! { .format(Some(utoipa::openapi::ComponentFormat::Email)) })
+            } else if self.url {
+                tokens.extend(quote! { .format(Some(utoipa::openapi::ComponentFormat::Uri)) })
+            }
+        }
+
+        if let Some(ref length) = self.length {
+            if let Some(min) = length.min.as_ref().or(length.equal.as_ref()) {
+                tokens.extend(quote! { .min_length(Some(#min as usize)) })
+            }
+            if let Some(max) = length.max.as_ref().or(length.equal.as_ref()) {
+                tokens.extend(quote! { .max_length(Some(#max as usize)) })
+            }
+        }
+
+        if let Some(ref range) = self.range {
+            if let Some(ref min) = range.min {
+                tokens.extend(quote! { .minimum(Some(#min as f64)) })
+            }
+            if let Some(ref max) = range.max {
+                tokens.extend(quote! { .maximum(Some(#max as f64)) })
+            }
+        }
+
+        if let Some(ref regex) = self.regex {
+            tokens.extend(quote! { .pattern(Some((#regex).as_str())) })
+        }
+
+        tokens
+    }
+
+    fn array_tokens(&self) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+
+        if let Some(ref length) = self.length {
+            if let Some(min) = length.min.as_ref().or(length.equal.as_ref()) {
+                tokens.extend(quote! { .min_items(Some(#min as usize)) })
+            }
+            if let Some(max) = length.max.as_ref().or(length.equal.as_ref()) {
+                tokens.extend(quote! { .max_items(Some(#max as usize)) })
+            }
+        }
+
+        tokens
+    }
+}
+
+impl Limits {
+    fn from_args(args: Vec<(Ident, Expr)>) -> Self {
+        args.into_iter()
+            .fold(Limits::default(), |mut limits, (name, value)| {
+                match &*name.to_string() {
+                    "min" => limits.min = Some(value),
+                    "max" => limits.max = Some(value),
+                    "equal" => limits.equal = Some(value),
+                    _ => (),
+                };
+                limits
+            })
+    }
+}
+
+impl ValidateTarget {
+    fn resolve(component_part: &ComponentPart) -> Option<Self> {
+        match component_part.generic_type {
+            Some(GenericType::Option)
+            | Some(GenericType::Cow)
+            | Some(GenericType::Box)
+            | Some(GenericType::RefCell) => {
+                Self::resolve(component_part.child.as_ref().unwrap().as_ref())
+            }
+            Some(GenericType::Vec) => Some(Self::Array),
+            Some(GenericType::Map) => None,
+            None => match component_part.value_type {
+                ValueType::Primitive => Some(Self::Property),
+                ValueType::Object | ValueType::Value => None,
+            },
+        }
+    }
+}
+
+/// Single validation within `#[validate(...)]` attribute e.g. `email`, `regex = "RE"` or
+/// `length(min = 1, max = 10)`.
+struct Validation {
+    name: Ident,
+    value: Option<Expr>,
+    args: Vec<(Ident, Expr)>,
+}
+
+impl Parse for Validation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+        let mut validation = Validation {
+            name,
+            value: None,
+            args: Vec::new(),
+        };
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            validation.value = if validation.name == "regex" {
+                Some(parse_value(input)?)
+            } else {
+                skip_value(input)?;
+                None
+            };
+        } else if input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            validation.args = Punctuated::<ValidationArg, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .filter_map(|arg| arg.value.map(|value| (arg.name, value)))
+                .collect();
+        }
+
+        Ok(validation)
+    }
+}
+
+/// Argument of validation e.g. `min = 1` in `length(min = 1)`.
+struct ValidationArg {
+    name: Ident,
+    value: Option<Expr>,
+}
+
+impl Parse for ValidationArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let value = match &*name.to_string() {
+                "min" | "max" | "equal" | "path" => Some(parse_value(input)?),
+                _ => {
+                    skip_value(input)?;
+                    None
+                }
+            };
+
+            Ok(Self { name, value })
+        } else {
+            if input.peek(Paren) {
+                let content;
+                parenthesized!(content in input);
+                content.parse::<TokenStream2>()?;
+            }
+            Ok(Self { name, value: None })
+        }
+    }
+}
+
+/// Parse value of validation argument which has no equivalent in OpenAPI e.g. `message` or `code`.
+/// The value is consumed from the input without interpreting content of string literals.
+fn skip_value(input: ParseStream) -> syn::Result<()> {
+    input.parse::<Expr>().map(|_| ())
+}
+
+/// Parse numeric or path value of validation. Older versions of validator accept paths and constants as string
+/// literals e.g. `regex = "RE"` thus the content of string literals is parsed as expression.
+fn parse_value(input: ParseStream) -> syn::Result<Expr> {
+    let value = input.parse::<Expr>()?;
+
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(ref value),
+            ..
+        }) => value
+            .parse::<Expr>()
+            .or_else(|_| Ok(Expr::Lit(syn::parse_quote!(#value)))),
+        value => Ok(value),
+    }
+}